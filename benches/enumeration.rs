@@ -0,0 +1,96 @@
+//! Benchmarks of full enumerations, as a baseline for changes to the generated code.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use enumerable::Enumerable;
+use std::hint::black_box;
+
+#[derive(Copy, Clone, Debug, Enumerable)]
+enum Fieldless {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+}
+
+// fields are never read explicitly, only passed to `black_box`
+#[allow(dead_code)]
+#[derive(Copy, Clone, Debug, Enumerable)]
+struct ThreeFields {
+    level: u8,
+    kind: Fieldless,
+    enabled: bool,
+}
+
+#[allow(dead_code)]
+#[derive(Copy, Clone, Debug, Enumerable)]
+enum WithData {
+    Empty,
+    Flag(bool),
+    Pair(Fieldless, Fieldless),
+    Level { level: u8, enabled: bool },
+}
+
+/// Enumerates `ThreeFields` with hand-written nested loops, the best the generated code can do.
+fn three_fields_nested_loops(mut f: impl FnMut(ThreeFields)) {
+    for level in u8::MIN..=u8::MAX {
+        for kind in Fieldless::enumerator() {
+            for enabled in [false, true] {
+                f(ThreeFields {
+                    level,
+                    kind,
+                    enabled,
+                });
+            }
+        }
+    }
+}
+
+fn bench_enumeration(c: &mut Criterion) {
+    c.bench_function("fieldless enum", |b| {
+        b.iter(|| {
+            Fieldless::enumerator().for_each(|v| {
+                black_box(v);
+            })
+        })
+    });
+
+    let mut group = c.benchmark_group("three-field struct");
+    group.bench_function("derived", |b| {
+        b.iter(|| {
+            ThreeFields::enumerator().for_each(|v| {
+                black_box(v);
+            })
+        })
+    });
+    group.bench_function("nested loops", |b| {
+        b.iter(|| {
+            three_fields_nested_loops(|v| {
+                black_box(v);
+            })
+        })
+    });
+    group.finish();
+
+    c.bench_function("data enum", |b| {
+        b.iter(|| {
+            WithData::enumerator().for_each(|v| {
+                black_box(v);
+            })
+        })
+    });
+
+    c.bench_function("3-tuple", |b| {
+        b.iter(|| {
+            <(u8, Fieldless, bool)>::enumerator().for_each(|v| {
+                black_box(v);
+            })
+        })
+    });
+}
+
+criterion_group!(benches, bench_enumeration);
+criterion_main!(benches);
@@ -0,0 +1,252 @@
+//! Parsing of the `#[enumerable(...)]` attributes.
+
+use proc_macro2::Span;
+use syn::{spanned::Spanned, Attribute, Expr, Field, LitInt, Variant};
+
+use crate::errors::Errors;
+
+/// The options set by `#[enumerable(...)]` attributes on the type to be derived.
+#[derive(Clone, Default)]
+pub struct ContainerAttributes {
+    /// `#[enumerable(max_size = N)]`: the maximum number of values the type may have.
+    pub max_size: Option<usize>,
+    /// `#[enumerable(sort_by_discriminant)]`: enumerate variants in the order of their
+    /// discriminants. Holds the span of the option for error reporting.
+    pub sort_by_discriminant: Option<Span>,
+    /// `#[enumerable(no_size)]`: report `None` as the size instead of computing it. Holds the span
+    /// of the option for error reporting.
+    pub no_size: Option<Span>,
+    /// `#[enumerable(exact_size)]`: implement `ExactSizeEnumerable`, requiring all fields to be
+    /// `ExactSizeEnumerable`. Holds the span of the option for error reporting.
+    pub exact_size: Option<Span>,
+    /// `#[enumerable(diagonal)]`: implement `ProductEnumerable`, requiring the sizes of all fields
+    /// to be known when enumerating in diagonal order. Holds the span of the option for error
+    /// reporting.
+    pub diagonal: Option<Span>,
+    /// `#[enumerable(ordered)]`: declare that the enumerator yields values in ascending order of
+    /// `Ord`, implementing `OrderedEnumerable`. Holds the span of the option for error reporting.
+    pub ordered: Option<Span>,
+}
+
+impl ContainerAttributes {
+    /// Parses all `#[enumerable(...)]` attributes on a type.
+    ///
+    /// Errors in different attributes are reported together.
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut errors = Errors::new();
+        let mut result = Self::default();
+
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("enumerable"))
+        {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("max_size") {
+                    if result.max_size.is_some() {
+                        return Err(meta.error("`max_size` specified more than once"));
+                    }
+
+                    let max_size: LitInt = meta.value()?.parse()?;
+                    result.max_size = Some(max_size.base10_parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("sort_by_discriminant") {
+                    if result.sort_by_discriminant.is_some() {
+                        return Err(meta.error("`sort_by_discriminant` specified more than once"));
+                    }
+
+                    result.sort_by_discriminant = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("no_size") {
+                    if result.no_size.is_some() {
+                        return Err(meta.error("`no_size` specified more than once"));
+                    }
+
+                    result.no_size = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("exact_size") {
+                    if result.exact_size.is_some() {
+                        return Err(meta.error("`exact_size` specified more than once"));
+                    }
+
+                    result.exact_size = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("diagonal") {
+                    if result.diagonal.is_some() {
+                        return Err(meta.error("`diagonal` specified more than once"));
+                    }
+
+                    result.diagonal = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("ordered") {
+                    if result.ordered.is_some() {
+                        return Err(meta.error("`ordered` specified more than once"));
+                    }
+
+                    result.ordered = Some(meta.path.span());
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `enumerable` attribute"))
+                }
+            });
+            errors.take(parsed);
+        }
+
+        if let (Some(span), Some(_)) = (result.no_size, result.max_size) {
+            errors.push_spanned(
+                span,
+                "`no_size` cannot be used together with `max_size`, which requires the size to be known",
+            );
+        }
+
+        if let (Some(span), Some(_)) = (result.exact_size, result.no_size) {
+            errors.push_spanned(
+                span,
+                "`exact_size` cannot be used together with `no_size`, which makes the size unknown",
+            );
+        }
+
+        errors.finish(result)
+    }
+}
+
+/// The options set by `#[enumerable(...)]` attributes on an enum variant.
+#[derive(Clone, Default)]
+pub struct VariantAttributes {
+    /// `#[enumerable(skip)]`: leave the variant out of the enumeration. Holds the span of the
+    /// option for error reporting.
+    pub skip: Option<Span>,
+}
+
+impl VariantAttributes {
+    /// Parses all `#[enumerable(...)]` attributes on a variant.
+    ///
+    /// Errors in different attributes are reported together.
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut errors = Errors::new();
+        let mut result = Self::default();
+
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("enumerable"))
+        {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    if result.skip.is_some() {
+                        return Err(meta.error("`skip` specified more than once"));
+                    }
+
+                    result.skip = Some(meta.path.span());
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `enumerable` attribute on variants"))
+                }
+            });
+            errors.take(parsed);
+        }
+
+        errors.finish(result)
+    }
+
+    /// Returns the variants which are not skipped by `#[enumerable(skip)]`, in the declaration
+    /// order.
+    ///
+    /// Errors in the attributes of all variants are reported together.
+    pub fn enumerated_variants<'a>(
+        variants: impl IntoIterator<Item = &'a Variant>,
+    ) -> syn::Result<Vec<&'a Variant>> {
+        let mut errors = Errors::new();
+        let mut enumerated = vec![];
+
+        for variant in variants {
+            if let Some(attributes) = errors.take(Self::from_attrs(&variant.attrs)) {
+                if attributes.skip.is_none() {
+                    enumerated.push(variant);
+                }
+            }
+        }
+
+        errors.finish(enumerated)
+    }
+}
+
+/// The options set by `#[enumerable(...)]` attributes on a field.
+#[derive(Clone, Default)]
+pub struct FieldAttributes {
+    /// `#[enumerable(skip)]`: leave the field out of the enumeration, holding it at `default`.
+    /// Holds the span of the option for error reporting.
+    pub skip: Option<Span>,
+    /// `#[enumerable(default = expr)]`: the value of a skipped field in all enumerated values.
+    pub default: Option<Expr>,
+}
+
+impl FieldAttributes {
+    /// Parses all `#[enumerable(...)]` attributes on a field.
+    ///
+    /// Errors in different attributes are reported together.
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut errors = Errors::new();
+        let mut result = Self::default();
+        let mut default_span = None;
+
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("enumerable"))
+        {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    if result.skip.is_some() {
+                        return Err(meta.error("`skip` specified more than once"));
+                    }
+
+                    result.skip = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    if result.default.is_some() {
+                        return Err(meta.error("`default` specified more than once"));
+                    }
+
+                    default_span = Some(meta.path.span());
+                    result.default = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `enumerable` attribute on fields"))
+                }
+            });
+            errors.take(parsed);
+        }
+
+        match (result.skip, default_span) {
+            (Some(span), None) => errors.push_spanned(
+                span,
+                "skipped fields require a value to hold, e.g. `#[enumerable(skip, default = 0)]`",
+            ),
+            (None, Some(span)) => {
+                errors.push_spanned(span, "`default` can only be used together with `skip`")
+            }
+            _ => {}
+        }
+
+        errors.finish(result)
+    }
+
+    /// Returns the fields which are not skipped by `#[enumerable(skip, default = ...)]`, in the
+    /// declaration order.
+    ///
+    /// Errors in the attributes of all fields are reported together.
+    pub fn enumerated_fields<'a>(
+        fields: impl IntoIterator<Item = &'a Field>,
+    ) -> syn::Result<Vec<&'a Field>> {
+        let mut errors = Errors::new();
+        let mut enumerated = vec![];
+
+        for field in fields {
+            if let Some(attributes) = errors.take(Self::from_attrs(&field.attrs)) {
+                if attributes.skip.is_none() {
+                    enumerated.push(field);
+                }
+            }
+        }
+
+        errors.finish(enumerated)
+    }
+}
@@ -9,6 +9,20 @@ pub struct EnumerableImpl<'a> {
     size_option: SizeOption,
     enumerator_type: Option<&'a TokenStream>,
     enumerator_creator: Option<&'a TokenStream>,
+    last_value: Option<&'a TokenStream>,
+    from_index: Option<&'a TokenStream>,
+    from_index_u128: Option<&'a TokenStream>,
+    is_inhabited: Option<&'a TokenStream>,
+    next_value_direct: Option<&'a TokenStream>,
+    previous_value_direct: Option<&'a TokenStream>,
+    enumerator_since: Option<&'a TokenStream>,
+    enumerator_from_direct: Option<&'a TokenStream>,
+    to_index_direct: Option<&'a TokenStream>,
+    exact_size: Option<TokenStream>,
+    double_ended: bool,
+    const_values: Option<&'a TokenStream>,
+    product: Option<(Vec<TokenStream>, &'a TokenStream)>,
+    sum: Option<Vec<TokenStream>>,
 }
 
 impl<'a> EnumerableImpl<'a> {
@@ -19,6 +33,20 @@ impl<'a> EnumerableImpl<'a> {
             size_option,
             enumerator_type: None,
             enumerator_creator: None,
+            last_value: None,
+            from_index: None,
+            from_index_u128: None,
+            is_inhabited: None,
+            next_value_direct: None,
+            previous_value_direct: None,
+            enumerator_since: None,
+            enumerator_from_direct: None,
+            to_index_direct: None,
+            exact_size: None,
+            double_ended: false,
+            const_values: None,
+            product: None,
+            sum: None,
         }
     }
 
@@ -35,8 +63,135 @@ impl<'a> EnumerableImpl<'a> {
         self
     }
 
+    /// Override the body of the `last_value` method, an expression of type `Option<Self>`. The
+    /// default implementation of the trait is used if not overridden.
+    pub fn override_last_value(mut self, last_value: &'a TokenStream) -> Self {
+        self.last_value = Some(last_value);
+        self
+    }
+
+    /// Override the body of the `enumerable_from_index` method, an expression of type
+    /// `Option<Self>` with the argument `index: usize` in scope. The default implementation of the
+    /// trait is used if not overridden.
+    pub fn override_from_index(mut self, from_index: &'a TokenStream) -> Self {
+        self.from_index = Some(from_index);
+        self
+    }
+
+    /// Override the body of the `enumerable_from_index_u128` method, an expression of type
+    /// `Option<Self>` with the argument `index: u128` in scope. The default implementation of the
+    /// trait is used if not overridden.
+    pub fn override_from_index_u128(mut self, from_index_u128: &'a TokenStream) -> Self {
+        self.from_index_u128 = Some(from_index_u128);
+        self
+    }
+
+    /// Override the value of the `IS_INHABITED` constant, a constant expression of type `bool`.
+    /// The default value of the trait is used if not overridden.
+    pub fn override_is_inhabited(mut self, is_inhabited: &'a TokenStream) -> Self {
+        self.is_inhabited = Some(is_inhabited);
+        self
+    }
+
+    /// Override the body of the `next_value_direct` method, an expression of type
+    /// `Option<Option<Self>>` with `self` in scope. The default implementation of the trait is used
+    /// if not overridden.
+    pub fn override_next_value_direct(mut self, next_value_direct: &'a TokenStream) -> Self {
+        self.next_value_direct = Some(next_value_direct);
+        self
+    }
+
+    /// Override the body of the `previous_value_direct` method, an expression of type
+    /// `Option<Option<Self>>` with `self` in scope. The default implementation of the trait is used
+    /// if not overridden.
+    pub fn override_previous_value_direct(
+        mut self,
+        previous_value_direct: &'a TokenStream,
+    ) -> Self {
+        self.previous_value_direct = Some(previous_value_direct);
+        self
+    }
+
+    /// Override the body of the `enumerator_since` method, an expression of type
+    /// `Self::Enumerator` with the argument `start: usize` in scope. The default implementation of
+    /// the trait is used if not overridden.
+    pub fn override_enumerator_since(mut self, enumerator_since: &'a TokenStream) -> Self {
+        self.enumerator_since = Some(enumerator_since);
+        self
+    }
+
+    /// Override the body of the `enumerator_from_direct` method, an expression of type
+    /// `Option<Self::Enumerator>` with the argument `value: Self` in scope. The default
+    /// implementation of the trait is used if not overridden.
+    pub fn override_enumerator_from_direct(
+        mut self,
+        enumerator_from_direct: &'a TokenStream,
+    ) -> Self {
+        self.enumerator_from_direct = Some(enumerator_from_direct);
+        self
+    }
+
+    /// Override the body of the `enumerable_to_index_direct` method, an expression of type
+    /// `Option<u128>` with the argument `value: &Self` in scope. The default implementation of the
+    /// trait is used if not overridden.
+    pub fn override_to_index_direct(mut self, to_index_direct: &'a TokenStream) -> Self {
+        self.to_index_direct = Some(to_index_direct);
+        self
+    }
+
+    /// Implement the `ExactSizeEnumerable` trait as well, with the extra predicates (separated and
+    /// terminated by commas) in its where clause. The enumerator must be an `ExactSizeIterator`
+    /// under them.
+    pub fn with_exact_size(mut self, predicates: TokenStream) -> Self {
+        self.exact_size = Some(predicates);
+        self
+    }
+
+    /// Override the `enumerator_reversed` method to consume the enumerator from the back. The
+    /// enumerator must be a `DoubleEndedIterator`.
+    pub fn with_double_ended(mut self) -> Self {
+        self.double_ended = true;
+        self
+    }
+
+    /// Implement the `ConstEnumerable` trait as well, with the value of the `VALUES` constant, a
+    /// constant expression of type `&'static [Self]`. It's ignored if the target type has type or
+    /// lifetime parameters, as `ConstEnumerable` requires `Self: 'static`.
+    pub fn with_const_values(mut self, const_values: &'a TokenStream) -> Self {
+        self.const_values = Some(const_values);
+        self
+    }
+
+    /// Implement the `ProductEnumerable` trait as well, with the types of the fields in order and
+    /// the body of the `from_field_indices` method, an expression of type `Option<Self>` with the
+    /// argument `indices: [u128; N]` in scope.
+    pub fn with_product(
+        mut self,
+        field_types: Vec<TokenStream>,
+        from_field_indices: &'a TokenStream,
+    ) -> Self {
+        self.product = Some((field_types, from_field_indices));
+        self
+    }
+
+    /// Implement the `SumEnumerable` trait as well, with the sizes of the variants in enumeration
+    /// order, constant expressions of type `Option<u128>`.
+    pub fn with_sum(mut self, variant_sizes: Vec<TokenStream>) -> Self {
+        self.sum = Some(variant_sizes);
+        self
+    }
+
     /// Generate the implementation of the `Enumerable` trait for the target type.
     pub fn generate(&self) -> TokenStream {
+        self.generate_with_enumerator_from_direct(self.enumerator_from_direct)
+    }
+
+    /// Generate the implementation of the `Enumerable` trait for the target type, with the body of
+    /// the `enumerator_from_direct` method given instead of the overridden one.
+    fn generate_with_enumerator_from_direct(
+        &self,
+        enumerator_from_direct: Option<&TokenStream>,
+    ) -> TokenStream {
         let enumerable_trait_path = self.target.enumerable_trait_path();
         let impl_generics = self.target.generic_params_full();
         let target_type = self.target.target_type();
@@ -49,19 +204,310 @@ impl<'a> EnumerableImpl<'a> {
             .enumerator_creator
             .cloned()
             .unwrap_or_else(|| quote!(<#enumerator_type>::new()));
-        let size_option = &self.size_option;
+
+        let max_size = self.target.attributes().max_size;
+        let size_option = match max_size {
+            // `no_size` and `max_size` are mutually exclusive, checked when parsing attributes.
+            _ if self.target.attributes().no_size.is_some() => {
+                SizeOption::none().into_token_stream()
+            }
+            Some(max_size) => {
+                let type_name = self.target.target_type_name().to_token_stream().to_string();
+                self.size_option
+                    .with_max_size_check(self.target.size_module_path(), &type_name, max_size)
+                    .into_token_stream()
+            }
+            None => self.size_option.to_token_stream(),
+        };
+
+        // The max size check is a part of the size constant, so it only takes effect when the
+        // constant is evaluated. Non-generic types are checked at their definitions, generic ones
+        // when their enumerators are instantiated.
+        let is_generic = !self
+            .target
+            .generic_params_simple()
+            .to_token_stream()
+            .is_empty();
+        let (size_check_in_enumerator, size_check) = match (max_size, is_generic) {
+            (None, _) => (TokenStream::new(), TokenStream::new()),
+            (Some(_), true) => (
+                quote!(let _ = <Self as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION;),
+                TokenStream::new(),
+            ),
+            (Some(_), false) => (
+                TokenStream::new(),
+                quote!(
+                    const _: Option<usize> =
+                        <#target_type as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION;
+                ),
+            ),
+        };
+
+        let last_value_fn = match self.last_value {
+            Some(last_value) => quote!(
+                #[inline]
+                fn last_value() -> Option<Self> {
+                    #last_value
+                }
+            ),
+            None => TokenStream::new(),
+        };
+
+        let from_index_fn = match self.from_index {
+            Some(from_index) => quote!(
+                #[inline]
+                fn enumerable_from_index(index: usize) -> Option<Self> {
+                    #from_index
+                }
+            ),
+            None => TokenStream::new(),
+        };
+
+        let from_index_u128_fn = match self.from_index_u128 {
+            Some(from_index_u128) => quote!(
+                #[inline]
+                fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+                    #from_index_u128
+                }
+            ),
+            None => TokenStream::new(),
+        };
+
+        let next_value_direct_fn = match self.next_value_direct {
+            Some(next_value_direct) => quote!(
+                #[inline]
+                fn next_value_direct(self) -> Option<Option<Self>> {
+                    #next_value_direct
+                }
+            ),
+            None => TokenStream::new(),
+        };
+
+        let previous_value_direct_fn = match self.previous_value_direct {
+            Some(previous_value_direct) => quote!(
+                #[inline]
+                fn previous_value_direct(self) -> Option<Option<Self>> {
+                    #previous_value_direct
+                }
+            ),
+            None => TokenStream::new(),
+        };
+
+        let enumerator_since_fn = match self.enumerator_since {
+            Some(enumerator_since) => quote!(
+                #[inline]
+                fn enumerator_since(start: usize) -> Self::Enumerator {
+                    #enumerator_since
+                }
+            ),
+            None => TokenStream::new(),
+        };
+
+        let to_index_direct_fn = match self.to_index_direct {
+            Some(to_index_direct) => quote!(
+                #[inline]
+                fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+                    #to_index_direct
+                }
+            ),
+            None => TokenStream::new(),
+        };
+
+        // values are looked up by index in time linear in the number of fields
+        let step_by_enumerator_path = self.target.step_by_enumerator_path();
+        let enumerator_step_by_fn = quote!(
+            #[inline]
+            fn enumerator_step_by(start: usize, step: usize) -> #step_by_enumerator_path<Self> {
+                #step_by_enumerator_path::by_index(start, step)
+            }
+        );
+
+        let enumerator_reversed_fn = if self.double_ended {
+            let reversed_enumerator_path = self.target.reversed_enumerator_path();
+            quote!(
+                #[inline]
+                fn enumerator_reversed() -> #reversed_enumerator_path<Self> {
+                    #reversed_enumerator_path::from_double_ended()
+                }
+            )
+        } else {
+            TokenStream::new()
+        };
+
+        let enumerator_from_direct_fn = match enumerator_from_direct {
+            Some(enumerator_from_direct) => quote!(
+                #[inline]
+                fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+                    #enumerator_from_direct
+                }
+            ),
+            None => TokenStream::new(),
+        };
+
+        let exact_size_impl = match &self.exact_size {
+            Some(predicates) => {
+                let exact_size_enumerable_trait_path =
+                    self.target.exact_size_enumerable_trait_path();
+                let where_clause = self.target.where_clause_with(predicates);
+
+                quote!(
+                    #[automatically_derived]
+                    impl #impl_generics #exact_size_enumerable_trait_path for #target_type #where_clause {
+                        type ExactSizeEnumerator = #enumerator_type;
+                    }
+                )
+            }
+            None => TokenStream::new(),
+        };
+
+        let const_values_impl = match self.const_values {
+            Some(const_values) if !self.target.has_non_static_params() => {
+                let const_enumerable_trait_path = self.target.const_enumerable_trait_path();
+
+                quote!(
+                    #[automatically_derived]
+                    impl #impl_generics #const_enumerable_trait_path for #target_type #where_clause {
+                        const VALUES: &'static [Self] = #const_values;
+                    }
+                )
+            }
+            _ => TokenStream::new(),
+        };
+
+        let product_impl = match &self.product {
+            Some((field_types, from_field_indices)) => {
+                let product_enumerable_trait_path = self.target.product_enumerable_trait_path();
+                let arity = field_types.len();
+                let type_name = self.target.target_type_name().to_token_stream().to_string();
+                let message = format!(
+                    "the number of values of a field of `{}` is unknown or exceeds `u128::MAX`",
+                    type_name
+                );
+
+                quote!(
+                    #[automatically_derived]
+                    impl #impl_generics #product_enumerable_trait_path for #target_type #where_clause {
+                        type FieldIndices = [u128; #arity];
+
+                        const FIELD_SIZES: Self::FieldIndices = [#(
+                            match <#field_types as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION_U128 {
+                                Some(size) => size,
+                                None => panic!(#message),
+                            }
+                        ),*];
+
+                        #[inline]
+                        fn from_field_indices(indices: Self::FieldIndices) -> Option<Self> {
+                            #from_field_indices
+                        }
+                    }
+                )
+            }
+            None => TokenStream::new(),
+        };
+
+        let sum_impl = match &self.sum {
+            Some(variant_sizes) => {
+                let sum_enumerable_trait_path = self.target.sum_enumerable_trait_path();
+                let variant_count = variant_sizes.len();
+                let type_name = self.target.target_type_name().to_token_stream().to_string();
+                let message = format!(
+                    "the number of values of a variant of `{}` is unknown or exceeds `u128::MAX`",
+                    type_name
+                );
+
+                quote!(
+                    #[automatically_derived]
+                    impl #impl_generics #sum_enumerable_trait_path for #target_type #where_clause {
+                        type VariantSizes = [u128; #variant_count];
+
+                        const VARIANT_SIZES: Self::VariantSizes = [#(
+                            match #variant_sizes {
+                                Some(size) => size,
+                                None => panic!(#message),
+                            }
+                        ),*];
+                    }
+                )
+            }
+            None => TokenStream::new(),
+        };
+
+        // `#[enumerable(ordered)]` is a promise of the user, as the derived `Ord` of an enum
+        // compares discriminants, which may not follow the declaration order
+        let ordered_impl = match self.target.attributes().ordered {
+            Some(_) => {
+                let ordered_enumerable_trait_path = self.target.ordered_enumerable_trait_path();
+                let where_clause = self
+                    .target
+                    .where_clause_with(quote!(#target_type: ::core::cmp::Ord,));
+
+                quote!(
+                    #[automatically_derived]
+                    impl #impl_generics #ordered_enumerable_trait_path for #target_type #where_clause {}
+                )
+            }
+            None => TokenStream::new(),
+        };
+
+        let is_inhabited_const = match self.is_inhabited {
+            Some(is_inhabited) => quote!(const IS_INHABITED: bool = #is_inhabited;),
+            None => TokenStream::new(),
+        };
 
         quote!(
             #[automatically_derived]
             impl #impl_generics #enumerable_trait_path for #target_type #where_clause {
                 type Enumerator = #enumerator_type;
 
+                #[inline]
                 fn enumerator() -> Self::Enumerator {
+                    #size_check_in_enumerator
                     #enumerator_creator
                 }
 
-                const ENUMERABLE_SIZE_OPTION: Option<usize> = #size_option;
+                #last_value_fn
+
+                #from_index_fn
+
+                #from_index_u128_fn
+
+                #next_value_direct_fn
+
+                #previous_value_direct_fn
+
+                #enumerator_since_fn
+
+                #enumerator_from_direct_fn
+
+                #to_index_direct_fn
+
+                #enumerator_reversed_fn
+
+                #enumerator_step_by_fn
+
+                const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = #size_option;
+
+                const ENUMERABLE_SIZE_OPTION: Option<usize> =
+                    match <Self as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION_U128 {
+                        Some(size) if size <= usize::MAX as u128 => Some(size as usize),
+                        _ => None,
+                    };
+
+                #is_inhabited_const
             }
+
+            #size_check
+
+            #ordered_impl
+
+            #exact_size_impl
+
+            #const_values_impl
+
+            #product_impl
+
+            #sum_impl
         )
     }
 }
@@ -94,6 +540,12 @@ pub struct EnumeratorInfo {
     pub new_fn_body: TokenStream,
     pub step_fn_body: TokenStream,
     pub next_to_yield_fn_body: TokenStream,
+    /// The body of the `from_value` method, an expression of type `Option<Self>` with the argument
+    /// `value` of the target type in scope, which creates an enumerator yielding `value` first.
+    pub from_value_fn_body: TokenStream,
+    /// The body of the `size_hint` method of the `Iterator` implementation, an expression of type
+    /// `(usize, Option<usize>)`. It must be exact if the enumerators of all fields are exact.
+    pub size_hint_fn_body: TokenStream,
 }
 
 /// The implementation of the `Enumerable` trait for the target type, and the definition of its
@@ -101,6 +553,9 @@ pub struct EnumeratorInfo {
 pub struct EnumerableImplWithEnumerator<'a> {
     enumerable_impl: EnumerableImpl<'a>,
     enumerator_info: EnumeratorInfo,
+    public_constructor: bool,
+    nth_fn_body: Option<&'a TokenStream>,
+    enumerator_doc: Option<&'a str>,
 }
 
 /// Generates the implementation of the `Enumerable` trait for the target type, and the definition
@@ -113,11 +568,13 @@ pub fn enumerable_impl_with_enumerator(
     EnumerableImplWithEnumerator {
         enumerable_impl: EnumerableImpl::new(target, size_option),
         enumerator_info,
+        public_constructor: false,
+        nth_fn_body: None,
+        enumerator_doc: None,
     }
 }
 
 impl<'a> EnumerableImplWithEnumerator<'a> {
-    #[allow(dead_code)]
     /// Manipulate the `EnumerableImpl` instance.
     pub fn with_enumerable_impl<F: FnOnce(EnumerableImpl<'a>) -> EnumerableImpl<'a>>(
         mut self,
@@ -127,6 +584,29 @@ impl<'a> EnumerableImplWithEnumerator<'a> {
         self
     }
 
+    /// Make the `new` method of the enumerator public, so manual implementations can reuse the
+    /// enumerator. It's private by default.
+    pub fn with_public_constructor(mut self) -> Self {
+        self.public_constructor = true;
+        self
+    }
+
+    /// Document the enumerator type with `doc`, making it part of the public API. It's hidden from
+    /// the documentation by default.
+    pub fn with_enumerator_doc(mut self, doc: &'a str) -> Self {
+        self.enumerator_doc = Some(doc);
+        self
+    }
+
+    /// Override the body of the `nth` method of the `Iterator` implementation of the enumerator,
+    /// an expression of type `Option<Self::Item>` with the argument `n: usize` in scope. The
+    /// default implementation of the trait, which steps through the enumerator, is used if not
+    /// overridden.
+    pub fn override_nth(mut self, nth_fn_body: &'a TokenStream) -> Self {
+        self.nth_fn_body = Some(nth_fn_body);
+        self
+    }
+
     /// Return the target type.
     pub fn target(&self) -> &Target {
         self.enumerable_impl.target
@@ -134,12 +614,18 @@ impl<'a> EnumerableImplWithEnumerator<'a> {
 
     /// Generate the implementation of the `Enumerable` trait for the target type, and the
     /// definition of its enumerator type.
+    ///
+    /// `enumerator_from_direct` of the implementation is always overridden to call the `from_value`
+    /// method of the enumerator.
     pub fn generate(&self) -> TokenStream {
-        let enumerable_impl = self.enumerable_impl.generate();
+        let enumerator_type = self.target().enumerator_type();
+        let enumerator_from_direct = quote!(<#enumerator_type>::from_value(value));
+        let enumerable_impl = self
+            .enumerable_impl
+            .generate_with_enumerator_from_direct(Some(&enumerator_from_direct));
 
         let vis = self.target().visibility();
         let target_type = self.target().target_type();
-        let enumerator_type = self.target().enumerator_type();
         let enumerator_type_bounded = self.target().enumerator_type_bounded();
         let where_clause = self.target().where_clause();
         let impl_generics = self.target().generic_params_full();
@@ -148,33 +634,89 @@ impl<'a> EnumerableImplWithEnumerator<'a> {
         let enumerator_new_fn_body = &self.enumerator_info.new_fn_body;
         let enumerator_step_fn_body = &self.enumerator_info.step_fn_body;
         let enumerator_next_to_yield_fn_body = &self.enumerator_info.next_to_yield_fn_body;
+        let enumerator_from_value_fn_body = &self.enumerator_info.from_value_fn_body;
+        let enumerator_size_hint_fn_body = &self.enumerator_info.size_hint_fn_body;
+        let enumerator_exact_size_impl = match &self.enumerable_impl.exact_size {
+            Some(predicates) => {
+                let where_clause = self.target().where_clause_with(predicates);
+
+                quote!(
+                    #[automatically_derived]
+                    impl #impl_generics ::core::iter::ExactSizeIterator for #enumerator_type #where_clause {}
+                )
+            }
+            None => TokenStream::new(),
+        };
+        let enumerator_doc = match self.enumerator_doc {
+            Some(doc) => quote!(#[doc = #doc]),
+            None => quote!(#[doc(hidden)]),
+        };
+        let enumerator_nth_fn = match self.nth_fn_body {
+            Some(nth_fn_body) => quote!(
+                #[inline]
+                fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                    #nth_fn_body
+                }
+            ),
+            None => TokenStream::new(),
+        };
+        let (enumerator_new_fn_signature, enumerator_default_impl) = if self.public_constructor {
+            (
+                quote!(
+                    /// Creates a new enumerator, yielding the same values in the same order as
+                    /// `Enumerable::enumerator` of the enumerated type.
+                    #[inline]
+                    pub fn new() -> Self
+                ),
+                quote!(
+                    impl #impl_generics ::core::default::Default for #enumerator_type #where_clause {
+                        #[inline]
+                        fn default() -> Self {
+                            Self::new()
+                        }
+                    }
+                ),
+            )
+        } else {
+            (quote!(#[inline] fn new() -> Self), TokenStream::new())
+        };
 
         quote!(
             #enumerable_impl
 
-            #[doc(hidden)]
+            #enumerator_doc
             #vis #enumerator_keyword #enumerator_type_bounded #where_clause {
                 #enumerator_body
             }
 
             impl #impl_generics #enumerator_type #where_clause {
-                fn new() -> Self {
+                #enumerator_new_fn_signature {
                     #enumerator_new_fn_body
                 }
 
+                #[inline]
                 fn step(&mut self) {
                     #enumerator_step_fn_body
                 }
 
+                #[inline]
                 fn next_to_yield(&self) -> Option<#target_type> {
                     #enumerator_next_to_yield_fn_body
                 }
+
+                #[inline]
+                fn from_value(value: #target_type) -> Option<Self> {
+                    #enumerator_from_value_fn_body
+                }
             }
 
+            #enumerator_default_impl
+
             #[automatically_derived]
             impl #impl_generics ::core::iter::Iterator for #enumerator_type #where_clause {
                 type Item = #target_type;
 
+                #[inline]
                 fn next(&mut self) -> Option<Self::Item> {
                     // `Option::inspect` is not available until Rust 1.76.0.
                     self.next_to_yield().map(|item| {
@@ -182,7 +724,16 @@ impl<'a> EnumerableImplWithEnumerator<'a> {
                         item
                     })
                 }
+
+                #[inline]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    #enumerator_size_hint_fn_body
+                }
+
+                #enumerator_nth_fn
             }
+
+            #enumerator_exact_size_impl
         )
     }
 }
@@ -0,0 +1,56 @@
+//! Evaluation of enum discriminants, for `#[enumerable(sort_by_discriminant)]`.
+
+use syn::{Expr, ExprLit, ExprUnary, ItemEnum, Lit, UnOp, Variant};
+
+use crate::errors::Errors;
+
+/// Evaluates an explicit discriminant expression.
+///
+/// Only integer literals, optionally negated or parenthesized, are supported, as evaluating
+/// arbitrary const expressions is not possible in a proc-macro.
+fn evaluate_discriminant_expr(expr: &Expr) -> syn::Result<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => evaluate_discriminant_expr(expr).map(|value| -value),
+        Expr::Paren(paren) => evaluate_discriminant_expr(&paren.expr),
+        Expr::Group(group) => evaluate_discriminant_expr(&group.expr),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "cannot evaluate this discriminant, only integer literals are supported by `sort_by_discriminant`",
+        )),
+    }
+}
+
+/// Returns the variants of a fieldless enum sorted by their discriminants.
+///
+/// Implicit discriminants are evaluated as Rust does: zero for the first variant, and one more
+/// than the previous variant otherwise. All discriminants that can't be evaluated are reported.
+pub fn variants_sorted_by_discriminant(e: &ItemEnum) -> syn::Result<Vec<&Variant>> {
+    let mut errors = Errors::new();
+    let mut variants = Vec::with_capacity(e.variants.len());
+    let mut next_implicit = 0i128;
+
+    for variant in &e.variants {
+        let discriminant = match &variant.discriminant {
+            Some((_, expr)) => match errors.take(evaluate_discriminant_expr(expr)) {
+                Some(value) => value,
+                None => continue,
+            },
+            None => next_implicit,
+        };
+
+        next_implicit = discriminant.wrapping_add(1);
+        variants.push((discriminant, variant));
+    }
+
+    errors.finish(())?;
+
+    variants.sort_by_key(|(discriminant, _)| *discriminant);
+    Ok(variants.into_iter().map(|(_, variant)| variant).collect())
+}
@@ -0,0 +1,53 @@
+//! Accumulation of errors found while deriving `Enumerable`.
+
+use std::fmt::Display;
+
+use proc_macro2::Span;
+
+/// A list of spanned errors, reported together in a single expansion.
+///
+/// Checks that are independent of each other should push their errors here instead of returning
+/// on the first one, so users can fix all problems of a type in one compilation.
+#[derive(Default)]
+pub struct Errors {
+    combined: Option<syn::Error>,
+}
+
+impl Errors {
+    /// Creates an empty error list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an error to the list.
+    pub fn push(&mut self, error: syn::Error) {
+        match &mut self.combined {
+            Some(combined) => combined.combine(error),
+            None => self.combined = Some(error),
+        }
+    }
+
+    /// Appends an error with the given span and message to the list.
+    pub fn push_spanned(&mut self, span: Span, message: impl Display) {
+        self.push(syn::Error::new(span, message));
+    }
+
+    /// Unwraps a result, appending the error to the list if it's an `Err`.
+    pub fn take<T>(&mut self, result: syn::Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+                None
+            }
+        }
+    }
+
+    /// Returns `Ok(value)` if no errors have been pushed, or all errors combined otherwise.
+    pub fn finish<T>(self, value: T) -> syn::Result<T> {
+        match self.combined {
+            Some(combined) => Err(combined),
+            None => Ok(value),
+        }
+    }
+}
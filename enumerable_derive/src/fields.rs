@@ -2,6 +2,8 @@ use proc_macro2::{Span, TokenStream};
 use quote::quote;
 use syn::{Field, Fields, Ident};
 
+use crate::attributes::FieldAttributes;
+
 /// An identifier or an index.
 ///
 /// Used to represent a field in a field list.
@@ -11,7 +13,7 @@ pub enum IdentOrIndex<'a> {
 }
 
 /// Returns the name of a field or its index if it's from a list of unnamed fields.
-fn field_name_or_index(index: usize, field: &Field) -> IdentOrIndex {
+fn field_name_or_index(index: usize, field: &Field) -> IdentOrIndex<'_> {
     field
         .ident
         .as_ref()
@@ -29,43 +31,77 @@ pub struct FieldToEnumerate {
 /// A list of fields that need to be enumerated.
 pub struct FieldsToEnumerate {
     pub fields: Vec<FieldToEnumerate>,
+    /// Constructs a value from the field references, with skipped fields set to their defaults.
     pub binder: TokenStream,
+    /// Destructures a value into the field references, ignoring skipped fields.
+    pub pattern: TokenStream,
 }
 
 impl FieldsToEnumerate {
     /// Create a new `FieldsToEnumerate` from a [`Fields`] instance.
+    ///
+    /// Fields skipped by `#[enumerable(skip, default = ...)]` are not enumerated. Their attributes
+    /// are expected to be validated by [`FieldAttributes::enumerated_fields`] beforehand.
     pub fn from_fields(
         fields: &Fields,
         mut field_ref_naming: impl FnMut(IdentOrIndex) -> Ident,
         mut enumerator_ref_naming: impl FnMut(IdentOrIndex) -> Ident,
     ) -> Self {
-        let fields_to_enumerate: Vec<_> = fields
-            .iter()
-            .enumerate()
-            .map(|(index, field)| {
-                let field_ref = field_ref_naming(field_name_or_index(index, field));
-                let enumerator_ref = enumerator_ref_naming(field_name_or_index(index, field));
-                let field_type = &field.ty;
-
-                FieldToEnumerate {
-                    field_ref,
-                    field_type: quote!(#field_type),
-                    enumerator_ref,
+        let mut fields_to_enumerate = vec![];
+        let mut binder_items = vec![];
+        let mut pattern_items = vec![];
+
+        for (index, field) in fields.iter().enumerate() {
+            let default = FieldAttributes::from_attrs(&field.attrs)
+                .ok()
+                .and_then(|attributes| attributes.skip.and(attributes.default));
+
+            if let Some(default) = default {
+                match &field.ident {
+                    Some(name) => binder_items.push(quote!(#name: #default)),
+                    None => {
+                        binder_items.push(quote!(#default));
+                        pattern_items.push(quote!(_));
+                    }
                 }
-            })
-            .collect();
 
-        let field_refs = fields_to_enumerate.iter().map(|field| &field.field_ref);
+                continue;
+            }
+
+            let field_ref = field_ref_naming(field_name_or_index(index, field));
+            let enumerator_ref = enumerator_ref_naming(field_name_or_index(index, field));
+            let field_type = &field.ty;
+
+            binder_items.push(quote!(#field_ref));
+            pattern_items.push(quote!(#field_ref));
+            fields_to_enumerate.push(FieldToEnumerate {
+                field_ref,
+                field_type: quote!(#field_type),
+                enumerator_ref,
+            });
+        }
 
-        let binder = if let Fields::Unnamed(_) = fields {
-            quote!(( #(#field_refs),* ))
+        let (binder, pattern) = if let Fields::Unnamed(_) = fields {
+            (
+                quote!(( #(#binder_items),* )),
+                quote!(( #(#pattern_items),* )),
+            )
+        } else if binder_items.len() > pattern_items.len() {
+            (
+                quote!({ #(#binder_items),* }),
+                quote!({ #(#pattern_items,)* .. }),
+            )
         } else {
-            quote!({ #(#field_refs),* })
+            (
+                quote!({ #(#binder_items),* }),
+                quote!({ #(#pattern_items),* }),
+            )
         };
 
         Self {
             fields: fields_to_enumerate,
             binder,
+            pattern,
         }
     }
 
@@ -89,6 +125,7 @@ impl FieldsToEnumerate {
 
         Self {
             fields: fields_to_enumerate,
+            pattern: binder.clone(),
             binder,
         }
     }
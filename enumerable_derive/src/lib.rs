@@ -1,16 +1,20 @@
 #![doc = include_str!("./IMPL_DETAIL.md")]
 
 use proc_macro::TokenStream as TokenStream1;
-use proc_macro2::{Ident, TokenStream};
-use quote::{format_ident, quote, quote_spanned, ToTokens, TokenStreamExt};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use syn::{spanned::Spanned, Item, ItemEnum, ItemStruct};
 
+mod attributes;
 mod code_gen;
+mod discriminants;
+mod errors;
 mod fields;
 mod size_option;
 mod targets;
 mod tuples;
 
+use attributes::{FieldAttributes, VariantAttributes};
 use code_gen::{
     enumerable_impl, enumerable_impl_with_enumerator, EnumeratorInfo, EnumeratorKeyword,
 };
@@ -18,48 +22,169 @@ use fields::{FieldToEnumerate, FieldsToEnumerate, IdentOrIndex};
 use size_option::SizeOption;
 use targets::Target;
 
-/// Implements the `Enumerable` trait for an empty type.
-fn impl_enumerable_for_empty_type(target: &Target) -> TokenStream {
+/// Implements the `Enumerable` trait for a type without values to enumerate.
+///
+/// If `has_skipped` is `true`, the type has values of variants skipped by `#[enumerable(skip)]`,
+/// which have neither next nor previous values, and position enumerators past the end.
+fn impl_enumerable_for_empty_type(target: &Target, has_skipped: bool) -> TokenStream {
+    let (adjacent_value_direct, enumerator_from_direct, to_index_direct) = if has_skipped {
+        (
+            quote!(Some(None)),
+            quote!(Some(core::iter::empty())),
+            quote!(None),
+        )
+    } else {
+        (
+            quote!(match self {}),
+            quote!(match value {}),
+            quote!(match *value {}),
+        )
+    };
+
     enumerable_impl(target, SizeOption::from_usize(0))
         .override_enumerator_type(&quote!(core::iter::Empty<Self>))
         .override_enumerator_creator(&quote!(core::iter::empty()))
+        .override_last_value(&quote!(None))
+        .override_is_inhabited(&quote!(false))
+        .override_next_value_direct(&adjacent_value_direct)
+        .override_previous_value_direct(&adjacent_value_direct)
+        .override_enumerator_from_direct(&enumerator_from_direct)
+        .override_to_index_direct(&to_index_direct)
+        .with_exact_size(TokenStream::new())
+        .with_double_ended()
+        .with_const_values(&quote!(&[]))
+        .with_sum(vec![])
         .generate()
 }
 
-/// Implements the `Enumerable` trait for a unit type.
-fn impl_enumerable_for_unit_type(target: &Target, value: TokenStream) -> TokenStream {
-    enumerable_impl(target, SizeOption::from_usize(1))
-        .override_enumerator_type(&quote!(core::iter::Once<Self>))
-        .override_enumerator_creator(&quote!(core::iter::once(#value)))
-        .generate()
+/// Implements the `Enumerable` trait for a unit type, whose only value is `value`.
+///
+/// If `has_skipped` is `true`, the type has fields skipped by `#[enumerable(skip, default = ...)]`,
+/// whose defaults may not be constant, so `ConstEnumerable` isn't implemented. Values with other
+/// skipped fields are treated as `value`, like in structs with fields to enumerate.
+fn impl_enumerable_for_unit_type(
+    target: &Target,
+    value: TokenStream,
+    has_skipped: bool,
+) -> TokenStream {
+    let enumerator_type = quote!(core::iter::Once<Self>);
+    let enumerator_creator = quote!(core::iter::once(#value));
+    let last_value = quote!(Some(#value));
+    let adjacent_value_direct = quote!(Some(None));
+    let enumerator_from_direct = quote!(Some(core::iter::once(#value)));
+    let to_index_direct = quote!(Some(0));
+    let const_values = quote!(&[#value]);
+
+    let enumerable_impl = enumerable_impl(target, SizeOption::from_usize(1))
+        .override_enumerator_type(&enumerator_type)
+        .override_enumerator_creator(&enumerator_creator)
+        .override_last_value(&last_value)
+        .override_next_value_direct(&adjacent_value_direct)
+        .override_previous_value_direct(&adjacent_value_direct)
+        .override_enumerator_from_direct(&enumerator_from_direct)
+        .override_to_index_direct(&to_index_direct)
+        .with_exact_size(TokenStream::new())
+        .with_double_ended();
+
+    if has_skipped {
+        enumerable_impl.generate()
+    } else {
+        enumerable_impl.with_const_values(&const_values).generate()
+    }
 }
 
-/// Implements the `Enumerable` trait for an enum without fields.
+/// Implements the `Enumerable` trait for an enum whose variants to enumerate, `vars`, have no
+/// fields. `has_skipped` tells whether other variants are skipped by `#[enumerable(skip)]`.
 ///
-/// It calls `impl_enumerable_for_empty_type` if the enum has no variants.
+/// It calls `impl_enumerable_for_empty_type` if there are no variants to enumerate.
 // TODO: should we keep using a const ref to a static array or replace it with a state-machine?
 fn impl_enumerable_for_plain_enum<'a>(
     target: &'a Target,
     vars: impl Iterator<Item = &'a Ident>,
+    has_skipped: bool,
 ) -> TokenStream {
     let target_type = target.target_type_name();
+    let enumerable_trait_path = target.enumerable_trait_path();
     let vars: Vec<_> = vars.collect();
     let vars_count = vars.len();
 
     if vars_count == 0 {
-        return impl_enumerable_for_empty_type(target);
+        return impl_enumerable_for_empty_type(target, has_skipped);
     }
 
+    // values of skipped variants have no adjacent values, and position enumerators past the end
+    let (skipped_adjacent_arm, skipped_index_arm) = if has_skipped {
+        (quote!(_ => None,), quote!(_ => #vars_count,))
+    } else {
+        (TokenStream::new(), TokenStream::new())
+    };
+    // values of skipped variants have no indices, like `skipped_adjacent_arm`
+    let to_index_arms = (0..vars_count as u128).map(|index| quote!(Some(#index)));
+
+    let last_var = vars[vars_count - 1];
+    let next_vars = vars[1..]
+        .iter()
+        .map(|var| quote!(Some(#target_type::#var)))
+        .chain(core::iter::once(quote!(None)));
+    let next_value_direct = quote!(
+        Some(match self {
+            #( #target_type::#vars => #next_vars, )*
+            #skipped_adjacent_arm
+        })
+    );
+    let previous_vars = core::iter::once(quote!(None)).chain(
+        vars[..vars_count - 1]
+            .iter()
+            .map(|var| quote!(Some(#target_type::#var))),
+    );
+    let previous_value_direct = quote!(
+        Some(match self {
+            #( #target_type::#vars => #previous_vars, )*
+            #skipped_adjacent_arm
+        })
+    );
+    // the enumerator is a slice iterator, so it's positioned in O(1) time
+    let indices = 0..vars_count;
+    let enumerator_from_direct = quote!(
+        Some(<Self as #enumerable_trait_path>::enumerator_since(match value {
+            #( #target_type::#vars => #indices, )*
+            #skipped_index_arm
+        }))
+    );
+    let to_index_direct = quote!(
+        match value {
+            #( #target_type::#vars => #to_index_arms, )*
+            #skipped_adjacent_arm
+        }
+    );
+
     enumerable_impl(target, SizeOption::from_usize(vars_count))
+        .override_last_value(&quote!(Some(#target_type::#last_var)))
+        .override_next_value_direct(&next_value_direct)
+        .override_previous_value_direct(&previous_value_direct)
+        .override_enumerator_from_direct(&enumerator_from_direct)
+        .override_to_index_direct(&to_index_direct)
         .override_enumerator_type(&quote!(
-            core::iter::Copied<core::slice::Iter<'static, Self>>
+            core::iter::Cloned<core::slice::Iter<'static, Self>>
         ))
         .override_enumerator_creator(&quote!(
             {
-                const ALL_VARIANTS: &[#target_type; #vars_count] = &[#(#target_type::#vars),*];
-                ALL_VARIANTS.iter().copied()
+                // a promoted constant rather than a `const` item, which couldn't name the
+                // generic parameters of `Self`
+                let all_variants: &'static [Self; #vars_count] = &[#(#target_type::#vars),*];
+                all_variants.iter().cloned()
+            }
+        ))
+        .override_enumerator_since(&quote!(
+            {
+                let all_variants: &'static [Self; #vars_count] = &[#(#target_type::#vars),*];
+                all_variants[start.min(#vars_count)..].iter().cloned()
             }
         ))
+        .with_exact_size(TokenStream::new())
+        .with_double_ended()
+        .with_const_values(&quote!(&[#(#target_type::#vars),*]))
+        .with_sum(vec![quote!(Some(1u128)); vars_count])
         .generate()
 }
 
@@ -149,6 +274,523 @@ fn generate_init_for_fields<'a>(
     )
 }
 
+/// Generate the code fragment which positions the enumerators of the fields right after the
+/// values bound to the fields, returning `None` early if any of them can't be positioned directly.
+/// It evaluates to `#on_positioned`, with the enumerators bound.
+fn generate_from_value_for_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    on_positioned: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    let mut field_refs = vec![];
+    let mut field_types = vec![];
+    let mut enumerator_refs = vec![];
+
+    for FieldToEnumerate {
+        field_ref,
+        field_type,
+        enumerator_ref,
+    } in fields
+    {
+        field_refs.push(field_ref);
+        field_types.push(field_type);
+        enumerator_refs.push(enumerator_ref);
+    }
+
+    quote!({
+        // unreachable_code will be triggered on uninhabited fields
+        #[allow(unreachable_code)]
+        {
+            #(
+                let mut #enumerator_refs =
+                    <#field_types as #enumerable_trait_path>::enumerator_from_direct(
+                        ::core::clone::Clone::clone(&#field_refs),
+                    )?;
+                #enumerator_refs.next();
+            )*
+            #on_positioned
+        }
+    })
+}
+
+/// Generate the expression of type `(usize, Option<usize>)` which evaluates to the bounds on the
+/// number of values left to yield by an enumerator of a list of fields, i.e. the value made of the
+/// current values of the fields, the values the field enumerators can still be stepped to, and
+/// `#later` values after them, an expression of type `Option<usize>` which is `None` if unknown.
+///
+/// `enumerator` maps the reference to the enumerator of a field to an expression of type
+/// `&Enumerator`. The bounds are exact if all field enumerators report exact size hints.
+fn generate_size_hint_for_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    enumerator: impl Fn(&Ident) -> TokenStream,
+    later: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    let (enumerators, field_types): (Vec<_>, Vec<_>) = fields
+        .map(|field| (enumerator(&field.enumerator_ref), &field.field_type))
+        .unzip();
+    let field_count = field_types.len();
+
+    quote!({
+        // the size hint of each field enumerator, and the size of the field
+        let fields: [((usize, Option<usize>), Option<usize>); #field_count] = [
+            #((
+                ::core::iter::Iterator::size_hint(#enumerators),
+                <#field_types as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION,
+            ),)*
+        ];
+        let later: Option<usize> = #later;
+
+        let mut lower = later.unwrap_or(0).saturating_add(1);
+        let mut upper = later.and_then(|later| later.checked_add(1));
+        // the number of values a step of the current field skips, i.e. the product of the sizes of
+        // all later fields, which are at least 1 as the current values exist
+        let mut scale_lower: usize = 1;
+        let mut scale_upper: Option<usize> = Some(1);
+        for &((field_lower, field_upper), size) in fields.iter().rev() {
+            lower = lower.saturating_add(field_lower.saturating_mul(scale_lower));
+            upper = match (upper, field_upper, scale_upper) {
+                (Some(upper), Some(field_upper), Some(scale)) => field_upper
+                    .checked_mul(scale)
+                    .and_then(|skipped| upper.checked_add(skipped)),
+                _ => None,
+            };
+            scale_lower = scale_lower.saturating_mul(size.unwrap_or(1));
+            scale_upper = match (scale_upper, size) {
+                (Some(scale), Some(size)) => scale.checked_mul(size),
+                _ => None,
+            };
+        }
+
+        (lower, upper)
+    })
+}
+
+/// Generate the expression of type `Option<_>` which evaluates to the last value of a list of
+/// fields, `Some(#construct)` with fields bound to their last values, or `None` if any field is
+/// uninhabited.
+fn generate_last_value_for_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    construct: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    generate_value_for_fields_by(fields, construct, enumerable_trait_path, quote!(last_value))
+}
+
+/// Generate the expression of type `Option<_>` which evaluates to the first value of a list of
+/// fields, `Some(#construct)` with fields bound to their first values, or `None` if any field is
+/// uninhabited.
+fn generate_first_value_for_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    construct: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    generate_value_for_fields_by(
+        fields,
+        construct,
+        enumerable_trait_path,
+        quote!(first_value),
+    )
+}
+
+/// Generate the expression of type `Option<_>` which evaluates to `Some(#construct)` with fields
+/// bound to the values returned by `#method` (`first_value` or `last_value`) of their types, or
+/// `None` if any of them returns `None`.
+fn generate_value_for_fields_by<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    construct: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+    method: TokenStream,
+) -> TokenStream {
+    let (field_refs, field_types): (Vec<_>, Vec<_>) = fields
+        .map(|field| (&field.field_ref, &field.field_type))
+        .unzip();
+
+    quote!({
+        #(
+            let #field_refs = <#field_types as #enumerable_trait_path>::#method();
+        )*
+
+        // unreachable_patterns and unreachable_code will be triggered on uninhabited fields
+        #[allow(unreachable_patterns, unreachable_code)]
+        // unused_parens will be triggered if there is only one field
+        #[allow(unused_parens)]
+        match (#( #field_refs ),*) {
+            ( #(Some(#field_refs)),* ) => Some(#construct),
+            _ => None,
+        }
+    })
+}
+
+/// Generate the expression of type `Option<Option<_>>` for `next_value_direct` on a list of fields
+/// bound to their current values. The last field is advanced first, and reset to its first value
+/// to carry over to the previous one if it's already the last value.
+///
+/// It evaluates to `Some(Some(#construct))` with fields bound to the next values,
+/// `Some(#on_overflow)` if all fields are at their last values, or `None` if any field can't tell
+/// its next value directly.
+fn generate_next_value_for_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    construct: TokenStream,
+    on_overflow: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    generate_adjacent_value_for_fields_by(
+        fields,
+        construct,
+        on_overflow,
+        enumerable_trait_path,
+        quote!(next_value_direct),
+        quote!(first_value),
+    )
+}
+
+/// Generate the expression of type `Option<Option<_>>` for `previous_value_direct` on a list of
+/// fields bound to their current values. The last field is moved back first, and reset to its last
+/// value to borrow from the previous one if it's already the first value.
+///
+/// It evaluates to `Some(Some(#construct))` with fields bound to the previous values,
+/// `Some(#on_underflow)` if all fields are at their first values, or `None` if any field can't tell
+/// its previous value directly.
+fn generate_previous_value_for_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    construct: TokenStream,
+    on_underflow: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    generate_adjacent_value_for_fields_by(
+        fields,
+        construct,
+        on_underflow,
+        enumerable_trait_path,
+        quote!(previous_value_direct),
+        quote!(last_value),
+    )
+}
+
+/// Generate the expression of type `Option<Option<_>>` which steps a list of fields with `#step`
+/// (`next_value_direct` or `previous_value_direct`), from the last field to the first one. A field
+/// that can't be stepped further is reset by `#reset` (`first_value` or `last_value`), and the
+/// previous field is stepped instead. It evaluates to `Some(#on_exhausted)` if no fields can be
+/// stepped.
+fn generate_adjacent_value_for_fields_by<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    construct: TokenStream,
+    on_exhausted: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+    step: TokenStream,
+    reset: TokenStream,
+) -> TokenStream {
+    let fields: Vec<_> = fields.collect();
+    let mut result = TokenStream::new();
+
+    for (
+        index,
+        FieldToEnumerate {
+            field_ref,
+            field_type,
+            ..
+        },
+    ) in fields.into_iter().enumerate().rev()
+    {
+        result.append_all(quote!(
+            if let Some(#field_ref) = <#field_type as #enumerable_trait_path>::#step(#field_ref)? {
+                return Some(Some(#construct));
+            }
+        ));
+
+        // the first field is never reset, as there's nothing left to step
+        if index > 0 {
+            result.append_all(quote!(
+                let #field_ref = <#field_type as #enumerable_trait_path>::#reset()?;
+            ));
+        }
+    }
+
+    quote!({
+        // unreachable_code will be triggered on uninhabited fields
+        #[allow(unreachable_code)]
+        {
+            #result
+            Some(#on_exhausted)
+        }
+    })
+}
+
+/// Generate the constant expression of type `bool` which evaluates to whether all fields in a list
+/// are inhabited, i.e. whether values can be constructed from them.
+fn generate_is_inhabited_for_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    let field_types = fields.map(|field| &field.field_type);
+
+    quote!(true #( && <#field_types as #enumerable_trait_path>::IS_INHABITED )*)
+}
+
+/// The names of the local variables used in the generated `enumerable_from_index_u128` and
+/// `enumerable_to_index_direct` methods, the index left to decode (or encoded so far) and the size
+/// of the current field. They are hygienic, so they never
+/// clash with the references to the fields.
+fn from_index_locals() -> (Ident, Ident) {
+    (
+        Ident::new("rest", Span::mixed_site()),
+        Ident::new("size", Span::mixed_site()),
+    )
+}
+
+/// Generate the statement which returns `None` early from the generated `enumerable_to_index_direct`
+/// methods if the size of the target type is unknown or exceeds `u128::MAX`. Indices of values of
+/// fields or variants never overflow after it.
+fn generate_to_index_preamble(enumerable_trait_path: impl ToTokens) -> TokenStream {
+    quote!(
+        <Self as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION_U128?;
+    )
+}
+
+/// Generate the expression of type `Option<u128>` which evaluates to the index of the values of a
+/// list of fields in their lexicographic ordering, with references to the fields bound to the
+/// references to the fields, or `None` if the index of any field can't be computed directly.
+///
+/// The product of the sizes of the fields must fit in `u128`, see
+/// [`generate_to_index_preamble`].
+fn generate_to_index_for_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    let (rest, size) = from_index_locals();
+
+    // the last field varies the fastest, so it's encoded last
+    let encode = fields.map(|field| {
+        let field_ref = &field.field_ref;
+        let field_type = &field.field_type;
+
+        quote!(
+            let #size = <#field_type as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION_U128?;
+            let #rest = #rest * #size
+                + <#field_type as #enumerable_trait_path>::enumerable_to_index_direct(#field_ref)?;
+        )
+    });
+
+    quote!({
+        let #rest: u128 = 0;
+        #( #encode )*
+        Some(#rest)
+    })
+}
+
+/// Generate the statement which binds the index to decode in the generated
+/// `enumerable_from_index_u128` methods, returning early if the index is out of range, or if the
+/// size of the target type is unknown, in which case the fields can't be indexed and the enumerator
+/// is stepped through.
+fn generate_from_index_preamble(enumerable_trait_path: impl ToTokens) -> TokenStream {
+    let (rest, _) = from_index_locals();
+
+    quote!(
+        let #rest: u128 = match <Self as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) if index < size => index,
+            Some(_) => return None,
+            None => {
+                return usize::try_from(index)
+                    .ok()
+                    .and_then(|index| <Self as #enumerable_trait_path>::enumerator().nth(index))
+            }
+        };
+    )
+}
+
+/// Generate the body of the `enumerable_from_index` method delegating to
+/// `enumerable_from_index_u128`.
+fn generate_from_index_by_u128(enumerable_trait_path: impl ToTokens) -> TokenStream {
+    quote!(<Self as #enumerable_trait_path>::enumerable_from_index_u128(index as u128))
+}
+
+/// Generate the statements which decode the index bound by [`generate_from_index_preamble`] into
+/// the indices of a list of fields in their lexicographic ordering, binding each index of type
+/// `u128` to the reference to the field.
+///
+/// The index must be less than the product of the sizes of the fields, so the sizes are all known,
+/// and `#on_unknown_size`, which must diverge, is never evaluated.
+fn generate_decode_index_for_fields(
+    field_refs: &[&Ident],
+    field_types: &[&TokenStream],
+    on_unknown_size: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    let (rest, size) = from_index_locals();
+
+    // the last field varies the fastest, so it's decoded first, the first field takes the rest
+    let mut decode = TokenStream::new();
+    for (index, (field_ref, field_type)) in field_refs.iter().zip(field_types).enumerate().rev() {
+        if index == 0 {
+            decode.append_all(quote!(let #field_ref = #rest;));
+        } else {
+            decode.append_all(quote!(
+                let #size = match <#field_type as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION_U128 {
+                    Some(size) => size,
+                    None => #on_unknown_size,
+                };
+                let #field_ref = #rest % #size;
+                let #rest = #rest / #size;
+            ));
+        }
+    }
+
+    decode
+}
+
+/// Generate the expression of type `Option<_>` which evaluates to the value of a list of fields at
+/// the index bound by [`generate_from_index_preamble`] in their lexicographic ordering,
+/// `Some(#construct)` with fields bound to their values.
+///
+/// The index must be less than the product of the sizes of the fields, so the sizes are all known.
+fn generate_from_index_for_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    construct: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    let (field_refs, field_types): (Vec<_>, Vec<_>) = fields
+        .map(|field| (&field.field_ref, &field.field_type))
+        .unzip();
+    let decode = generate_decode_index_for_fields(
+        &field_refs,
+        &field_types,
+        quote!(return None),
+        &enumerable_trait_path,
+    );
+
+    quote!({
+        #decode
+
+        // unreachable_patterns will be triggered on uninhabited fields
+        #[allow(unreachable_patterns)]
+        // unused_parens will be triggered if there is only one field
+        #[allow(unused_parens)]
+        match (#( <#field_types as #enumerable_trait_path>::enumerable_from_index_u128(#field_refs) ),*) {
+            ( #(Some(#field_refs)),* ) => Some(#construct),
+            _ => None,
+        }
+    })
+}
+
+/// Generate the statement which binds the index to decode in the generated `enumerator_since`
+/// methods, returning `#on_out_of_range` early if `start` is out of range. If the size of the
+/// target type is unknown, the fields can't be indexed, so a fresh enumerator is stepped through
+/// and returned instead.
+fn generate_enumerator_since_preamble(
+    on_out_of_range: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    let (rest, _) = from_index_locals();
+
+    quote!(
+        let #rest: u128 = match <Self as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) if (start as u128) < size => start as u128,
+            Some(_) => return #on_out_of_range,
+            None => {
+                let mut enumerator = <Self as #enumerable_trait_path>::enumerator();
+                if start > 0 {
+                    enumerator.nth(start - 1);
+                }
+                return enumerator;
+            }
+        };
+    )
+}
+
+/// Generate the expression which evaluates to `#on_positioned` with the enumerators of a list of
+/// fields positioned right after the values of the fields at the index bound by
+/// [`generate_enumerator_since_preamble`], and the fields bound to these values. Each field
+/// enumerator is positioned by `enumerator_since` at the decoded index of its field, so nothing is
+/// replayed from the start.
+fn generate_enumerator_since_for_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    on_positioned: TokenStream,
+    on_out_of_range: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    let mut field_refs = vec![];
+    let mut field_types = vec![];
+    let mut enumerator_refs = vec![];
+
+    for FieldToEnumerate {
+        field_ref,
+        field_type,
+        enumerator_ref,
+    } in fields
+    {
+        field_refs.push(field_ref);
+        field_types.push(field_type);
+        enumerator_refs.push(enumerator_ref);
+    }
+
+    let decode = generate_decode_index_for_fields(
+        &field_refs,
+        &field_types,
+        quote!(return #on_out_of_range),
+        &enumerable_trait_path,
+    );
+
+    quote!({
+        #decode
+
+        // the index of each field is less than `start`, so it fits in `usize`
+        #(
+            let mut #enumerator_refs =
+                <#field_types as #enumerable_trait_path>::enumerator_since(#field_refs as usize);
+            let #field_refs = #enumerator_refs.next();
+        )*
+
+        // unreachable_patterns will be triggered on uninhabited fields
+        #[allow(unreachable_patterns)]
+        // unused_parens will be triggered if there is only one field
+        #[allow(unused_parens)]
+        match (#( #field_refs ),*) {
+            ( #(Some(#field_refs)),* ) => #on_positioned,
+            _ => #on_out_of_range,
+        }
+    })
+}
+
+/// Generate the body of the `from_field_indices` method of `ProductEnumerable`, an expression of
+/// type `Option<_>` which evaluates to `Some(#construct)` with fields bound to their values at the
+/// indices in `indices`, or `None` if any index is out of range.
+fn generate_from_field_indices_for_fields<'a>(
+    fields: impl Iterator<Item = &'a FieldToEnumerate>,
+    construct: TokenStream,
+    enumerable_trait_path: impl ToTokens,
+) -> TokenStream {
+    let decode = fields.enumerate().map(|(index, field)| {
+        let field_ref = &field.field_ref;
+        let field_type = &field.field_type;
+        let index = Literal::usize_unsuffixed(index);
+
+        quote!(
+            let #field_ref =
+                <#field_type as #enumerable_trait_path>::enumerable_from_index_u128(indices[#index])?;
+        )
+    });
+
+    quote!({
+        #( #decode )*
+        Some(#construct)
+    })
+}
+
+/// The extra predicates of `ExactSizeEnumerable` implementations, one for each field type, if
+/// `#[enumerable(exact_size)]` is set.
+fn exact_size_predicates<'a>(
+    target: &Target,
+    fields: impl Iterator<Item = &'a syn::Field>,
+) -> Option<TokenStream> {
+    target.attributes().exact_size?;
+
+    let exact_size_enumerable_trait_path = target.exact_size_enumerable_trait_path();
+    let field_types = fields.map(|field| &field.ty);
+
+    Some(quote!(#( #field_types: #exact_size_enumerable_trait_path, )*))
+}
+
 /// The naming convention for the references to the fields in enumerators for them.
 fn field_ref_naming(field: IdentOrIndex) -> Ident {
     match field {
@@ -159,28 +801,70 @@ fn field_ref_naming(field: IdentOrIndex) -> Ident {
 
 /// The naming convention for the references to the enumerators of the fields in enumerators for
 /// them.
+///
+/// The field name comes first, so that names of fields with leading underscores (e.g. `_tag`)
+/// still make snake case identifiers (`_tag_enumerator`).
 fn enumerator_ref_naming(field: IdentOrIndex) -> Ident {
     match field {
-        IdentOrIndex::Name(field_name) => format_ident!("enumerator_{}", field_name),
+        IdentOrIndex::Name(field_name) => format_ident!("{}_enumerator", field_name),
         IdentOrIndex::Index(index) => format_ident!("enumerator_field_{}", index),
     }
 }
 
 /// Implements the `Enumerable` trait for an enum.
-fn impl_enumerable_for_enum(e: ItemEnum) -> Result<TokenStream, TokenStream> {
-    let target = Target::new_for_enum(&e)?;
+fn impl_enumerable_for_enum(e: ItemEnum) -> syn::Result<TokenStream> {
+    // variants skipped by `#[enumerable(skip)]` are left out of everything but exhaustive matches
+    let variants = VariantAttributes::enumerated_variants(&e.variants)?;
+    let has_skipped = variants.len() < e.variants.len();
+    // so are fields skipped by `#[enumerable(skip, default = ...)]`, which are set to the defaults
+    let fields = FieldAttributes::enumerated_fields(variants.iter().flat_map(|v| &v.fields))?;
+    let target = Target::new_for_enum(&e, &fields)?;
     let ident = &e.ident;
-    let variants = &e.variants;
 
     let enumerable_trait_path = target.enumerable_trait_path();
 
-    // Call `impl_enumerable_for_empty_type` if the enum has no fields.
+    if let Some(span) = target.attributes().diagonal {
+        return Err(syn::Error::new(
+            span,
+            "`diagonal` is only supported on structs",
+        ));
+    }
+
+    let variants = match target.attributes().sort_by_discriminant {
+        Some(span) => {
+            if variants.iter().any(|v| !v.fields.is_empty()) {
+                return Err(syn::Error::new(
+                    span,
+                    "`sort_by_discriminant` is only supported on enums without fields",
+                ));
+            }
+
+            // discriminants of skipped variants are evaluated as well, as later implicit ones
+            // follow
+            discriminants::variants_sorted_by_discriminant(&e)?
+                .into_iter()
+                .filter(|v| {
+                    variants
+                        .iter()
+                        .any(|enumerated| core::ptr::eq(*enumerated, *v))
+                })
+                .collect()
+        }
+        None => variants,
+    };
+
+    // Call `impl_enumerable_for_plain_enum` if the enum has no fields.
     //
-    // This if covers empty enums also.
-    if variants.iter().all(|v| v.fields.is_empty()) {
+    // This if covers empty enums also. Its enumerator borrows a static array of the variants, so
+    // enums with type or lifetime parameters, possible if the variants using them are skipped,
+    // are enumerated by the generated enumerator below unless they are empty.
+    if variants.iter().all(|v| v.fields.is_empty())
+        && (variants.is_empty() || !target.has_non_static_params())
+    {
         return Ok(impl_enumerable_for_plain_enum(
             &target,
             variants.iter().map(|v| &v.ident),
+            has_skipped,
         ));
     }
 
@@ -204,6 +888,15 @@ fn impl_enumerable_for_enum(e: ItemEnum) -> Result<TokenStream, TokenStream> {
     let variant_count = variant_idents.len();
     let first_enumerator_variant = enumerator_variant_name_before(&variant_idents[0]);
     let mut size_options = vec![];
+    let mut variant_sizes = vec![];
+    let mut last_values = vec![];
+    let mut variants_inhabited = vec![];
+    let mut first_values = vec![];
+    let mut variant_fields = vec![];
+    let mut from_index_branches = TokenStream::new();
+    let mut from_value_branches = TokenStream::new();
+    let mut to_index_branches = TokenStream::new();
+    let (rest, size) = from_index_locals();
 
     for (index, var) in variants.iter().enumerate() {
         let var_ident = &variant_idents[index];
@@ -219,15 +912,49 @@ fn impl_enumerable_for_enum(e: ItemEnum) -> Result<TokenStream, TokenStream> {
         let fields_to_enumerate =
             FieldsToEnumerate::from_fields(&var.fields, field_ref_naming, enumerator_ref_naming);
         let binder = &fields_to_enumerate.binder;
+        let pattern = &fields_to_enumerate.pattern;
         let enumerator_refs: Vec<_> = fields_to_enumerate.enumerator_refs().collect();
         let field_refs: Vec<_> = fields_to_enumerate.field_refs().collect();
         let field_types: Vec<_> = fields_to_enumerate.field_types().collect();
 
-        let field_sizes = var.fields.iter().map(|f| {
-            let ty = &f.ty;
-            SizeOption::from_type(quote!(#ty), enumerable_trait_path.clone())
-        });
-        size_options.push(SizeOption::from_product(field_sizes));
+        let field_sizes = fields_to_enumerate
+            .field_types()
+            .map(|ty| SizeOption::from_type(ty.clone(), enumerable_trait_path.clone()));
+        let variant_size = SizeOption::from_product(field_sizes);
+        let variant_from_index = generate_from_index_for_fields(
+            fields_to_enumerate.fields_iter(),
+            quote!(#ident::#var_ident #binder),
+            enumerable_trait_path.clone(),
+        );
+        // the index is less than the size of the enum, so the sizes of all variants are known
+        from_index_branches.append_all(quote!(
+            let #size = match #variant_size {
+                Some(size) => size,
+                None => return None,
+            };
+            if #rest < #size {
+                return #variant_from_index;
+            }
+        ));
+        if index < variant_count - 1 {
+            from_index_branches.append_all(quote!(let #rest = #rest - #size;));
+        }
+        variant_sizes.push(variant_size.to_token_stream());
+        size_options.push(variant_size);
+        variants_inhabited.push(generate_is_inhabited_for_fields(
+            fields_to_enumerate.fields_iter(),
+            enumerable_trait_path.clone(),
+        ));
+        last_values.push(generate_last_value_for_fields(
+            fields_to_enumerate.fields_iter(),
+            quote!(#ident::#var_ident #binder),
+            enumerable_trait_path.clone(),
+        ));
+        first_values.push(generate_first_value_for_fields(
+            fields_to_enumerate.fields_iter(),
+            quote!(#ident::#var_ident #binder),
+            enumerable_trait_path.clone(),
+        ));
 
         let step = generate_step_for_fields(
             fields_to_enumerate.fields_iter(),
@@ -263,19 +990,171 @@ fn impl_enumerable_for_enum(e: ItemEnum) -> Result<TokenStream, TokenStream> {
             },
         ));
 
+        let from_value = generate_from_value_for_fields(
+            fields_to_enumerate.fields_iter(),
+            quote!(Some(Self::#enumerator_variant_in{#(#enumerator_refs,)* #(#field_refs,)*})),
+            enumerable_trait_path.clone(),
+        );
+        from_value_branches.append_all(quote!(
+            #ident::#var_ident #pattern => #from_value,
+        ));
+
+        // values of a variant are indexed after all values of the earlier variants
+        let earlier_sizes = &variant_sizes[..index];
+        let variant_to_index = generate_to_index_for_fields(
+            fields_to_enumerate.fields_iter(),
+            enumerable_trait_path.clone(),
+        );
+        to_index_branches.append_all(quote!(
+            #ident::#var_ident #pattern => {
+                let #size: u128 = 0 #( + (#earlier_sizes)? )*;
+                Some(#size + #variant_to_index?)
+            },
+        ));
+
         current_match_branches.append_all(quote!(
             Self::#enumerator_variant_in{#(#field_refs,)* ..} => {
                 #(
-                    let #field_refs = *#field_refs;
+                    let #field_refs = ::core::clone::Clone::clone(#field_refs);
                 )*
                 Some(#ident::#var_ident #binder)
             },
         ));
+
+        variant_fields.push(fields_to_enumerate);
     }
 
     enumerator_variants.append_all(quote!(#enumerator_variant_name_done,));
 
+    // parameters used only by skipped variants would be unused in the enumerator, so an
+    // uninhabited variant uses them
+    let phantom_step_branch = if target.has_non_static_params() {
+        let target_type = target.target_type();
+        enumerator_variants.append_all(quote!(
+            Phantom(
+                ::core::convert::Infallible,
+                ::core::marker::PhantomData<fn() -> #target_type>,
+            ),
+        ));
+        quote!(Self::Phantom(never, _) => match *never {},)
+    } else {
+        TokenStream::new()
+    };
+
     let enumerable_size_option = SizeOption::from_sum(size_options.into_iter());
+    // the last value of the last inhabited variant
+    let last_values_reversed = last_values.iter().rev();
+    let last_value = quote!(None #( .or_else(|| #last_values_reversed) )*);
+    let from_index_preamble = generate_from_index_preamble(enumerable_trait_path.clone());
+    let from_index_u128 = quote!({
+        #from_index_preamble
+        #from_index_branches
+        None
+    });
+    let from_index = generate_from_index_by_u128(enumerable_trait_path.clone());
+    let is_inhabited = quote!(false #( || (#variants_inhabited) )*);
+    // the first value of the next inhabited variant if a variant overflows
+    let next_value_branches = variant_fields.iter().enumerate().map(|(index, fields)| {
+        let var_ident = &variant_idents[index];
+        let binder = &fields.binder;
+        let later_first_values = &first_values[index + 1..];
+        let next_value = generate_next_value_for_fields(
+            fields.fields_iter(),
+            quote!(#ident::#var_ident #binder),
+            quote!(None #( .or_else(|| #later_first_values) )*),
+            enumerable_trait_path.clone(),
+        );
+
+        let pattern = &fields.pattern;
+
+        quote!(#ident::#var_ident #pattern => #next_value,)
+    });
+    // values of skipped variants have no adjacent values, and position enumerators past the end
+    let (skipped_adjacent_arm, skipped_from_value_arm, skipped_to_index_arm) = if has_skipped {
+        (
+            quote!(_ => Some(None),),
+            quote!(_ => Some(Self::#enumerator_variant_name_done),),
+            quote!(_ => None,),
+        )
+    } else {
+        (TokenStream::new(), TokenStream::new(), TokenStream::new())
+    };
+    let next_value_direct = quote!(
+        match self {
+            #( #next_value_branches )*
+            #skipped_adjacent_arm
+        }
+    );
+    let to_index_preamble = generate_to_index_preamble(enumerable_trait_path.clone());
+    let to_index_direct = quote!({
+        #to_index_preamble
+        match value {
+            #to_index_branches
+            #skipped_to_index_arm
+        }
+    });
+    // the last value of the previous inhabited variant if a variant underflows
+    let previous_value_branches = variant_fields.iter().enumerate().map(|(index, fields)| {
+        let var_ident = &variant_idents[index];
+        let binder = &fields.binder;
+        let earlier_last_values = last_values[..index].iter().rev();
+        let previous_value = generate_previous_value_for_fields(
+            fields.fields_iter(),
+            quote!(#ident::#var_ident #binder),
+            quote!(None #( .or_else(|| #earlier_last_values) )*),
+            enumerable_trait_path.clone(),
+        );
+
+        let pattern = &fields.pattern;
+
+        quote!(#ident::#var_ident #pattern => #previous_value,)
+    });
+    let previous_value_direct = quote!(
+        match self {
+            #( #previous_value_branches )*
+            #skipped_adjacent_arm
+        }
+    );
+    // the values of later variants are left to yield after the current one
+    let size_hint_branches = variant_fields.iter().enumerate().map(|(index, fields)| {
+        let enumerator_variant_in = &enumerator_variant_names_in[index];
+        let enumerator_refs = fields.enumerator_refs();
+        let later_sizes = &variant_sizes[index + 1..];
+        let size_hint = generate_size_hint_for_fields(
+            fields.fields_iter(),
+            |enumerator_ref| quote!(#enumerator_ref),
+            quote!({
+                let later: Option<u128> = Some(0);
+                #(
+                    let later = match (later, #later_sizes) {
+                        (Some(later), Some(size)) => later.checked_add(size),
+                        _ => None,
+                    };
+                )*
+                match later {
+                    Some(later) if later <= usize::MAX as u128 => Some(later as usize),
+                    _ => None,
+                }
+            }),
+            enumerable_trait_path.clone(),
+        );
+
+        quote!(Self::#enumerator_variant_in{#(#enumerator_refs,)* ..} => #size_hint,)
+    });
+    let size_hint = quote!(
+        match self {
+            #( #size_hint_branches )*
+            // `Before*` states are never left after stepping
+            _ => (0, Some(0)),
+        }
+    );
+    // enumerators of field-less enums are always exact
+    let exact_size_predicates = if fields.is_empty() {
+        Some(TokenStream::new())
+    } else {
+        exact_size_predicates(&target, fields.iter().copied())
+    };
+
     let impl_ = enumerable_impl_with_enumerator(
         &target,
         enumerable_size_option,
@@ -292,6 +1171,7 @@ fn impl_enumerable_for_enum(e: ItemEnum) -> Result<TokenStream, TokenStream> {
                     match self {
                         #step_match_branches
                         Self::#enumerator_variant_name_done => {},
+                        #phantom_step_branch
                     }
 
                     break;
@@ -303,31 +1183,72 @@ fn impl_enumerable_for_enum(e: ItemEnum) -> Result<TokenStream, TokenStream> {
                     _ => None,
                 }
             }),
+            from_value_fn_body: quote!({
+                match value {
+                    #from_value_branches
+                    #skipped_from_value_arm
+                }
+            }),
+            size_hint_fn_body: size_hint,
         },
-    );
+    )
+    .with_enumerable_impl(|impl_| {
+        let impl_ = impl_
+            .override_last_value(&last_value)
+            .override_from_index(&from_index)
+            .override_from_index_u128(&from_index_u128)
+            .override_is_inhabited(&is_inhabited)
+            .override_next_value_direct(&next_value_direct)
+            .override_previous_value_direct(&previous_value_direct)
+            .override_to_index_direct(&to_index_direct)
+            .with_sum(variant_sizes.clone());
+
+        match exact_size_predicates {
+            Some(predicates) => impl_.with_exact_size(predicates),
+            None => impl_,
+        }
+    });
 
     Ok(impl_.generate())
 }
 
 /// Implements the `Enumerable` trait for a struct.
-fn impl_enumerable_for_struct(s: ItemStruct) -> Result<TokenStream, TokenStream> {
-    let target = Target::new_for_struct(&s)?;
+fn impl_enumerable_for_struct(s: ItemStruct) -> syn::Result<TokenStream> {
+    // fields skipped by `#[enumerable(skip, default = ...)]` are set to the defaults
+    let fields = FieldAttributes::enumerated_fields(&s.fields)?;
+    let target = Target::new_for_struct(&s, &fields)?;
     let ident = &s.ident;
-    let fields = &s.fields;
+
+    if let Some(span) = target.attributes().sort_by_discriminant {
+        return Err(syn::Error::new(
+            span,
+            "`sort_by_discriminant` is only supported on enums",
+        ));
+    }
+
     let enumerable_trait_path = target.enumerable_trait_path();
 
     let target_type = target.target_type();
 
     let fields_to_enumerate =
-        FieldsToEnumerate::from_fields(fields, field_ref_naming, enumerator_ref_naming);
+        FieldsToEnumerate::from_fields(&s.fields, field_ref_naming, enumerator_ref_naming);
     let binder = &fields_to_enumerate.binder;
+    let pattern = &fields_to_enumerate.pattern;
     let enumerator_refs: Vec<_> = fields_to_enumerate.enumerator_refs().collect();
     let field_types: Vec<_> = fields_to_enumerate.field_types().collect();
 
     if fields.is_empty() {
+        if let Some(span) = target.attributes().diagonal {
+            return Err(syn::Error::new(
+                span,
+                "`diagonal` is only supported on structs with fields",
+            ));
+        }
+
         return Ok(impl_enumerable_for_unit_type(
             &target,
             quote!(#ident #binder),
+            !s.fields.is_empty(),
         ));
     }
 
@@ -336,6 +1257,57 @@ fn impl_enumerable_for_struct(s: ItemStruct) -> Result<TokenStream, TokenStream>
         SizeOption::from_type(quote!(#ty), enumerable_trait_path.clone())
     });
     let enumerable_size_option = SizeOption::from_product(field_sizes);
+    let last_value = generate_last_value_for_fields(
+        fields_to_enumerate.fields_iter(),
+        quote!(#ident #binder),
+        enumerable_trait_path.clone(),
+    );
+
+    let from_index_preamble = generate_from_index_preamble(enumerable_trait_path.clone());
+    let from_index_for_fields = generate_from_index_for_fields(
+        fields_to_enumerate.fields_iter(),
+        quote!(#ident #binder),
+        enumerable_trait_path.clone(),
+    );
+    let from_index_u128 = quote!({
+        #from_index_preamble
+        #from_index_for_fields
+    });
+    let from_index = generate_from_index_by_u128(enumerable_trait_path.clone());
+    let is_inhabited = generate_is_inhabited_for_fields(
+        fields_to_enumerate.fields_iter(),
+        enumerable_trait_path.clone(),
+    );
+    let next_value_for_fields = generate_next_value_for_fields(
+        fields_to_enumerate.fields_iter(),
+        quote!(#ident #binder),
+        quote!(None),
+        enumerable_trait_path.clone(),
+    );
+    let next_value_direct = quote!({
+        let #ident #pattern = self;
+        #next_value_for_fields
+    });
+    let previous_value_for_fields = generate_previous_value_for_fields(
+        fields_to_enumerate.fields_iter(),
+        quote!(#ident #binder),
+        quote!(None),
+        enumerable_trait_path.clone(),
+    );
+    let previous_value_direct = quote!({
+        let #ident #pattern = self;
+        #previous_value_for_fields
+    });
+    let to_index_preamble = generate_to_index_preamble(enumerable_trait_path.clone());
+    let to_index_for_fields = generate_to_index_for_fields(
+        fields_to_enumerate.fields_iter(),
+        enumerable_trait_path.clone(),
+    );
+    let to_index_direct = quote!({
+        #to_index_preamble
+        let #ident #pattern = value;
+        #to_index_for_fields
+    });
 
     let step = generate_step_for_fields(
         fields_to_enumerate.fields_iter(),
@@ -358,6 +1330,26 @@ fn impl_enumerable_for_struct(s: ItemStruct) -> Result<TokenStream, TokenStream>
         enumerable_trait_path.clone(),
     );
 
+    let from_value_for_fields = generate_from_value_for_fields(
+        fields_to_enumerate.fields_iter(),
+        quote!(Some(Self {
+            #( #enumerator_refs, )* next: Some(#ident #binder),
+        })),
+        enumerable_trait_path.clone(),
+    );
+    let size_hint_for_fields = generate_size_hint_for_fields(
+        fields_to_enumerate.fields_iter(),
+        |enumerator_ref| quote!(&self.#enumerator_ref),
+        quote!(Some(0)),
+        enumerable_trait_path.clone(),
+    );
+    let exact_size_predicates = exact_size_predicates(&target, fields.iter().copied());
+    let from_field_indices = generate_from_field_indices_for_fields(
+        fields_to_enumerate.fields_iter(),
+        quote!(#ident #binder),
+        enumerable_trait_path.clone(),
+    );
+
     let impl_ = enumerable_impl_with_enumerator(
         &target,
         enumerable_size_option,
@@ -369,7 +1361,7 @@ fn impl_enumerable_for_struct(s: ItemStruct) -> Result<TokenStream, TokenStream>
             },
             new_fn_body: quote!(#init),
             step_fn_body: quote!({
-                if let Some(#ident #binder) = &mut self.next {
+                if let Some(#ident #pattern) = &mut self.next {
                     #(
                         let #enumerator_refs = &mut self.#enumerator_refs;
                     )*
@@ -378,27 +1370,58 @@ fn impl_enumerable_for_struct(s: ItemStruct) -> Result<TokenStream, TokenStream>
                     }
                 }
             }),
-            next_to_yield_fn_body: quote!(self.next),
+            next_to_yield_fn_body: quote!(::core::clone::Clone::clone(&self.next)),
+            from_value_fn_body: quote!({
+                let #ident #pattern = value;
+                #from_value_for_fields
+            }),
+            size_hint_fn_body: quote!(match &self.next {
+                Some(_) => #size_hint_for_fields,
+                None => (0, Some(0)),
+            }),
         },
-    );
+    )
+    .with_enumerable_impl(|impl_| {
+        let impl_ = impl_
+            .override_last_value(&last_value)
+            .override_from_index(&from_index)
+            .override_from_index_u128(&from_index_u128)
+            .override_is_inhabited(&is_inhabited)
+            .override_next_value_direct(&next_value_direct)
+            .override_previous_value_direct(&previous_value_direct)
+            .override_to_index_direct(&to_index_direct);
+        let impl_ = match exact_size_predicates {
+            Some(predicates) => impl_.with_exact_size(predicates),
+            None => impl_,
+        };
+
+        match target.attributes().diagonal {
+            Some(_) => impl_.with_product(
+                field_types.iter().map(|ty| (*ty).clone()).collect(),
+                &from_field_indices,
+            ),
+            None => impl_,
+        }
+    });
 
     Ok(impl_.generate())
 }
 
 /// Derives the `Enumerable` trait for an enum or struct.
-#[proc_macro_derive(Enumerable, attributes(enumerator))]
+#[proc_macro_derive(Enumerable, attributes(enumerator, enumerable))]
 pub fn derive_enumerable(input: TokenStream1) -> TokenStream1 {
     let target = syn::parse_macro_input!(input as Item);
 
     let result = match target {
         Item::Enum(e) => impl_enumerable_for_enum(e),
         Item::Struct(s) => impl_enumerable_for_struct(s),
-        _ => Err(
-            quote_spanned!(target.span() => compile_error!("only enums and structs are supported");),
-        ),
+        _ => Err(syn::Error::new(
+            target.span(),
+            "only enums and structs are supported",
+        )),
     };
 
-    result.unwrap_or_else(|e| e).into()
+    result.unwrap_or_else(|e| e.to_compile_error()).into()
 }
 
 #[doc(hidden)]
@@ -407,6 +1430,6 @@ pub fn derive_enumerable(input: TokenStream1) -> TokenStream1 {
 pub fn __impl_enumerable_for_tuples(input: TokenStream1) -> TokenStream1 {
     let params = syn::parse_macro_input!(input as tuples::ImplEnumerableForTupleParams);
     tuples::impl_enumerable_for_tuples(params)
-        .unwrap_or_else(|e| e)
+        .unwrap_or_else(|e| e.to_compile_error())
         .into()
 }
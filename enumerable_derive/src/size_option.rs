@@ -4,9 +4,10 @@ use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 use syn::LitInt;
 
-/// An constant expression with type `Option<usize>`.
+/// An constant expression with type `Option<u128>`.
 ///
-/// This type is used to represent the size of an enumerable type.
+/// This type is used to represent the size of an enumerable type, as in
+/// `ENUMERABLE_SIZE_OPTION_U128`.
 pub struct SizeOption {
     token_stream: TokenStream,
 }
@@ -16,27 +17,33 @@ impl SizeOption {
     ///
     /// ## Safety
     ///
-    /// The token stream must be a valid Rust expression of type `Option<usize>`.
+    /// The token stream must be a valid Rust expression of type `Option<u128>`.
     pub unsafe fn from_raw(token_stream: TokenStream) -> Self {
         Self { token_stream }
     }
 
-    /// Creates a new `SizeOption` from the `ENUMERABLE_SIZE_OPTION` constant of
+    /// Creates a new `SizeOption` from the `ENUMERABLE_SIZE_OPTION_U128` constant of
     /// a type implementing the `Enumerable` trait.
     pub fn from_type(type_name: impl ToTokens, enumerable_trait_path: impl ToTokens) -> Self {
-        // SAFETY: It's a `ENUMERABLE_SIZE_OPTION` constant of a type implementing the `Enumerable` trait.
+        // SAFETY: It's a `ENUMERABLE_SIZE_OPTION_U128` constant of a type implementing the `Enumerable` trait.
         unsafe {
             Self::from_raw(quote!(
-                <#type_name as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION
+                <#type_name as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION_U128
             ))
         }
     }
 
+    /// Creates a new `SizeOption` which reports no size, i.e. `None`.
+    pub fn none() -> Self {
+        // SAFETY: `None` is a valid expression of type `Option<u128>`.
+        unsafe { Self::from_raw(quote!(None)) }
+    }
+
     /// Creates a new `SizeOption` from a `usize` literal.
     pub fn from_usize(size: usize) -> Self {
-        let size_lit = LitInt::new(&format!("{}usize", size), Span::call_site());
+        let size_lit = LitInt::new(&format!("{}u128", size), Span::call_site());
 
-        // SAFETY: It's a literal expression of type `Option<usize>`.
+        // SAFETY: It's a literal expression of type `Option<u128>`.
         unsafe { Self::from_raw(quote!(Some(#size_lit))) }
     }
 
@@ -56,13 +63,13 @@ impl SizeOption {
             Some(_) => {
                 // there are at least two sizes
 
-                // SAFETY: `size` is always an `Option<usize>`.
+                // SAFETY: `size` is always an `Option<u128>`.
                 unsafe {
                     Self::from_raw(quote!(
                         {
-                            let size: Option<usize> = #size_first;
+                            let size: Option<u128> = #size_first;
                             #(
-                                let size: Option<usize> = match (size, #sizes) {
+                                let size: Option<u128> = match (size, #sizes) {
                                     (Some(0), _) | (_, Some(0)) => Some(0),
                                     (Some(size), Some(size_field)) => size.checked_mul(size_field),
                                     _ => None,
@@ -96,13 +103,13 @@ impl SizeOption {
             Some(_) => {
                 // there are at least two sizes
 
-                // SAFETY: `size` is always an `Option<usize>`.
+                // SAFETY: `size` is always an `Option<u128>`.
                 unsafe {
                     Self::from_raw(quote!(
                         {
-                            let size: Option<usize> = #size_first;
+                            let size: Option<u128> = #size_first;
                             #(
-                                let size: Option<usize> = match (size, #sizes) {
+                                let size: Option<u128> = match (size, #sizes) {
                                     (Some(size), Some(size_field)) => size.checked_add(size_field),
                                     _ => None,
                                 };
@@ -120,6 +127,31 @@ impl SizeOption {
     }
 }
 
+impl SizeOption {
+    /// Creates a new `SizeOption` with the same value, which fails const evaluation if the value
+    /// exceeds `max_size` or is `None`.
+    ///
+    /// The error message names the type and contains both the actual size and the limit.
+    pub fn with_max_size_check(
+        &self,
+        size_module_path: impl ToTokens,
+        type_name: &str,
+        max_size: usize,
+    ) -> Self {
+        let size = &self.token_stream;
+        let max_size_lit = LitInt::new(&format!("{}u128", max_size), Span::call_site());
+        // The message is the type name and at most 165 more bytes, see `check_max_size`.
+        let message_len = type_name.len() + 165;
+
+        // SAFETY: `check_max_size` returns its `Option<u128>` argument.
+        unsafe {
+            Self::from_raw(quote!(
+                #size_module_path::check_max_size::<#message_len>(#size, #max_size_lit, #type_name)
+            ))
+        }
+    }
+}
+
 impl ToTokens for SizeOption {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.token_stream.to_tokens(tokens);
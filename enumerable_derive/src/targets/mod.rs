@@ -1,7 +1,9 @@
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use proc_macro_crate::{crate_name, FoundCrate};
-use quote::{format_ident, quote, quote_spanned, ToTokens};
-use syn::{Field, Generics, ItemEnum, ItemStruct, Visibility};
+use quote::{format_ident, quote, ToTokens};
+use syn::{Attribute, Field, GenericParam, Generics, ItemEnum, ItemStruct, Type, Visibility};
+
+use crate::{attributes::ContainerAttributes, errors::Errors};
 
 mod enumerator_naming;
 
@@ -103,8 +105,12 @@ pub struct Target {
     ///
     /// If the [`Target`] is created for a struct or an enum, this field is generated from the target type's where clause, with extra bounds `F: Enumerable` for each field type
     where_clause: Option<TokenStream>,
+    /// Whether the target type has type or lifetime parameters, so it's not necessarily `'static`.
+    has_non_static_params: bool,
     /// The path to the `Enumerable` trait.
     enumerable_trait_path: TokenStream,
+    /// The options set by `#[enumerable(...)]` attributes on the target type.
+    attributes: ContainerAttributes,
 }
 
 // Constructors
@@ -130,33 +136,70 @@ impl Target {
             generic_params_simple: None,
             generic_params_full: None,
             where_clause: None,
+            has_non_static_params: false,
+            attributes: ContainerAttributes::default(),
         }
     }
 
-    /// Creates a new [`Target`] for a struct.
-    pub fn new_for_struct(target: &ItemStruct) -> Result<Self, TokenStream> {
-        Self::new_for_any(
-            target.ident.to_token_stream(),
-            enumerator_naming::get_enumerator_name(&target.ident, &target.attrs)?.to_token_stream(),
+    /// Creates a new [`Target`] for a struct, of which only `fields` are enumerated, so only they
+    /// are bounded.
+    pub fn new_for_struct(target: &ItemStruct, fields: &[&Field]) -> syn::Result<Self> {
+        Self::new_for_item(
+            &target.ident,
+            &target.attrs,
+            &target.vis,
+            &target.generics,
+            fields.iter().copied(),
         )
-        .with_visibility(target.vis.clone())
-        .with_where_clause_from_generics_and_fields(&target.generics, target.fields.iter())
-        .with_generic_params_from_generics(&target.generics)
     }
 
-    /// Creates a new [`Target`] for an enum.
-    pub fn new_for_enum(target: &ItemEnum) -> Result<Self, TokenStream> {
-        Self::new_for_any(
-            target.ident.to_token_stream(),
-            enumerator_naming::get_enumerator_name(&target.ident, &target.attrs)?
-                .into_token_stream(),
-        )
-        .with_visibility(target.vis.clone())
-        .with_where_clause_from_generics_and_fields(
+    /// Creates a new [`Target`] for an enum, of which only `fields` of the enumerated variants are
+    /// enumerated, so only they are bounded.
+    pub fn new_for_enum(target: &ItemEnum, fields: &[&Field]) -> syn::Result<Self> {
+        Self::new_for_item(
+            &target.ident,
+            &target.attrs,
+            &target.vis,
             &target.generics,
-            target.variants.iter().flat_map(|v| v.fields.iter()),
+            fields.iter().copied(),
         )
-        .with_generic_params_from_generics(&target.generics)
+    }
+
+    /// Creates a new [`Target`] for a struct or an enum, from its parts.
+    ///
+    /// The enumerator name, the `#[enumerable(...)]` attributes and the field types are checked
+    /// independently, and all errors found are reported together.
+    fn new_for_item<'a>(
+        ident: &Ident,
+        attrs: &[Attribute],
+        vis: &Visibility,
+        generics: &'a Generics,
+        fields: impl Iterator<Item = &'a Field>,
+    ) -> syn::Result<Self> {
+        let mut errors = Errors::new();
+
+        let enumerator_name = errors
+            .take(enumerator_naming::get_enumerator_name(ident, attrs))
+            .unwrap_or_else(|| enumerator_naming::default_enumerator_name(ident));
+        let attributes = errors
+            .take(ContainerAttributes::from_attrs(attrs))
+            .unwrap_or_default();
+
+        let fields: Vec<_> = fields.collect();
+        for field in &fields {
+            if let Some(span) = find_reference(&field.ty) {
+                errors.push_spanned(span, "Fields of reference types can't be enumerated.");
+            }
+        }
+
+        let target =
+            Self::new_for_any(ident.to_token_stream(), enumerator_name.into_token_stream())
+                .with_visibility(vis.clone())
+                .with_attributes(attributes)
+                .with_generic_params_from_generics(generics)
+                .with_where_clause_from_generics_and_fields(generics, fields.into_iter());
+
+        errors.finish(target)
     }
 }
 
@@ -169,6 +212,12 @@ impl Target {
         self
     }
 
+    /// Sets the options set by `#[enumerable(...)]` attributes.
+    pub fn with_attributes(mut self, attributes: ContainerAttributes) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
     /// Sets the target type.
     pub fn with_target_type(
         mut self,
@@ -203,43 +252,56 @@ impl Target {
     }
 
     /// Sets the generic parameters of the target type from [`Generics`].
-    pub fn with_generic_params_from_generics(
-        self,
-        generics: &Generics,
-    ) -> Result<Self, TokenStream> {
+    ///
+    /// Lifetime, type and const parameters are all carried over in the declaration order.
+    pub fn with_generic_params_from_generics(mut self, generics: &Generics) -> Self {
         if generics.params.is_empty() {
-            return self.into_ok();
+            return self;
         }
 
-        if let Some(lifetime) = generics.lifetimes().next() {
-            return Err(
-                quote_spanned!(lifetime.lifetime.span() => compile_error!("Lifetime parameters are not supported.")),
-            );
-        }
-
-        if let Some(const_param) = generics.const_params().next() {
-            return Err(
-                quote_spanned!(const_param.ident.span() => compile_error!("Const parameters are not supported.")),
-            );
-        }
+        self.has_non_static_params = generics
+            .params
+            .iter()
+            .any(|param| !matches!(param, GenericParam::Const(_)));
 
         let mut params_simple = quote!(<);
         let mut params_full = quote!(<);
 
-        for param in generics.type_params() {
-            let ident = &param.ident;
-            let colon_token = &param.colon_token;
-            let bounds = &param.bounds;
+        // type and const parameters may be interleaved, so they are kept in the declaration order
+        for param in &generics.params {
+            match param {
+                GenericParam::Type(param) => {
+                    let ident = &param.ident;
+                    let colon_token = &param.colon_token;
+                    let bounds = &param.bounds;
+
+                    params_simple.extend(quote!(#ident,));
+                    params_full.extend(quote!(#ident #colon_token #bounds,));
+                }
+                // const parameters are never bounded by `Enumerable`, they only parameterize types
+                GenericParam::Const(param) => {
+                    let ident = &param.ident;
+                    let ty = &param.ty;
 
-            params_simple.extend(quote!(#ident,));
-            params_full.extend(quote!(#ident #colon_token #bounds,));
+                    params_simple.extend(quote!(#ident,));
+                    params_full.extend(quote!(const #ident: #ty,));
+                }
+                // lifetimes never appear in enumerated values, they only parameterize types
+                GenericParam::Lifetime(param) => {
+                    let lifetime = &param.lifetime;
+                    let colon_token = &param.colon_token;
+                    let bounds = &param.bounds;
+
+                    params_simple.extend(quote!(#lifetime,));
+                    params_full.extend(quote!(#lifetime #colon_token #bounds,));
+                }
+            }
         }
 
         params_simple.extend(quote!(>));
         params_full.extend(quote!(>));
 
         self.with_generic_params(params_simple, params_full)
-            .into_ok()
     }
 
     /// Sets the where clause of the target type.
@@ -249,6 +311,8 @@ impl Target {
     }
 
     /// Sets the where clause of the target type from [`Generics`] and iterator of [`Field`]s.
+    ///
+    /// The generic parameters must have been set, as the target type may appear in the clause.
     pub fn with_where_clause_from_generics_and_fields<'a>(
         self,
         generics: &'a Generics,
@@ -256,18 +320,35 @@ impl Target {
     ) -> Self {
         let enumerable_trait_path = &self.enumerable_trait_path;
         let mut where_clause_for_fields = TokenStream::new();
+        let field_types: Vec<_> = fields.map(|field| field.ty.to_token_stream()).collect();
 
-        for field in fields {
-            let ty = &field.ty;
+        for ty in &field_types {
             where_clause_for_fields.extend(quote!(#ty: #enumerable_trait_path,));
         }
 
-        // Add an extra bound `T: ::core::marker::Copy` for each generic parameter `T`.
+        // Add an extra bound `T: ::core::clone::Clone` for each generic parameter `T`.
         //
         // See here for more information: https://github.com/GeminiLab/enumerable/issues/51.
+        //
+        // Parameters used only in `PhantomData` (or not at all) may be unsized or not `Clone`, so
+        // they are not bounded. The target type itself is bounded by `Clone` instead, which holds
+        // if it's derived with the bounds above, or implemented by hand without them.
+        let mut has_phantom_params = false;
         for param in generics.type_params() {
             let ident = &param.ident;
-            where_clause_for_fields.extend(quote!(#ident: ::core::marker::Copy,));
+            if field_types
+                .iter()
+                .any(|ty| is_used_outside_phantom_data(ty.clone(), ident))
+            {
+                where_clause_for_fields.extend(quote!(#ident: ::core::clone::Clone,));
+            } else {
+                has_phantom_params = true;
+            }
+        }
+
+        if has_phantom_params {
+            let target_type = self.target_type();
+            where_clause_for_fields.extend(quote!(#target_type: ::core::clone::Clone,));
         }
 
         let where_clause = match &generics.where_clause {
@@ -291,12 +372,12 @@ impl Target {
 #[allow(dead_code)]
 impl Target {
     /// Gets the target type name. Generics are not included.
-    pub fn target_type_name(&self) -> TokenStreamRefs {
+    pub fn target_type_name(&self) -> TokenStreamRefs<'_> {
         (&self.target_type_name).into()
     }
 
     /// Gets the target type, with generics included but bounds and defaults stripped.
-    pub fn target_type(&self) -> TokenStreamRefs {
+    pub fn target_type(&self) -> TokenStreamRefs<'_> {
         self.target_type
             .as_ref()
             .map(Into::into)
@@ -307,7 +388,7 @@ impl Target {
     }
 
     /// Gets the target type, with generics and bounds included but defaults stripped.
-    pub fn target_type_bounded(&self) -> TokenStreamRefs {
+    pub fn target_type_bounded(&self) -> TokenStreamRefs<'_> {
         self.target_type_with_bound
             .as_ref()
             .map(Into::into)
@@ -327,12 +408,12 @@ impl Target {
     }
 
     /// Gets the type of the enumerator to be generated for the current target type.
-    pub fn enumerator_type_name(&self) -> TokenStreamRefs {
+    pub fn enumerator_type_name(&self) -> TokenStreamRefs<'_> {
         (&self.enumerator_type_name).into()
     }
 
     /// Gets the type of the enumerator, with generics included but bounds and defaults stripped.
-    pub fn enumerator_type(&self) -> TokenStreamRefs {
+    pub fn enumerator_type(&self) -> TokenStreamRefs<'_> {
         self.enumerator_type
             .as_ref()
             .map(Into::into)
@@ -343,7 +424,7 @@ impl Target {
     }
 
     /// Gets the type of the enumerator, with generics and bounds included but defaults stripped.
-    pub fn enumerator_type_bounded(&self) -> TokenStreamRefs {
+    pub fn enumerator_type_bounded(&self) -> TokenStreamRefs<'_> {
         self.enumerator_type_with_bound
             .as_ref()
             .map(Into::into)
@@ -354,24 +435,151 @@ impl Target {
     }
 
     /// Gets the generic parameters of the target type, with bounds and defaults stripped.
-    pub fn generic_params_simple(&self) -> TokenStreamRefs {
+    pub fn generic_params_simple(&self) -> TokenStreamRefs<'_> {
         self.generic_params_simple.as_ref().into()
     }
 
     /// Gets the generic parameters of the target type, with bounds retained and defaults stripped.
-    pub fn generic_params_full(&self) -> TokenStreamRefs {
+    pub fn generic_params_full(&self) -> TokenStreamRefs<'_> {
         self.generic_params_full.as_ref().into()
     }
 
     /// Gets the where clause of the target type, with extra bounds `F: Enumerable` for each field type.
-    pub fn where_clause(&self) -> TokenStreamRefs {
+    pub fn where_clause(&self) -> TokenStreamRefs<'_> {
         self.where_clause.as_ref().into()
     }
 
+    /// Returns whether the target type has type or lifetime parameters, so it's not necessarily
+    /// `'static`.
+    pub fn has_non_static_params(&self) -> bool {
+        self.has_non_static_params
+    }
+
+    /// Gets the options set by `#[enumerable(...)]` attributes on the target type.
+    pub fn attributes(&self) -> &ContainerAttributes {
+        &self.attributes
+    }
+
     /// Gets the path to the `Enumerable` trait. If the `enumerable` crate is not found, it emits a compile error.
-    pub fn enumerable_trait_path(&self) -> TokenStreamRefs {
+    pub fn enumerable_trait_path(&self) -> TokenStreamRefs<'_> {
         (&self.enumerable_trait_path).into()
     }
+
+    /// Gets the path to the `ExactSizeEnumerable` trait. If the `enumerable` crate is not found, it
+    /// emits a compile error.
+    pub fn exact_size_enumerable_trait_path(&self) -> TokenStream {
+        get_enumerable_item_path("ExactSizeEnumerable").unwrap_or_else(|e| e)
+    }
+
+    /// Gets the path to the `ConstEnumerable` trait. If the `enumerable` crate is not found, it
+    /// emits a compile error.
+    pub fn const_enumerable_trait_path(&self) -> TokenStream {
+        get_enumerable_item_path("ConstEnumerable").unwrap_or_else(|e| e)
+    }
+
+    /// Gets the path to the `ProductEnumerable` trait. If the `enumerable` crate is not found, it
+    /// emits a compile error.
+    pub fn product_enumerable_trait_path(&self) -> TokenStream {
+        get_enumerable_item_path("ProductEnumerable").unwrap_or_else(|e| e)
+    }
+
+    /// Gets the path to the `SumEnumerable` trait. If the `enumerable` crate is not found, it
+    /// emits a compile error.
+    pub fn sum_enumerable_trait_path(&self) -> TokenStream {
+        get_enumerable_item_path("SumEnumerable").unwrap_or_else(|e| e)
+    }
+
+    /// Gets the path to the `OrderedEnumerable` trait. If the `enumerable` crate is not found, it
+    /// emits a compile error.
+    pub fn ordered_enumerable_trait_path(&self) -> TokenStream {
+        get_enumerable_item_path("OrderedEnumerable").unwrap_or_else(|e| e)
+    }
+
+    /// Gets the path to the `size` module. If the `enumerable` crate is not found, it emits a
+    /// compile error.
+    pub fn size_module_path(&self) -> TokenStream {
+        get_enumerable_item_path("size").unwrap_or_else(|e| e)
+    }
+
+    /// Gets the path to the `StepByEnumerator` type. If the `enumerable` crate is not found, it
+    /// emits a compile error.
+    pub fn step_by_enumerator_path(&self) -> TokenStream {
+        get_enumerable_item_path("StepByEnumerator").unwrap_or_else(|e| e)
+    }
+
+    /// Gets the path to the `ReversedEnumerator` type. If the `enumerable` crate is not found, it
+    /// emits a compile error.
+    pub fn reversed_enumerator_path(&self) -> TokenStream {
+        get_enumerable_item_path("ReversedEnumerator").unwrap_or_else(|e| e)
+    }
+
+    /// Gets the where clause of the target type with extra predicates appended. The predicates
+    /// must be separated and terminated by commas.
+    pub fn where_clause_with(&self, predicates: impl ToTokens) -> TokenStream {
+        match &self.where_clause {
+            // where clauses always end with a comma if not empty
+            Some(where_clause) => quote!(#where_clause #predicates),
+            None => quote!(where #predicates),
+        }
+    }
+}
+
+/// Returns whether the identifier `ident` appears in the tokens of a type, other than in the
+/// generic arguments of `PhantomData`.
+fn is_used_outside_phantom_data(ty: TokenStream, ident: &Ident) -> bool {
+    let mut tokens = ty.into_iter().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(name) if name == "PhantomData" => {
+                if !matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '<') {
+                    continue;
+                }
+
+                // skip the generic arguments, tracking nested angle brackets, but not the `>` of
+                // `->` in function pointer types
+                let mut depth = 0;
+                let mut after_hyphen = false;
+                for token in tokens.by_ref() {
+                    if let TokenTree::Punct(punct) = &token {
+                        match punct.as_char() {
+                            '<' => depth += 1,
+                            '>' if !after_hyphen => depth -= 1,
+                            _ => {}
+                        }
+                        after_hyphen = punct.as_char() == '-';
+                    } else {
+                        after_hyphen = false;
+                    }
+
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+            TokenTree::Ident(name) if name == *ident => return true,
+            TokenTree::Group(group) if is_used_outside_phantom_data(group.stream(), ident) => {
+                return true
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Finds a reference in a field type, which can't be enumerated as it borrows values from
+/// elsewhere, and returns its span. References nested in other types, e.g. `PhantomData<&'a T>`,
+/// are left to the `Enumerable` bounds on the field types.
+fn find_reference(ty: &Type) -> Option<Span> {
+    match ty {
+        Type::Reference(reference) => Some(reference.and_token.span),
+        Type::Array(array) => find_reference(&array.elem),
+        Type::Group(group) => find_reference(&group.elem),
+        Type::Paren(paren) => find_reference(&paren.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().find_map(find_reference),
+        _ => None,
+    }
 }
 
 /// Gets the path to the `Enumerable` trait. Used when initializing a new [`Target`].
@@ -391,3 +599,23 @@ fn get_enumerable_trait_path() -> Result<TokenStream, TokenStream> {
         }
     }
 }
+
+/// Gets the path to an item other than the `Enumerable` trait in the `enumerable` crate.
+///
+/// Unlike the `Enumerable` trait, these items are not expected to be in scope, so the crate itself
+/// is referred to by `::enumerable`, which it declares by `extern crate self as enumerable`.
+fn get_enumerable_item_path(item: &str) -> Result<TokenStream, TokenStream> {
+    let item = format_ident!("{}", item);
+
+    match crate_name("enumerable") {
+        Ok(FoundCrate::Itself) => Ok(quote!(::enumerable::#item)),
+        Ok(FoundCrate::Name(name)) => {
+            let crate_name = format_ident!("{}", name);
+            Ok(quote!(::#crate_name::#item))
+        }
+        Err(e) => {
+            let e = format!("failed to find crate `enumerable`: {}", e);
+            Err(quote!(compile_error!(#e);))
+        }
+    }
+}
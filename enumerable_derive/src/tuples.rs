@@ -1,40 +1,51 @@
-use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote, quote_spanned, TokenStreamExt};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, TokenStreamExt};
 use syn::{parse::Parse, LitInt, Path, Type, TypePath};
 
 use crate::{
     code_gen::{enumerable_impl_with_enumerator, EnumeratorInfo, EnumeratorKeyword},
     fields::FieldsToEnumerate,
-    generate_init_for_fields, generate_step_for_fields,
+    generate_enumerator_since_for_fields, generate_enumerator_since_preamble,
+    generate_from_field_indices_for_fields, generate_from_index_by_u128,
+    generate_from_index_for_fields, generate_from_index_preamble, generate_from_value_for_fields,
+    generate_init_for_fields, generate_is_inhabited_for_fields, generate_last_value_for_fields,
+    generate_next_value_for_fields, generate_previous_value_for_fields,
+    generate_size_hint_for_fields, generate_step_for_fields, generate_to_index_for_fields,
+    generate_to_index_preamble,
     size_option::SizeOption,
     targets::Target,
 };
 
-/// Returns the i-th capital letter.
-fn capital_letter(i: usize) -> char {
-    char::from(b'A' + i as u8)
+/// Returns the i-th name made of letters from `first`, in the order `A`, ..., `Z`, `AA`, `AB`, ...
+/// for capital letters, i.e. the bijective base-26 representation of `i + 1`.
+fn letter_name(i: usize, first: u8) -> String {
+    let mut letters = Vec::new();
+    let mut rest = i + 1;
+    while rest > 0 {
+        rest -= 1;
+        letters.push(char::from(first + (rest % 26) as u8));
+        rest /= 26;
+    }
+
+    letters.into_iter().rev().collect()
 }
 
-/// Returns the i-th lowercase letter.
-fn lowercase_letter(i: usize) -> char {
-    char::from(b'a' + i as u8)
+/// Returns the name of the i-th type parameter, i.e. `A`, ..., `Z`, `AA`, `AB`, ....
+fn capital_name(i: usize) -> String {
+    letter_name(i, b'A')
 }
 
-/// Implements the `Enumerable` trait for a tuple with `n` elements.
-fn impl_enumerable_for_tuple_n(n: usize) -> Result<TokenStream, TokenStream> {
-    if n > 26 {
-        let error = format!(
-            "tuple with more than 26 elements are not supported, but got {}",
-            n
-        );
-        return Err(quote_spanned!(Span::call_site() => compile_error!(#error);));
-    }
+/// Returns the name of the i-th field, i.e. `a`, ..., `z`, `aa`, `ab`, ....
+fn lowercase_name(i: usize) -> String {
+    letter_name(i, b'a')
+}
 
+/// Implements the `Enumerable` trait for a tuple with `n` elements.
+fn impl_enumerable_for_tuple_n(n: usize) -> syn::Result<TokenStream> {
     let enumerator_ident = format_ident!("Tuple{}Enumerator", n);
-    // let enumerator_desc = format!("Enumerator for tuples with {} elements.", n);
 
     // names for types of the tuple elements
-    let gen_names: Vec<_> = (0..n).map(capital_letter).collect();
+    let gen_names: Vec<_> = (0..n).map(capital_name).collect();
     // identifiers for types of the tuple elements
     let gen_types: Vec<_> = gen_names
         .iter()
@@ -46,13 +57,20 @@ fn impl_enumerable_for_tuple_n(n: usize) -> Result<TokenStream, TokenStream> {
             })
         })
         .collect();
+    let enumerator_doc = format!(
+        "`{enumerator_ident}` is an iterator over possible values of `({params})`, i.e. \
+         `<({params}) as Enumerable>::Enumerator`.\n\n\
+         It yields the values in lexicographic order, with the last element varying the fastest. \
+         It implements `Clone` and `Debug` if the enumerators of all elements do.",
+        params = gen_names.join(", ") + if n == 1 { "," } else { "" },
+    );
     // the generic parameters for the enumerator
     let gen_params = quote!(<#( #gen_types ),*>);
     // the type of the tuple
     let tuple_type = quote!((#( #gen_types ),*));
     // where clause here
     let where_clause = quote!(
-        where #( #gen_types: Enumerable ),*
+        where #( #gen_types: Enumerable, )*
     );
 
     let target = Target::new_for_any(tuple_type.clone(), quote!(#enumerator_ident))
@@ -63,9 +81,9 @@ fn impl_enumerable_for_tuple_n(n: usize) -> Result<TokenStream, TokenStream> {
 
     let fields = gen_types.iter().enumerate().map(|(i, ty)| {
         (
-            format!("{}", lowercase_letter(i)),
+            lowercase_name(i),
             quote!(#ty),
-            format!("enumerator_{}", lowercase_letter(i)),
+            format!("enumerator_{}", lowercase_name(i)),
         )
     });
     let fields = FieldsToEnumerate::new_unnamed(fields);
@@ -101,6 +119,122 @@ fn impl_enumerable_for_tuple_n(n: usize) -> Result<TokenStream, TokenStream> {
             .map(|ty| SizeOption::from_type(ty, enumerable_trait_path.clone())),
     );
 
+    let field_refs: Vec<_> = fields.field_refs().collect();
+    let last_value = generate_last_value_for_fields(
+        fields.fields_iter(),
+        quote!((#( #field_refs ),*)),
+        enumerable_trait_path.clone(),
+    );
+    let from_index_preamble = generate_from_index_preamble(enumerable_trait_path.clone());
+    let from_index_for_fields = generate_from_index_for_fields(
+        fields.fields_iter(),
+        quote!(#binder),
+        enumerable_trait_path.clone(),
+    );
+    let from_index_u128 = quote!({
+        #from_index_preamble
+        #from_index_for_fields
+    });
+    let from_index = generate_from_index_by_u128(enumerable_trait_path.clone());
+    // an exhausted enumerator, returned if `start` is out of range
+    let exhausted_enumerator = quote!({
+        let mut enumerator = <Self as #enumerable_trait_path>::enumerator();
+        enumerator.next = None;
+        enumerator
+    });
+    let enumerator_since_preamble = generate_enumerator_since_preamble(
+        exhausted_enumerator.clone(),
+        enumerable_trait_path.clone(),
+    );
+    let enumerator_since_for_fields = generate_enumerator_since_for_fields(
+        fields.fields_iter(),
+        quote!(Self::Enumerator {
+            #( #enumerator_refs, )* next: Some(#binder),
+        }),
+        exhausted_enumerator,
+        enumerable_trait_path.clone(),
+    );
+    let enumerator_since = quote!({
+        #enumerator_since_preamble
+        #enumerator_since_for_fields
+    });
+    let is_inhabited =
+        generate_is_inhabited_for_fields(fields.fields_iter(), enumerable_trait_path.clone());
+    let next_value_for_fields = generate_next_value_for_fields(
+        fields.fields_iter(),
+        quote!(#binder),
+        quote!(None),
+        enumerable_trait_path.clone(),
+    );
+    let next_value_direct = quote!({
+        let #binder = self;
+        #next_value_for_fields
+    });
+    let previous_value_for_fields = generate_previous_value_for_fields(
+        fields.fields_iter(),
+        quote!(#binder),
+        quote!(None),
+        enumerable_trait_path.clone(),
+    );
+    let previous_value_direct = quote!({
+        let #binder = self;
+        #previous_value_for_fields
+    });
+
+    let to_index_preamble = generate_to_index_preamble(enumerable_trait_path.clone());
+    let to_index_for_fields =
+        generate_to_index_for_fields(fields.fields_iter(), enumerable_trait_path.clone());
+    let to_index_direct = quote!({
+        #to_index_preamble
+        let #binder = value;
+        #to_index_for_fields
+    });
+
+    let from_value_for_fields = generate_from_value_for_fields(
+        fields.fields_iter(),
+        quote!(Some(Self {
+            #( #enumerator_refs, )* next: Some(#binder),
+        })),
+        enumerable_trait_path.clone(),
+    );
+
+    let size_hint_for_fields = generate_size_hint_for_fields(
+        fields.fields_iter(),
+        |enumerator_ref| quote!(&self.#enumerator_ref),
+        quote!(Some(0)),
+        enumerable_trait_path.clone(),
+    );
+    let exact_size_enumerable_trait_path = target.exact_size_enumerable_trait_path();
+    let exact_size_predicates = quote!(#( #gen_types: #exact_size_enumerable_trait_path, )*);
+    let from_field_indices = generate_from_field_indices_for_fields(
+        fields.fields_iter(),
+        quote!((#( #field_refs ),*)),
+        enumerable_trait_path.clone(),
+    );
+
+    // jump to the value `n` steps ahead by its index, which is known from the number of values left
+    // if the size of the tuple fits in `usize` and the size hint is exact, or step through the
+    // enumerator otherwise
+    let nth = quote!({
+        match (
+            <#tuple_type as #enumerable_trait_path>::ENUMERABLE_SIZE_OPTION,
+            ::core::iter::Iterator::size_hint(self),
+        ) {
+            (Some(size), (remaining, Some(upper))) if remaining == upper => {
+                *self = <#tuple_type as #enumerable_trait_path>::enumerator_since(
+                    (size - remaining).saturating_add(n),
+                );
+            }
+            _ => {
+                for _ in 0..n {
+                    ::core::iter::Iterator::next(self)?;
+                }
+            }
+        }
+
+        ::core::iter::Iterator::next(self)
+    });
+
     let impl_ = enumerable_impl_with_enumerator(
         &target,
         size_option,
@@ -121,11 +255,77 @@ fn impl_enumerable_for_tuple_n(n: usize) -> Result<TokenStream, TokenStream> {
                     }
                 }
             }),
-            next_to_yield_fn_body: quote!(self.next),
+            next_to_yield_fn_body: quote!(::core::clone::Clone::clone(&self.next)),
+            from_value_fn_body: quote!({
+                let #binder = value;
+                #from_value_for_fields
+            }),
+            size_hint_fn_body: quote!(match &self.next {
+                Some(_) => #size_hint_for_fields,
+                None => (0, Some(0)),
+            }),
         },
+    )
+    .with_enumerable_impl(|impl_| {
+        impl_
+            .override_last_value(&last_value)
+            .override_from_index(&from_index)
+            .override_from_index_u128(&from_index_u128)
+            .override_enumerator_since(&enumerator_since)
+            .override_is_inhabited(&is_inhabited)
+            .override_next_value_direct(&next_value_direct)
+            .override_previous_value_direct(&previous_value_direct)
+            .override_to_index_direct(&to_index_direct)
+            .with_exact_size(exact_size_predicates)
+            .with_product(
+                gen_types.iter().map(|ty| quote!(#ty)).collect(),
+                &from_field_indices,
+            )
+    })
+    .with_public_constructor()
+    .with_enumerator_doc(&enumerator_doc)
+    .override_nth(&nth);
+
+    // `Clone` and `Debug` are implemented by hand, as deriving them would bound the element types
+    // rather than their enumerators
+    let impl_generics = target.generic_params_full();
+    let enumerator_type = target.enumerator_type();
+    let clone_where_clause = target.where_clause_with(quote!(
+        #( <#gen_types as #enumerable_trait_path>::Enumerator: ::core::clone::Clone, )*
+    ));
+    // tuples with more than 12 elements don't implement `Debug`, so the tuple itself is bounded
+    let debug_where_clause = target.where_clause_with(quote!(
+        #tuple_type: ::core::fmt::Debug,
+        #( <#gen_types as #enumerable_trait_path>::Enumerator: ::core::fmt::Debug, )*
+    ));
+    let enumerator_name = enumerator_ident.to_string();
+    let enumerator_ref_names = enumerator_refs.iter().map(ToString::to_string);
+    let clone_and_debug_impls = quote!(
+        #[automatically_derived]
+        impl #impl_generics ::core::clone::Clone for #enumerator_type #clone_where_clause {
+            #[inline]
+            fn clone(&self) -> Self {
+                Self {
+                    #( #enumerator_refs: ::core::clone::Clone::clone(&self.#enumerator_refs), )*
+                    next: ::core::clone::Clone::clone(&self.next),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #enumerator_type #debug_where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(#enumerator_name)
+                    #( .field(#enumerator_ref_names, &self.#enumerator_refs) )*
+                    .field("next", &self.next)
+                    .finish()
+            }
+        }
     );
 
-    Ok(impl_.generate())
+    let mut result = impl_.generate();
+    result.append_all(clone_and_debug_impls);
+    Ok(result)
 }
 
 /// The input for the [`impl_enumerable_for_tuples`] function. An inclusive range of tuple sizes.
@@ -150,7 +350,7 @@ impl Parse for ImplEnumerableForTupleParams {
 /// Implements the `Enumerable` trait for tuples with sizes in the given range.
 pub fn impl_enumerable_for_tuples(
     params: ImplEnumerableForTupleParams,
-) -> Result<TokenStream, TokenStream> {
+) -> syn::Result<TokenStream> {
     let mut result = TokenStream::new();
     for i in params.from..=params.to {
         result.append_all(impl_enumerable_for_tuple_n(i)?);
@@ -158,3 +358,28 @@ pub fn impl_enumerable_for_tuples(
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letter_names() {
+        assert_eq!(capital_name(0), "A");
+        assert_eq!(capital_name(25), "Z");
+        assert_eq!(capital_name(26), "AA");
+        assert_eq!(capital_name(27), "AB");
+        assert_eq!(capital_name(51), "AZ");
+        assert_eq!(capital_name(52), "BA");
+        assert_eq!(capital_name(26 * 27 - 1), "ZZ");
+        assert_eq!(capital_name(26 * 27), "AAA");
+        assert_eq!(lowercase_name(0), "a");
+        assert_eq!(lowercase_name(27), "ab");
+    }
+
+    #[test]
+    fn test_letter_names_are_unique() {
+        let names: std::collections::HashSet<_> = (0..2000).map(capital_name).collect();
+        assert_eq!(names.len(), 2000);
+    }
+}
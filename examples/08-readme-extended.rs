@@ -1,7 +1,7 @@
 // This example is an extension of the one in README.md, where we calculate the health score and
 // price of each meal, and then find the healthiest, cheapest, and best value meals.
 
-use enumerable::Enumerable;
+use enumerable::{argmax_by_key, argmin_by_key, Enumerable};
 
 #[derive(Debug, Copy, Clone, Enumerable, PartialEq, Eq)]
 enum Food {
@@ -77,19 +77,9 @@ impl Meal {
 }
 
 fn main() {
-    let healthiest_meal = Meal::enumerator()
-        .max_by(|a, b| a.health_score().partial_cmp(&b.health_score()).unwrap())
-        .unwrap();
-    let cheapest_meal = Meal::enumerator()
-        .min_by(|a, b| a.price().partial_cmp(&b.price()).unwrap())
-        .unwrap();
-    let best_value_meal = Meal::enumerator()
-        .max_by(|a, b| {
-            (a.health_score() / a.price())
-                .partial_cmp(&(b.health_score() / b.price()))
-                .unwrap()
-        })
-        .unwrap();
+    let healthiest_meal = argmax_by_key(Meal::health_score).unwrap();
+    let cheapest_meal = argmin_by_key(Meal::price).unwrap();
+    let best_value_meal = argmax_by_key(|meal: &Meal| meal.health_score() / meal.price()).unwrap();
 
     println!("There are {} different meals:", Meal::ENUMERABLE_SIZE);
     println!(
@@ -2,7 +2,7 @@
 
 use std::fmt::Debug;
 
-use enumerable::Enumerable;
+use enumerable::{argmax_by_key, argmin_by_key, Enumerable};
 
 /// Foods that can be eaten in a specific meal.
 trait FoodOptions: Copy + Debug + Enumerable + Eq {
@@ -101,19 +101,10 @@ impl<F: FoodOptions> Meal<F> {
 }
 
 fn check_meal<F: FoodOptions>() {
-    let healthiest_meal = Meal::<F>::enumerator()
-        .max_by(|a, b| a.health_score().partial_cmp(&b.health_score()).unwrap())
-        .unwrap();
-    let cheapest_meal = Meal::<F>::enumerator()
-        .min_by(|a, b| a.price().partial_cmp(&b.price()).unwrap())
-        .unwrap();
-    let best_value_meal = Meal::<F>::enumerator()
-        .max_by(|a, b| {
-            (a.health_score() / a.price())
-                .partial_cmp(&(b.health_score() / b.price()))
-                .unwrap()
-        })
-        .unwrap();
+    let healthiest_meal = argmax_by_key(Meal::<F>::health_score).unwrap();
+    let cheapest_meal = argmin_by_key(Meal::<F>::price).unwrap();
+    let best_value_meal =
+        argmax_by_key(|meal: &Meal<F>| meal.health_score() / meal.price()).unwrap();
 
     println!("There are {} different meals:", Meal::<F>::ENUMERABLE_SIZE);
     println!(
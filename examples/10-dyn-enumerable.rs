@@ -0,0 +1,75 @@
+use std::any::Any;
+
+use enumerable::{DynEnumerable, Enumerable, EnumerableProxy};
+
+// `Enumerable` is not object safe: it has an associated type, constants, and methods without a
+// receiver. When the types to enumerate are only known at runtime, e.g. in a registry of settings,
+// `DynEnumerable` can be used instead. It's implemented by `EnumerableProxy<T>`, a zero-sized
+// stand-in for any `Enumerable` type `T`, and yields values boxed as `Box<dyn Any>`.
+
+// Some settings for demonstration purposes.
+#[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+enum Theme {
+    Light,
+    Dark,
+    HighContrast,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+enum Language {
+    English,
+    Chinese,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+struct Notifications {
+    sound: bool,
+    badge: bool,
+}
+
+// Values from `dyn_values` are `Box<dyn Any>`, so they have to be downcast to be printed.
+fn describe(value: &dyn Any) -> String {
+    if let Some(theme) = value.downcast_ref::<Theme>() {
+        format!("{:?}", theme)
+    } else if let Some(language) = value.downcast_ref::<Language>() {
+        format!("{:?}", language)
+    } else if let Some(notifications) = value.downcast_ref::<Notifications>() {
+        format!("{:?}", notifications)
+    } else {
+        "<unknown>".to_string()
+    }
+}
+
+fn main() {
+    // Register all settings types uniformly.
+    let registry: Vec<Box<dyn DynEnumerable>> = vec![
+        Box::new(EnumerableProxy::<Theme>::new()),
+        Box::new(EnumerableProxy::<Language>::new()),
+        Box::new(EnumerableProxy::<Notifications>::new()),
+    ];
+
+    // Enumerate all of them without knowing their types statically.
+    for setting in &registry {
+        println!(
+            "{} ({} values):",
+            setting.dyn_type_name(),
+            setting.dyn_size().unwrap()
+        );
+        for value in setting.dyn_values() {
+            println!("  {}", describe(value.as_ref()));
+        }
+    }
+
+    let sizes = registry
+        .iter()
+        .map(|setting| setting.dyn_size())
+        .collect::<Vec<_>>();
+    assert_eq!(sizes, vec![Some(3), Some(2), Some(4)]);
+
+    // Values can be downcast back to their original types.
+    let themes = registry[0]
+        .dyn_values()
+        .map(|value| *value.downcast::<Theme>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(themes, Theme::enumerator().collect::<Vec<_>>());
+}
@@ -0,0 +1,74 @@
+//! Enumeration of pairs of consecutive values.
+
+use crate::Enumerable;
+
+/// The iterator returned by [`adjacent_pairs`], yielding each value of `T` paired with the next
+/// one in enumeration order.
+pub struct AdjacentPairs<T: Enumerable> {
+    inner: T::Enumerator,
+    /// The last value taken from `inner`, or `None` if `inner` is exhausted.
+    previous: Option<T>,
+}
+
+impl<T: Enumerable> AdjacentPairs<T> {
+    /// Creates a new `AdjacentPairs`, yielding the same pairs as [`adjacent_pairs`].
+    #[inline]
+    pub fn new() -> Self {
+        let mut inner = T::enumerator();
+        let previous = inner.next();
+
+        Self { inner, previous }
+    }
+}
+
+impl<T: Enumerable> Default for AdjacentPairs<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Enumerable> Iterator for AdjacentPairs<T> {
+    type Item = (T, T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = self.previous.take()?;
+        let next = self.inner.next()?;
+        self.previous = Some(next.clone());
+
+        Some((previous, next))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // every value left in `inner` completes a pair
+        match self.previous {
+            Some(_) => self.inner.size_hint(),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+/// Returns an iterator over the pairs `(v[i], v[i + 1])` of consecutive values of `T` in
+/// enumeration order, e.g. to verify properties of transitions between values.
+///
+/// It yields `size - 1` pairs without collecting the values, and nothing if `T` has fewer than two
+/// values. The size hint is exact if the one of the enumerator of `T` is.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::adjacent_pairs;
+///
+/// assert_eq!(
+///     adjacent_pairs::<Option<bool>>().collect::<Vec<_>>(),
+///     vec![(None, Some(false)), (Some(false), Some(true))]
+/// );
+/// // consecutive values of `(bool, bool)` differ in the last field, or in both
+/// assert!(adjacent_pairs::<(bool, bool)>().all(|(a, b)| a.1 != b.1));
+/// ```
+#[inline]
+pub fn adjacent_pairs<T: Enumerable>() -> AdjacentPairs<T> {
+    AdjacentPairs::new()
+}
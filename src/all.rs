@@ -0,0 +1,101 @@
+//! A marker for iterating over all values of an [`Enumerable`] type with `for` loops.
+
+use crate::Enumerable;
+use core::{fmt, marker::PhantomData};
+
+/// A zero-sized marker standing for all possible values of `T`, which can be iterated over
+/// directly, e.g. `for value in All::<T>::new()`.
+///
+/// It implements [`IntoIterator`] yielding [`T::enumerator()`](Enumerable::enumerator), and has
+/// helpers for the number of values.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::{All, Enumerable};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// let mut pairs = 0;
+/// for (suit, face_up) in All::<(Suit, bool)>::new() {
+///     if suit != Suit::Clubs && face_up {
+///         pairs += 1;
+///     }
+/// }
+/// assert_eq!(pairs, 3);
+///
+/// assert_eq!(All::<Suit>::new().len(), 4);
+/// assert_eq!(All::<Suit>::new().into_iter().last(), Some(Suit::Spades));
+/// ```
+pub struct All<T>(PhantomData<fn() -> T>);
+
+impl<T> All<T> {
+    /// Creates a new `All` for `T`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Enumerable> All<T> {
+    /// Returns the number of possible values of `T`.
+    ///
+    /// Like [`ENUMERABLE_SIZE`](Enumerable::ENUMERABLE_SIZE), it fails to compile if the number
+    /// is unknown or exceeds `usize::MAX`. Use [`All::len_option`] otherwise.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        T::ENUMERABLE_SIZE
+    }
+
+    /// Returns the number of possible values of `T`, or `None` if it's unknown or exceeds
+    /// `usize::MAX`, as [`ENUMERABLE_SIZE_OPTION`](Enumerable::ENUMERABLE_SIZE_OPTION).
+    #[inline]
+    pub const fn len_option(&self) -> Option<usize> {
+        T::ENUMERABLE_SIZE_OPTION
+    }
+
+    /// Returns whether `T` has no possible values, i.e. it's uninhabited.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        !T::IS_INHABITED
+    }
+}
+
+impl<T: Enumerable> IntoIterator for All<T> {
+    type Item = T;
+    type IntoIter = T::Enumerator;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        T::enumerator()
+    }
+}
+
+// implemented manually to avoid requiring `T` to implement these traits
+impl<T> Clone for All<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for All<T> {}
+
+impl<T> Default for All<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for All<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "All<{}>", core::any::type_name::<T>())
+    }
+}
@@ -0,0 +1,249 @@
+//! ASCII characters, a small subset of `char` which is handy for exhaustively testing parsers and
+//! other text processing.
+
+use crate::{
+    ConstEnumerable, Enumerable, ExactSizeEnumerable, OrderedEnumerable, ReversedEnumerator,
+    StepByEnumerator,
+};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Range;
+
+/// The number of ASCII characters.
+const ASCII_COUNT: u8 = 0x80;
+
+/// An ASCII character, i.e. a `char` from `U+0000` to `U+007F`.
+///
+/// Enumerating all of `char` yields more than a million values, while most text processing code
+/// only branches on ASCII. This type enumerates the 128 ASCII characters only, and can be used as a
+/// field of types deriving `Enumerable`.
+///
+/// ## Example
+///
+/// ```rust
+/// use enumerable::{AsciiChar, Enumerable};
+///
+/// let digits = AsciiChar::enumerator()
+///     .filter(|c| c.to_char().is_ascii_digit())
+///     .map(AsciiChar::to_char)
+///     .collect::<String>();
+/// assert_eq!(digits, "0123456789");
+///
+/// assert_eq!(AsciiChar::new(b'a').map(AsciiChar::to_char), Some('a'));
+/// assert_eq!(AsciiChar::new(0x80), None);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AsciiChar(u8);
+
+impl AsciiChar {
+    /// The smallest ASCII character, `U+0000`.
+    pub const MIN: Self = Self(0);
+
+    /// The largest ASCII character, `U+007F`.
+    pub const MAX: Self = Self(ASCII_COUNT - 1);
+
+    /// Returns the ASCII character with the code `byte`, or `None` if `byte` is not ASCII.
+    #[inline]
+    pub const fn new(byte: u8) -> Option<Self> {
+        if byte < ASCII_COUNT {
+            Some(Self(byte))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the ASCII character `c`, or `None` if `c` is not ASCII.
+    #[inline]
+    pub const fn from_char(c: char) -> Option<Self> {
+        if c.is_ascii() {
+            Some(Self(c as u8))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the code of this character as a byte.
+    #[inline]
+    pub const fn to_u8(self) -> u8 {
+        self.0
+    }
+
+    /// Returns this character as a `char`.
+    #[inline]
+    pub const fn to_char(self) -> char {
+        self.0 as char
+    }
+}
+
+impl From<AsciiChar> for u8 {
+    #[inline]
+    fn from(value: AsciiChar) -> Self {
+        value.to_u8()
+    }
+}
+
+impl From<AsciiChar> for char {
+    #[inline]
+    fn from(value: AsciiChar) -> Self {
+        value.to_char()
+    }
+}
+
+impl fmt::Display for AsciiChar {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_char(), f)
+    }
+}
+
+/// `AsciiCharEnumerator` is an iterator over possible values of [`AsciiChar`].
+///
+/// It yields the 128 ASCII characters in code order.
+pub struct AsciiCharEnumerator {
+    /// The codes left to yield.
+    codes: Range<u8>,
+}
+
+impl AsciiCharEnumerator {
+    /// Creates a new enumerator, yielding the same values in the same order as
+    /// `<AsciiChar as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::since(0)
+    }
+
+    /// Creates a new enumerator yielding characters from the code `start` on.
+    #[inline]
+    fn since(start: usize) -> Self {
+        Self {
+            codes: start.min(ASCII_COUNT as usize) as u8..ASCII_COUNT,
+        }
+    }
+}
+
+impl Default for AsciiCharEnumerator {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for AsciiCharEnumerator {
+    type Item = AsciiChar;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.codes.next().map(AsciiChar)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.codes.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.codes.nth(n).map(AsciiChar)
+    }
+}
+
+impl DoubleEndedIterator for AsciiCharEnumerator {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.codes.next_back().map(AsciiChar)
+    }
+}
+
+impl ExactSizeIterator for AsciiCharEnumerator {}
+
+impl FusedIterator for AsciiCharEnumerator {}
+
+/// This is an implementation of the `Enumerable` trait for [`AsciiChar`].
+///
+/// Characters are enumerated in code order, from `U+0000` to `U+007F`.
+#[automatically_derived]
+impl Enumerable for AsciiChar {
+    type Enumerator = AsciiCharEnumerator;
+
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        AsciiCharEnumerator::new()
+    }
+
+    #[inline]
+    fn first_value() -> Option<Self> {
+        Some(Self::MIN)
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        Some(Self::MAX)
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        Some(Self::new(self.0 + 1))
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        Some(self.0.checked_sub(1).map(Self))
+    }
+
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        AsciiCharEnumerator::since(start)
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        u8::try_from(index).ok().and_then(Self::new)
+    }
+
+    #[inline]
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        u8::try_from(index).ok().and_then(Self::new)
+    }
+
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        Some(value.0 as u128)
+    }
+
+    #[inline]
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        Some(AsciiCharEnumerator::since(value.0 as usize))
+    }
+
+    #[inline]
+    fn enumerator_reversed() -> ReversedEnumerator<Self> {
+        ReversedEnumerator::from_double_ended()
+    }
+
+    #[inline]
+    fn enumerator_step_by(start: usize, step: usize) -> StepByEnumerator<Self> {
+        StepByEnumerator::by_index(start, step)
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> = Some(ASCII_COUNT as usize);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some(ASCII_COUNT as u128);
+}
+
+impl ExactSizeEnumerable for AsciiChar {
+    type ExactSizeEnumerator = AsciiCharEnumerator;
+}
+
+impl OrderedEnumerable for AsciiChar {}
+
+impl ConstEnumerable for AsciiChar {
+    const VALUES: &'static [Self] = &{
+        let mut values = [Self::MIN; ASCII_COUNT as usize];
+        let mut index = 0;
+        while index < values.len() {
+            values[index] = Self(index as u8);
+            index += 1;
+        }
+        values
+    };
+}
@@ -0,0 +1,65 @@
+//! Enumeration of a closed interval of values of ordered types.
+
+use crate::{Enumerable, OrderedEnumerable};
+use core::cmp::Ordering;
+
+/// The iterator returned by [`OrderedEnumerable::values_between`], yielding all values from
+/// `start` to `end` inclusive in enumeration order.
+pub struct BetweenEnumerator<T: Enumerable> {
+    /// The enumerator positioned at `start`, or `None` if nothing is left to yield.
+    inner: Option<T::Enumerator>,
+    end: T,
+}
+
+impl<T: OrderedEnumerable> BetweenEnumerator<T> {
+    /// Creates a new `BetweenEnumerator`, yielding the same values in the same order as
+    /// `T::values_between(start, end)`.
+    #[inline]
+    pub fn new(start: T, end: T) -> Self {
+        let inner = if start <= end {
+            Some(T::enumerator_from(start))
+        } else {
+            None
+        };
+
+        Self { inner, end }
+    }
+}
+
+impl<T: OrderedEnumerable> Iterator for BetweenEnumerator<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.inner.as_mut()?.next();
+        match value {
+            Some(value) => match value.cmp(&self.end) {
+                Ordering::Less => Some(value),
+                Ordering::Equal => {
+                    self.inner = None;
+                    Some(value)
+                }
+                Ordering::Greater => {
+                    self.inner = None;
+                    None
+                }
+            },
+            None => {
+                self.inner = None;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Some(inner) => {
+                // at least `end` is left to yield
+                let (lower, upper) = inner.size_hint();
+                (lower.min(1), upper)
+            }
+            None => (0, Some(0)),
+        }
+    }
+}
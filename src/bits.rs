@@ -0,0 +1,341 @@
+//! Fixed-width bit patterns, a compact alternative to tuples or arrays of `bool`s.
+
+use crate::{
+    size_option_from_u128, Enumerable, OrderedEnumerable, ReversedEnumerator, StepByEnumerator,
+};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::Index;
+
+/// A pattern of `N` bits, stored in a `u64`, so `N` must be at most 64.
+///
+/// It's handy to enumerate all combinations of `N` boolean flags, which would otherwise take a
+/// tuple or an array of `N` `bool`s. Bit `i` is the `i`-th least significant bit of
+/// [`to_u64`](Bits::to_u64), and patterns are enumerated in numeric order. Using the type with
+/// `N > 64` is a compile-time error.
+///
+/// ## Example
+///
+/// ```rust
+/// use enumerable::{Bits, Enumerable};
+///
+/// let mut iter = Bits::<2>::enumerator();
+/// assert_eq!(iter.next().map(Bits::to_u64), Some(0b00));
+/// assert_eq!(iter.next().map(Bits::to_u64), Some(0b01));
+/// assert_eq!(iter.next().map(Bits::to_u64), Some(0b10));
+/// assert_eq!(iter.next().map(Bits::to_u64), Some(0b11));
+/// assert_eq!(iter.next(), None);
+///
+/// let flags = Bits::<3>::ZERO.with(0, true).with(2, true);
+/// assert!(flags[0] && !flags[1] && flags[2]);
+/// assert_eq!(flags.to_u64(), 0b101);
+/// ```
+///
+/// ```rust,compile_fail
+/// use enumerable::Bits;
+///
+/// let _ = Bits::<65>::new(0);
+/// ```
+///
+/// ```rust,compile_fail
+/// use enumerable::Bits;
+///
+/// let _ = Bits::<65>::default();
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bits<const N: usize>(u64);
+
+impl<const N: usize> Bits<N> {
+    /// Fails to compile if the bits don't fit in a `u64`.
+    const VALID: () = assert!(N <= 64, "`Bits<N>` holds at most 64 bits");
+
+    /// The mask of the `N` valid bits.
+    const MASK: u64 = {
+        let () = Self::VALID;
+        if N == 64 {
+            u64::MAX
+        } else {
+            (1 << N) - 1
+        }
+    };
+
+    /// The number of bit patterns, i.e. `2^N`.
+    const COUNT: u128 = Self::MASK as u128 + 1;
+
+    /// The pattern with all bits cleared.
+    pub const ZERO: Self = {
+        let () = Self::VALID;
+        Self(0)
+    };
+
+    /// The pattern with all bits set.
+    pub const ALL: Self = {
+        let () = Self::VALID;
+        Self(Self::MASK)
+    };
+
+    /// Returns the pattern with the bits of `value`, or `None` if any bit from `N` on is set.
+    #[inline]
+    pub const fn new(value: u64) -> Option<Self> {
+        if value & !Self::MASK == 0 {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the pattern with the lowest `N` bits of `value`, ignoring the others.
+    #[inline]
+    pub const fn truncate(value: u64) -> Self {
+        Self(value & Self::MASK)
+    }
+
+    /// Returns the bits as a `u64`, whose bits from `N` on are cleared.
+    #[inline]
+    pub const fn to_u64(self) -> u64 {
+        self.0
+    }
+
+    /// Returns whether bit `i` is set.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= N`.
+    #[inline]
+    pub const fn get(self, i: usize) -> bool {
+        assert!(i < N, "bit index out of range");
+        self.0 >> i & 1 == 1
+    }
+
+    /// Returns the pattern with bit `i` set to `value`, and the others unchanged.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= N`.
+    #[inline]
+    pub const fn with(self, i: usize, value: bool) -> Self {
+        assert!(i < N, "bit index out of range");
+        if value {
+            Self(self.0 | 1 << i)
+        } else {
+            Self(self.0 & !(1 << i))
+        }
+    }
+
+    /// Returns the pattern whose bit `i` is `bits[i]`.
+    #[inline]
+    pub fn from_array(bits: [bool; N]) -> Self {
+        bits.iter()
+            .enumerate()
+            .fold(Self::ZERO, |result, (i, &bit)| result.with(i, bit))
+    }
+
+    /// Returns the bits as an array, whose element `i` is bit `i`.
+    #[inline]
+    pub fn to_array(self) -> [bool; N] {
+        core::array::from_fn(|i| self.get(i))
+    }
+}
+
+impl<const N: usize> Default for Bits<N> {
+    /// Returns [`Bits::ZERO`], the pattern with all bits cleared.
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> From<[bool; N]> for Bits<N> {
+    #[inline]
+    fn from(bits: [bool; N]) -> Self {
+        Self::from_array(bits)
+    }
+}
+
+impl<const N: usize> From<Bits<N>> for [bool; N] {
+    #[inline]
+    fn from(bits: Bits<N>) -> Self {
+        bits.to_array()
+    }
+}
+
+impl<const N: usize> Index<usize> for Bits<N> {
+    type Output = bool;
+
+    /// Returns whether bit `i` is set.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `i >= N`.
+    #[inline]
+    fn index(&self, i: usize) -> &bool {
+        if self.get(i) {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+impl<const N: usize> fmt::Debug for Bits<N> {
+    /// Formats the bits from the most significant one, e.g. `Bits<4>(0b0101)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Bits<{}>(0b{:0width$b})", N, self.0, width = N)
+    }
+}
+
+/// `BitsEnumerator` is an iterator over possible values of [`Bits<N>`](Bits).
+///
+/// It yields all `2^N` patterns in numeric order.
+pub struct BitsEnumerator<const N: usize> {
+    /// The numeric value of the next pattern to yield from the front.
+    front: u128,
+    /// One past the numeric value of the next pattern to yield from the back.
+    back: u128,
+}
+
+impl<const N: usize> BitsEnumerator<N> {
+    /// Creates a new enumerator, yielding the same values in the same order as
+    /// `<Bits<N> as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::since(0)
+    }
+
+    /// Creates a new enumerator yielding patterns from the numeric value `start` on.
+    #[inline]
+    fn since(start: u128) -> Self {
+        Self {
+            front: start.min(Bits::<N>::COUNT),
+            back: Bits::<N>::COUNT,
+        }
+    }
+}
+
+impl<const N: usize> Default for BitsEnumerator<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Iterator for BitsEnumerator<N> {
+    type Item = Bits<N>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.back - self.front) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n as u128 >= self.back - self.front {
+            self.front = self.back;
+            return None;
+        }
+
+        // the value is less than `back`, so it fits in `u64`
+        let value = self.front + n as u128;
+        self.front = value + 1;
+        Some(Bits(value as u64))
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for BitsEnumerator<N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(Bits(self.back as u64))
+    }
+}
+
+impl<const N: usize> FusedIterator for BitsEnumerator<N> {}
+
+/// This is an implementation of the `Enumerable` trait for [`Bits<N>`](Bits).
+///
+/// Patterns are enumerated in numeric order, and looked up by index in O(1) time.
+#[automatically_derived]
+impl<const N: usize> Enumerable for Bits<N> {
+    type Enumerator = BitsEnumerator<N>;
+
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        BitsEnumerator::new()
+    }
+
+    #[inline]
+    fn first_value() -> Option<Self> {
+        Some(Self::ZERO)
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        Some(Self::ALL)
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        Some(self.0.checked_add(1).and_then(Self::new))
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        Some(self.0.checked_sub(1).map(Self))
+    }
+
+    /// Returns an iterator over all possible values of this type starting from the `start`-th
+    /// one, positioned in O(1) time.
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        BitsEnumerator::since(start as u128)
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        Self::enumerable_from_index_u128(index as u128)
+    }
+
+    #[inline]
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        u64::try_from(index).ok().and_then(Self::new)
+    }
+
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        Some(value.0 as u128)
+    }
+
+    #[inline]
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        Some(BitsEnumerator::since(value.0 as u128))
+    }
+
+    #[inline]
+    fn enumerator_reversed() -> ReversedEnumerator<Self> {
+        ReversedEnumerator::from_double_ended()
+    }
+
+    #[inline]
+    fn enumerator_step_by(start: usize, step: usize) -> StepByEnumerator<Self> {
+        StepByEnumerator::by_index(start, step)
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> =
+        size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some(Self::COUNT);
+}
+
+impl<const N: usize> OrderedEnumerable for Bits<N> {}
@@ -0,0 +1,124 @@
+//! Process-wide cached slices of all values of [`Enumerable`] types.
+
+use crate::Enumerable;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+    sync::{Mutex, OnceLock},
+};
+
+/// The default limit of [`cached_all`] on the number of values to cache.
+pub const DEFAULT_CACHE_LIMIT: usize = 1 << 20;
+
+/// The error returned by [`try_cached_all`] if a type has too many values to cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooManyValues {
+    /// The number of values of the type, or `None` if it's unknown or exceeds `usize::MAX`.
+    pub size: Option<usize>,
+    /// The limit exceeded.
+    pub limit: usize,
+}
+
+impl fmt::Display for TooManyValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.size {
+            Some(size) => write!(
+                f,
+                "cannot cache {} values, exceeding the limit of {}",
+                size, self.limit
+            ),
+            None => write!(
+                f,
+                "cannot cache values, more than the limit of {}",
+                self.limit
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TooManyValues {}
+
+type Registry = Mutex<HashMap<TypeId, &'static (dyn Any + Send + Sync)>>;
+
+/// Returns the registry of cached slices, keyed by the type of their elements.
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// Returns a slice of all possible values of `T` in enumeration order, cached for the whole
+/// process.
+///
+/// The values are enumerated on the first call for each `T`, and the slice is never freed. Later
+/// calls return the same slice, which is cheaper to iterate and supports binary search if the
+/// enumerator of `T` is sorted.
+///
+/// ## Panics
+///
+/// Panics if `T` has more than [`DEFAULT_CACHE_LIMIT`] values. Use [`try_cached_all`] to choose
+/// another limit and to handle the error.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::cached_all;
+///
+/// let all = cached_all::<Option<bool>>();
+/// assert_eq!(all, &[None, Some(false), Some(true)]);
+/// assert!(std::ptr::eq(all, cached_all::<Option<bool>>()));
+/// ```
+pub fn cached_all<T: Enumerable + Send + Sync + 'static>() -> &'static [T] {
+    match try_cached_all::<T>(DEFAULT_CACHE_LIMIT) {
+        Ok(values) => values,
+        Err(error) => panic!("{}", error),
+    }
+}
+
+/// Returns a slice of all possible values of `T` in enumeration order, cached for the whole
+/// process, or an error if `T` has more than `limit` values.
+///
+/// The limit is checked against [`Enumerable::ENUMERABLE_SIZE_OPTION`] first, and against the
+/// number of values actually enumerated if the size is unknown. Once `T` is cached, the cached slice
+/// is returned if it doesn't exceed `limit`.
+///
+/// If multiple threads call it for the same `T` concurrently for the first time, each of them may
+/// enumerate `T`, but only one slice is kept and all of them get the same one.
+pub fn try_cached_all<T: Enumerable + Send + Sync + 'static>(
+    limit: usize,
+) -> Result<&'static [T], TooManyValues> {
+    let check = |size: usize| {
+        if size <= limit {
+            Ok(())
+        } else {
+            Err(TooManyValues {
+                size: Some(size),
+                limit,
+            })
+        }
+    };
+
+    if let Some(cached) = registry().lock().unwrap().get(&TypeId::of::<T>()) {
+        let cached = cached.downcast_ref::<&'static [T]>().unwrap();
+        return check(cached.len()).map(|_| *cached);
+    }
+
+    if let Some(size) = T::ENUMERABLE_SIZE_OPTION {
+        check(size)?;
+    }
+
+    // the registry is not locked while enumerating, so other types can be cached concurrently
+    let values: Vec<T> = T::enumerator().take(limit.saturating_add(1)).collect();
+    if values.len() > limit {
+        return Err(TooManyValues { size: None, limit });
+    }
+
+    let mut registry = registry().lock().unwrap();
+    let cached = registry.entry(TypeId::of::<T>()).or_insert_with(|| {
+        let values: &'static [T] = Box::leak(values.into_boxed_slice());
+        let values: &'static (dyn Any + Send + Sync) = Box::leak(Box::new(values));
+        values
+    });
+
+    Ok(*cached.downcast_ref::<&'static [T]>().unwrap())
+}
@@ -0,0 +1,124 @@
+//! Enumeration in const contexts, by [`ConstEnumerable::VALUES`], and by arithmetic for types
+//! with too many values to be tabulated.
+
+use crate::ConstEnumerable;
+
+/// Returns the value at `index` in enumeration order, or `None` if `index` is out of range, in
+/// const contexts.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::const_from_index;
+///
+/// const LAST: Option<i8> = const_from_index(255);
+/// assert_eq!(LAST, Some(127));
+/// assert_eq!(const_from_index::<bool>(2), None);
+/// ```
+pub const fn const_from_index<T: ConstEnumerable + Copy>(index: usize) -> Option<T> {
+    if index < T::VALUES.len() {
+        Some(T::VALUES[index])
+    } else {
+        None
+    }
+}
+
+/// Returns an array of all possible values of `T` in enumeration order, in const contexts.
+///
+/// ## Panics
+///
+/// Panics (or fails to compile, if evaluated in a const context) if `N` is not the number of
+/// values of `T`.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::const_values;
+///
+/// const BOOLS: [bool; 2] = const_values();
+/// assert_eq!(BOOLS, [false, true]);
+/// ```
+///
+/// ```compile_fail
+/// use enumerable::const_values;
+///
+/// const BOOLS: [bool; 3] = const_values(); // `bool` has only 2 values
+/// ```
+pub const fn const_values<T: ConstEnumerable + Copy, const N: usize>() -> [T; N] {
+    match T::VALUES.first_chunk::<N>() {
+        Some(values) if values.len() == T::VALUES.len() => *values,
+        _ => panic!("the length of the array differs from the number of values"),
+    }
+}
+
+/// Macro to define const fns looking up values of integer types by index arithmetically, as their
+/// tables would be too large to be built at compile time.
+macro_rules! impl_const_from_index_for_integers {
+    ($($name:ident: $ty:ty),+ $(,)?) => {
+        $(
+            #[doc = concat!("Returns the `", stringify!($ty), "` at `index` in enumeration order, or `None` if `index` is out of")]
+            /// range, in const contexts.
+            ///
+            #[doc = concat!("It's the const counterpart of `<", stringify!($ty), " as Enumerable>::enumerable_from_index_u128`,")]
+            /// computing the value arithmetically in O(1) time.
+            pub const fn $name(index: u128) -> Option<$ty> {
+                // the number of values minus 1, which fits in `u128` even for 128-bit types
+                let last_index = <$ty>::MAX.abs_diff(<$ty>::MIN) as u128;
+                if index > last_index {
+                    None
+                } else {
+                    // `index` is in range, so the wrapping addition is exact
+                    Some(<$ty>::MIN.wrapping_add(index as $ty))
+                }
+            }
+        )+
+    };
+}
+
+impl_const_from_index_for_integers!(
+    const_u8_from_index: u8,
+    const_u16_from_index: u16,
+    const_u32_from_index: u32,
+    const_u64_from_index: u64,
+    const_u128_from_index: u128,
+    const_usize_from_index: usize,
+    const_i8_from_index: i8,
+    const_i16_from_index: i16,
+    const_i32_from_index: i32,
+    const_i64_from_index: i64,
+    const_i128_from_index: i128,
+    const_isize_from_index: isize,
+);
+
+/// Returns the `char` at `index` in enumeration order, or `None` if `index` is out of range, in
+/// const contexts.
+///
+/// It's the const counterpart of `<char as Enumerable>::enumerable_from_index_u128`, skipping the
+/// surrogate code points `U+D800..=U+DFFF` like the enumerator of `char`.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::{const_char_from_index, const_i32_from_index};
+///
+/// const AFTER_SURROGATES: Option<char> = const_char_from_index(0xD800);
+/// assert_eq!(AFTER_SURROGATES, Some('\u{E000}'));
+/// assert_eq!(const_char_from_index(0x10F800), None);
+///
+/// const ZERO: Option<i32> = const_i32_from_index(1 << 31);
+/// assert_eq!(ZERO, Some(0));
+/// ```
+pub const fn const_char_from_index(index: u128) -> Option<char> {
+    const SURROGATES_START: u128 = 0xD800;
+    const SURROGATES_LEN: u128 = 0x800;
+
+    let code_point = if index < SURROGATES_START {
+        index
+    } else if index <= char::MAX as u128 - SURROGATES_LEN {
+        index + SURROGATES_LEN
+    } else {
+        return None;
+    };
+
+    char::from_u32(code_point as u32)
+}
@@ -0,0 +1,183 @@
+//! Diagonal enumeration of product types, advancing all fields fairly.
+
+use crate::Enumerable;
+
+/// A trait for product types, i.e. tuples and structs, whose values are made up of independently
+/// enumerable fields, allowing them to be enumerated in a diagonal order by
+/// [`enumerator_diagonal`](ProductEnumerable::enumerator_diagonal).
+///
+/// It's implemented for tuples of up to 16 elements, and can be derived for structs with fields
+/// by `#[enumerable(diagonal)]`.
+pub trait ProductEnumerable: Enumerable {
+    /// The indices of the fields in their own enumeration order, `[u128; N]` for `N` fields.
+    type FieldIndices: Copy + AsRef<[u128]> + AsMut<[u128]>;
+
+    /// The number of possible values of each field.
+    ///
+    /// Evaluating it fails to compile if the number of values of any field is unknown or exceeds
+    /// `u128::MAX`.
+    const FIELD_SIZES: Self::FieldIndices;
+
+    /// Returns the value with each field at the given index in its own enumeration order, or
+    /// `None` if any index is out of range.
+    fn from_field_indices(indices: Self::FieldIndices) -> Option<Self>;
+
+    /// Returns an iterator over all possible values of the implementing type in diagonal order.
+    ///
+    /// Lexicographic order, used by [`enumerator`](Enumerable::enumerator), exhausts the last field
+    /// before the first one ever advances. Diagonal order instead yields values in shells: all
+    /// values whose greatest field index is 0, then those whose greatest field index is 1, and so
+    /// on. After `n` values of a product of `k` fields, each field has advanced about `n^(1/k)`
+    /// times, unless it's out of values. Every value is still yielded exactly once.
+    ///
+    /// Like [`FIELD_SIZES`](ProductEnumerable::FIELD_SIZES), calling it fails to compile if the
+    /// number of values of any field is unknown.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::ProductEnumerable;
+    ///
+    /// let mut iter = <(u8, u8)>::enumerator_diagonal();
+    /// assert_eq!(
+    ///     iter.by_ref().take(4).collect::<Vec<_>>(),
+    ///     vec![(0, 0), (1, 0), (1, 1), (0, 1)]
+    /// );
+    /// assert_eq!(iter.next(), Some((2, 0)));
+    /// assert_eq!(iter.count(), 65536 - 5);
+    /// ```
+    fn enumerator_diagonal() -> DiagonalEnumerator<Self> {
+        DiagonalEnumerator::new()
+    }
+}
+
+/// The iterator returned by [`ProductEnumerable::enumerator_diagonal`].
+///
+/// Each shell of values whose greatest field index is `shell` is split into blocks by the first
+/// field `pivot` at that index: fields before it are less than `shell`, fields after it are at
+/// most `shell`. Each block is enumerated in lexicographic order.
+pub struct DiagonalEnumerator<T: ProductEnumerable> {
+    /// The number of possible values of each field.
+    sizes: T::FieldIndices,
+    /// The field indices of the next value to yield, or `None` if exhausted.
+    next: Option<T::FieldIndices>,
+    /// The greatest field index in the current shell.
+    shell: u128,
+    /// The first field at index `shell` in the current block.
+    pivot: usize,
+    /// The number of values left to yield, or `None` if it's unknown.
+    remaining: Option<u128>,
+}
+
+impl<T: ProductEnumerable> DiagonalEnumerator<T> {
+    /// Creates a new `DiagonalEnumerator`, yielding the same values in the same order as
+    /// `T::enumerator_diagonal()`.
+    #[inline]
+    pub fn new() -> Self {
+        let sizes = T::FIELD_SIZES;
+        let mut result = Self {
+            sizes,
+            next: None,
+            shell: 0,
+            pivot: 0,
+            remaining: T::ENUMERABLE_SIZE_OPTION_U128,
+        };
+
+        if sizes.as_ref().iter().all(|&size| size > 0) {
+            let mut first = sizes;
+            first.as_mut().fill(0);
+            result.next = Some(first);
+        }
+
+        result
+    }
+
+    /// The exclusive upper bound of the index of the `field` in the current block.
+    #[inline]
+    fn bound(&self, field: usize) -> u128 {
+        let size = self.sizes.as_ref()[field];
+        if field < self.pivot {
+            size.min(self.shell)
+        } else {
+            size.min(self.shell.saturating_add(1))
+        }
+    }
+
+    /// Advances `indices` to the next value in lexicographic order within the current block, and
+    /// returns whether it exists.
+    fn step_in_block(&self, indices: &mut [u128]) -> bool {
+        for field in (0..indices.len()).rev() {
+            if field == self.pivot {
+                continue;
+            }
+
+            if indices[field] + 1 < self.bound(field) {
+                indices[field] += 1;
+                indices[field + 1..].fill(0);
+                if self.pivot > field {
+                    indices[self.pivot] = self.shell;
+                }
+
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Moves to the next non-empty block, and sets `indices` to its first value. Returns whether
+    /// it exists.
+    fn step_block(&mut self, indices: &mut [u128]) -> bool {
+        let max_size = self.sizes.as_ref().iter().copied().max().unwrap_or(0);
+
+        loop {
+            self.pivot += 1;
+            if self.pivot >= indices.len() {
+                self.pivot = 0;
+                self.shell += 1;
+                if self.shell >= max_size {
+                    return false;
+                }
+            }
+
+            // the pivot field must reach `shell`, and fields before it must be able to stay below
+            let pivot_size = self.sizes.as_ref()[self.pivot];
+            if self.shell < pivot_size && (self.pivot == 0 || self.shell > 0) {
+                indices.fill(0);
+                indices[self.pivot] = self.shell;
+                return true;
+            }
+        }
+    }
+}
+
+impl<T: ProductEnumerable> Default for DiagonalEnumerator<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ProductEnumerable> Iterator for DiagonalEnumerator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+
+        let mut indices = current;
+        let advanced = self.step_in_block(indices.as_mut()) || self.step_block(indices.as_mut());
+        self.next = advanced.then_some(indices);
+        self.remaining = self.remaining.map(|remaining| remaining.saturating_sub(1));
+
+        T::from_field_indices(current)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining.map(usize::try_from) {
+            Some(Ok(remaining)) => (remaining, Some(remaining)),
+            Some(Err(_)) => (usize::MAX, None),
+            None => (0, None),
+        }
+    }
+}
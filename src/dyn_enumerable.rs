@@ -0,0 +1,105 @@
+//! An object-safe facade of [`Enumerable`].
+
+use crate::Enumerable;
+use alloc::boxed::Box;
+use core::{any::Any, fmt, marker::PhantomData};
+
+/// An object-safe counterpart of [`Enumerable`], for enumerating values of types chosen at
+/// runtime through `dyn DynEnumerable` trait objects.
+///
+/// [`Enumerable`] itself is not object safe, as it has an associated type, constants and methods
+/// without receivers. `DynEnumerable` is implemented for [`EnumerableProxy<T>`], a zero-sized
+/// stand-in for any `T: Enumerable + 'static`, and yields values boxed as [`Any`], which can be
+/// downcast back to `T`.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::{DynEnumerable, Enumerable, EnumerableProxy};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+/// enum Theme {
+///     Light,
+///     Dark,
+/// }
+///
+/// let registry: Vec<Box<dyn DynEnumerable>> = vec![
+///     Box::new(EnumerableProxy::<Theme>::new()),
+///     Box::new(EnumerableProxy::<Option<bool>>::new()),
+/// ];
+///
+/// let sizes = registry.iter().map(|e| e.dyn_size()).collect::<Vec<_>>();
+/// assert_eq!(sizes, vec![Some(2), Some(3)]);
+///
+/// let themes = registry[0]
+///     .dyn_values()
+///     .map(|value| *value.downcast::<Theme>().unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(themes, vec![Theme::Light, Theme::Dark]);
+/// ```
+pub trait DynEnumerable {
+    /// Returns the name of the enumerated type, as given by [`core::any::type_name`].
+    fn dyn_type_name(&self) -> &'static str;
+
+    /// Returns the number of possible values of the enumerated type, or `None` if it's unknown or
+    /// exceeds `usize::MAX`, like [`Enumerable::ENUMERABLE_SIZE_OPTION`].
+    fn dyn_size(&self) -> Option<usize>;
+
+    /// Returns an iterator over all possible values of the enumerated type in enumeration order,
+    /// each boxed as [`Any`].
+    fn dyn_values(&self) -> Box<dyn Iterator<Item = Box<dyn Any>>>;
+}
+
+/// A zero-sized stand-in for an [`Enumerable`] type `T`, implementing [`DynEnumerable`].
+pub struct EnumerableProxy<T>(PhantomData<fn() -> T>);
+
+impl<T> EnumerableProxy<T> {
+    /// Creates a new `EnumerableProxy` for `T`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+// implemented manually to avoid requiring `T` to implement these traits
+impl<T> Clone for EnumerableProxy<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for EnumerableProxy<T> {}
+
+impl<T> Default for EnumerableProxy<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for EnumerableProxy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "EnumerableProxy<{}>", core::any::type_name::<T>())
+    }
+}
+
+impl<T: Enumerable + 'static> DynEnumerable for EnumerableProxy<T>
+where
+    T::Enumerator: 'static,
+{
+    #[inline]
+    fn dyn_type_name(&self) -> &'static str {
+        core::any::type_name::<T>()
+    }
+
+    #[inline]
+    fn dyn_size(&self) -> Option<usize> {
+        T::ENUMERABLE_SIZE_OPTION
+    }
+
+    #[inline]
+    fn dyn_values(&self) -> Box<dyn Iterator<Item = Box<dyn Any>>> {
+        Box::new(T::enumerator().map(|value| Box::new(value) as Box<dyn Any>))
+    }
+}
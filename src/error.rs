@@ -0,0 +1,43 @@
+//! Errors returned by fallible methods of [`Enumerable`].
+
+use crate::Enumerable;
+use core::fmt;
+
+/// The error returned by [`Enumerable::try_enumerable_from_index`] if the index is out of range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FromIndexError {
+    /// The index requested.
+    pub index: usize,
+    /// The number of values of the type, as in [`Enumerable::ENUMERABLE_SIZE_OPTION`].
+    pub size: Option<usize>,
+}
+
+impl FromIndexError {
+    /// Creates a new `FromIndexError` for `index` out of range of the values of `T`.
+    #[inline]
+    pub fn new<T: Enumerable>(index: usize) -> Self {
+        Self {
+            index,
+            size: T::ENUMERABLE_SIZE_OPTION,
+        }
+    }
+}
+
+impl fmt::Display for FromIndexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.size {
+            Some(size) => write!(
+                f,
+                "index {} out of range for type with {} values",
+                self.index, size
+            ),
+            None => write!(
+                f,
+                "index {} out of range for type with an unknown number of values",
+                self.index
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FromIndexError {}
@@ -0,0 +1,275 @@
+use crate::{size_option_from_u128, Enumerable, ExactSizeEnumerable, ProductEnumerable};
+
+/// `ArrayEnumerator` is an iterator over possible values of `[T; N]`.
+///
+/// It yields arrays in lexicographic order, like tuples: the last element varies the fastest.
+pub struct ArrayEnumerator<T: Enumerable, const N: usize> {
+    /// The enumerators of the elements, positioned after the elements of `next`.
+    enumerators: [T::Enumerator; N],
+    /// The next value to yield, or `None` if exhausted.
+    next: Option<[T; N]>,
+}
+
+impl<T: Enumerable, const N: usize> ArrayEnumerator<T, N> {
+    /// Creates a new enumerator, yielding the same values in the same order as
+    /// `<[T; N] as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        let mut enumerators = core::array::from_fn(|_| T::enumerator());
+        let next = all_some(core::array::from_fn(|i| {
+            Iterator::next(&mut enumerators[i])
+        }));
+
+        Self { enumerators, next }
+    }
+
+    /// Moves to the next value, resetting the elements which overflow.
+    fn step(&mut self) {
+        let Some(next) = &mut self.next else {
+            return;
+        };
+
+        for i in (0..N).rev() {
+            if let Some(value) = self.enumerators[i].next() {
+                next[i] = value;
+                return;
+            }
+
+            // the element is inhabited, so its enumerator yields at least one value
+            self.enumerators[i] = T::enumerator();
+            match self.enumerators[i].next() {
+                Some(value) => next[i] = value,
+                None => break,
+            }
+        }
+
+        self.next = None;
+    }
+}
+
+impl<T: Enumerable, const N: usize> Default for ArrayEnumerator<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Enumerable, const N: usize> Iterator for ArrayEnumerator<T, N> {
+    type Item = [T; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.next.clone()?;
+        self.step();
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next.is_none() {
+            return (0, Some(0));
+        }
+
+        let mut lower: usize = 1;
+        let mut upper: Option<usize> = Some(1);
+        // the number of values a step of the current element skips, i.e. the size of `T` to the
+        // power of the number of later elements, which is at least 1 as the current values exist
+        let mut scale_lower: usize = 1;
+        let mut scale_upper: Option<usize> = Some(1);
+        for enumerator in self.enumerators.iter().rev() {
+            let (element_lower, element_upper) = enumerator.size_hint();
+            lower = lower.saturating_add(element_lower.saturating_mul(scale_lower));
+            upper = match (upper, element_upper, scale_upper) {
+                (Some(upper), Some(element_upper), Some(scale)) => element_upper
+                    .checked_mul(scale)
+                    .and_then(|skipped| upper.checked_add(skipped)),
+                _ => None,
+            };
+            scale_lower = scale_lower.saturating_mul(T::ENUMERABLE_SIZE_OPTION.unwrap_or(1));
+            scale_upper = match (scale_upper, T::ENUMERABLE_SIZE_OPTION) {
+                (Some(scale), Some(size)) => scale.checked_mul(size),
+                _ => None,
+            };
+        }
+
+        (lower, upper)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayEnumerator<T, N>
+where
+    T: Enumerable,
+    T::Enumerator: ExactSizeIterator,
+{
+}
+
+/// Returns the array of the values in `options`, or `None` if any of them is `None`.
+#[inline]
+fn all_some<T, const N: usize>(options: [Option<T>; N]) -> Option<[T; N]> {
+    if options.iter().all(Option::is_some) {
+        Some(options.map(|option| option.unwrap()))
+    } else {
+        None
+    }
+}
+
+/// This is an implementation of the `Enumerable` trait for `[T; N]` where `T` is `Enumerable`.
+///
+/// Arrays are enumerated in lexicographic order like tuples, and `[T; 0]` has exactly one value.
+impl<T: Enumerable, const N: usize> Enumerable for [T; N] {
+    type Enumerator = ArrayEnumerator<T, N>;
+
+    /// This method returns an iterator over all possible values of `[T; N]`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use enumerable::Enumerable;
+    ///
+    /// let mut iter = <[bool; 2]>::enumerator();
+    /// assert_eq!(iter.next(), Some([false, false]));
+    /// assert_eq!(iter.next(), Some([false, true]));
+    /// assert_eq!(iter.next(), Some([true, false]));
+    /// assert_eq!(iter.next(), Some([true, true]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        ArrayEnumerator::new()
+    }
+
+    #[inline]
+    fn first_value() -> Option<Self> {
+        all_some(core::array::from_fn(|_| T::first_value()))
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        all_some(core::array::from_fn(|_| T::last_value()))
+    }
+
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        let mut value = self;
+        for i in (0..N).rev() {
+            match value[i].clone().next_value_direct()? {
+                Some(next) => {
+                    value[i] = next;
+                    return Some(Some(value));
+                }
+                // the element overflows, so it's reset and the previous one is carried into
+                None => match T::first_value() {
+                    Some(first) => value[i] = first,
+                    None => return Some(None),
+                },
+            }
+        }
+
+        Some(None)
+    }
+
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        let mut value = self;
+        for i in (0..N).rev() {
+            match value[i].clone().previous_value_direct()? {
+                Some(previous) => {
+                    value[i] = previous;
+                    return Some(Some(value));
+                }
+                None => match T::last_value() {
+                    Some(last) => value[i] = last,
+                    None => return Some(None),
+                },
+            }
+        }
+
+        Some(None)
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        Self::enumerable_from_index_u128(index as u128)
+    }
+
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        let size = match T::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => size,
+            None => {
+                return usize::try_from(index)
+                    .ok()
+                    .and_then(|index| Self::enumerator().nth(index))
+            }
+        };
+        // the total size is only unknown if it exceeds `u128::MAX`, so any index is in range then
+        if matches!(Self::ENUMERABLE_SIZE_OPTION_U128, Some(total) if index >= total) {
+            return None;
+        }
+
+        // the last element varies the fastest, so it's decoded first
+        let mut digits = [0u128; N];
+        let mut rest = index;
+        for digit in digits.iter_mut().rev() {
+            *digit = rest % size;
+            rest /= size;
+        }
+
+        all_some(digits.map(T::enumerable_from_index_u128))
+    }
+
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        let mut enumerators = all_some(value.clone().map(T::enumerator_from_direct))?;
+        // the enumerators are positioned after the current values
+        for enumerator in &mut enumerators {
+            enumerator.next();
+        }
+
+        Some(ArrayEnumerator {
+            enumerators,
+            next: Some(value),
+        })
+    }
+
+    /// Computes the index in mixed radix from the indices of the elements, which takes `N` calls
+    /// to `T::enumerable_to_index_direct`. It's `None` if the number of values of `[T; N]` is
+    /// unknown or exceeds `u128::MAX`.
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        // the total size fits in `u128`, so none of the partial indices below overflow
+        Self::ENUMERABLE_SIZE_OPTION_U128?;
+        let size = T::ENUMERABLE_SIZE_OPTION_U128?;
+
+        let mut index = 0u128;
+        for element in value {
+            index = index * size + T::enumerable_to_index_direct(element)?;
+        }
+
+        Some(index)
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> =
+        size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = match T::ENUMERABLE_SIZE_OPTION_U128 {
+        _ if N == 0 => Some(1),
+        Some(size @ (0 | 1)) => Some(size),
+        Some(size) if N <= u32::MAX as usize => size.checked_pow(N as u32),
+        _ => None,
+    };
+
+    const IS_INHABITED: bool = N == 0 || T::IS_INHABITED;
+}
+
+impl<T: ExactSizeEnumerable, const N: usize> ExactSizeEnumerable for [T; N] {
+    type ExactSizeEnumerator = ArrayEnumerator<T, N>;
+}
+
+impl<T: Enumerable, const N: usize> ProductEnumerable for [T; N] {
+    type FieldIndices = [u128; N];
+
+    const FIELD_SIZES: Self::FieldIndices = [match T::ENUMERABLE_SIZE_OPTION_U128 {
+        Some(size) => size,
+        None => panic!("the number of values of `T` is unknown or exceeds `u128::MAX`"),
+    }; N];
+
+    #[inline]
+    fn from_field_indices(indices: Self::FieldIndices) -> Option<Self> {
+        all_some(indices.map(T::enumerable_from_index_u128))
+    }
+}
@@ -1,4 +1,10 @@
-use crate::Enumerable;
+use crate::{
+    size_option_from_u128, ConstEnumerable, Enumerable, ExactSizeEnumerable, OrderedEnumerable,
+    ReversedEnumerator, StepByEnumerator, SumEnumerable,
+};
+use core::iter::FusedIterator;
+use core::ops::{Bound, ControlFlow};
+use core::task::Poll;
 
 /// Macro to implement the `Enumerable` trait for a numeric type.
 macro_rules! impl_enumerable_for_numeric_type {
@@ -8,23 +14,91 @@ macro_rules! impl_enumerable_for_numeric_type {
             type Enumerator = core::ops::RangeInclusive<$ty>;
 
             /// Returns an iterator over all possible values of this type.
+            #[inline]
             fn enumerator() -> Self::Enumerator {
                 <$ty>::MIN..=<$ty>::MAX
             }
 
-            const ENUMERABLE_SIZE_OPTION: Option<usize> = {
-                if core::mem::size_of::<$ty>() < core::mem::size_of::<usize>() {
-                    match (<$ty>::MAX.abs_diff(<$ty>::MIN) as usize).checked_add(1) {
-                        Some(size) => Some(size),
-                        None => {
-                            unreachable!()
-                        }
+            #[inline]
+            fn first_value() -> Option<Self> {
+                Some(<$ty>::MIN)
+            }
+
+            #[inline]
+            fn last_value() -> Option<Self> {
+                Some(<$ty>::MAX)
+            }
+
+            #[inline]
+            fn next_value_direct(self) -> Option<Option<Self>> {
+                Some(self.checked_add(1))
+            }
+
+            #[inline]
+            fn previous_value_direct(self) -> Option<Option<Self>> {
+                Some(self.checked_sub(1))
+            }
+
+            /// Returns an iterator over all possible values of this type starting from the
+            /// `start`-th one, positioned in O(1) time.
+            #[inline]
+            fn enumerator_since(start: usize) -> Self::Enumerator {
+                match Self::ENUMERABLE_SIZE_OPTION {
+                    // an exhausted range
+                    Some(size) if start >= size => {
+                        let mut empty = <$ty>::MAX..=<$ty>::MAX;
+                        empty.next();
+                        empty
                     }
-                } else {
-                    None
+                    // `start` is less than the size, so the wrapping addition is exact
+                    _ => <$ty>::MIN.wrapping_add(start as $ty)..=<$ty>::MAX,
                 }
-            };
+            }
+
+            /// Returns the value at `index` in O(1) time. It's `None` if `index` is out of range,
+            /// which is never the case for types at least as wide as `usize`.
+            #[inline]
+            fn enumerable_from_index(index: usize) -> Option<Self> {
+                Self::enumerable_from_index_u128(index as u128)
+            }
+
+            #[inline]
+            fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+                match Self::ENUMERABLE_SIZE_OPTION_U128 {
+                    Some(size) if index >= size => None,
+                    // `index` is less than the size, so the wrapping addition is exact
+                    _ => Some(<$ty>::MIN.wrapping_add(index as $ty)),
+                }
+            }
+
+            #[inline]
+            fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+                Some(value.abs_diff(<$ty>::MIN) as u128)
+            }
+
+            #[inline]
+            fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+                Some(value..=<$ty>::MAX)
+            }
+
+            #[inline]
+            fn enumerator_reversed() -> ReversedEnumerator<Self> {
+                ReversedEnumerator::from_double_ended()
+            }
+
+            #[inline]
+            fn enumerator_step_by(start: usize, step: usize) -> StepByEnumerator<Self> {
+                StepByEnumerator::by_index(start, step)
+            }
+
+            const ENUMERABLE_SIZE_OPTION: Option<usize> =
+                size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+            const ENUMERABLE_SIZE_OPTION_U128: Option<u128> =
+                (<$ty>::MAX.abs_diff(<$ty>::MIN) as u128).checked_add(1);
         }
+
+        impl OrderedEnumerable for $ty {}
     };
 }
 
@@ -40,127 +114,1802 @@ macro_rules! impl_enumerable_for_numeric_types {
 // Implement the `Enumerable` trait for all standard numeric types.
 impl_enumerable_for_numeric_types!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
+/// Macro to implement the `ExactSizeEnumerable` trait for numeric types, whose `RangeInclusive`s
+/// are `ExactSizeIterator`s.
+macro_rules! impl_exact_size_enumerable_for_numeric_types {
+    ($($ty:ty),+) => {
+        $(
+            impl ExactSizeEnumerable for $ty {
+                type ExactSizeEnumerator = core::ops::RangeInclusive<$ty>;
+            }
+        )+
+    };
+}
+
+// `RangeInclusive` of wider types is not an `ExactSizeIterator`, as the length may overflow
+// `usize` on some platforms.
+impl_exact_size_enumerable_for_numeric_types!(u8, u16, i8, i16);
+
+/// Macro to implement the `ConstEnumerable` trait for numeric types, building the tables of all
+/// values at compile time.
+macro_rules! impl_const_enumerable_for_numeric_types {
+    ($($ty:ty),+) => {
+        $(
+            impl ConstEnumerable for $ty {
+                const VALUES: &'static [Self] = &{
+                    let mut values = [0; 1 << <$ty>::BITS];
+                    let mut index = 0;
+                    while index < values.len() {
+                        values[index] = <$ty>::MIN.wrapping_add(index as $ty);
+                        index += 1;
+                    }
+                    values
+                };
+            }
+        )+
+    };
+}
+
+// Tables of wider types are too large to be built at compile time.
+impl_const_enumerable_for_numeric_types!(u8, u16, i8, i16);
+
 /// This is an implementation of the `Enumerable` trait for `bool`.
 impl Enumerable for bool {
     type Enumerator = core::iter::Copied<core::slice::Iter<'static, bool>>;
 
     /// This method returns an iterator over all possible values of `bool`.
+    #[inline]
     fn enumerator() -> Self::Enumerator {
-        const ALL_VARIANTS: &[bool; 2] = &[false, true];
+        Self::VALUES.iter().copied()
+    }
+
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        Self::VALUES[start.min(2)..].iter().copied()
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        Some(true)
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        Some(if self { None } else { Some(true) })
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        Some(if self { Some(false) } else { None })
+    }
+
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        Some(*value as u128)
+    }
+
+    #[inline]
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        Some(Self::enumerator_since(value as usize))
+    }
 
-        ALL_VARIANTS.iter().copied()
+    #[inline]
+    fn enumerator_reversed() -> ReversedEnumerator<Self> {
+        ReversedEnumerator::from_double_ended()
     }
 
     const ENUMERABLE_SIZE_OPTION: Option<usize> = Some(2);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some(2);
 }
 
-/// This is an implementation of the `Enumerable` trait for `char`.
-impl Enumerable for char {
-    type Enumerator =
-        core::iter::Chain<core::ops::RangeInclusive<char>, core::ops::RangeInclusive<char>>;
+impl OrderedEnumerable for bool {}
 
-    /// This method returns an iterator over all possible values of `char`, which is `U+0000` to
-    /// `U+10FFFF`, excluding the surrogate code points.
+impl ExactSizeEnumerable for bool {
+    type ExactSizeEnumerator = core::iter::Copied<core::slice::Iter<'static, bool>>;
+}
+
+impl ConstEnumerable for bool {
+    const VALUES: &'static [Self] = &[false, true];
+}
+
+/// Macro to implement the `Enumerable` and `ConstEnumerable` traits for a type with a small, fixed
+/// list of values, enumerated from a static slice like `bool`.
+macro_rules! impl_enumerable_for_listed_type {
+    ($(#[$attr:meta])* $ty:ty = [$($value:expr),* $(,)?]) => {
+        $(#[$attr])*
+        impl Enumerable for $ty {
+            type Enumerator = core::iter::Copied<core::slice::Iter<'static, $ty>>;
+
+            #[inline]
+            fn enumerator() -> Self::Enumerator {
+                Self::VALUES.iter().copied()
+            }
+
+            #[inline]
+            fn enumerator_since(start: usize) -> Self::Enumerator {
+                Self::VALUES[start.min(Self::VALUES.len())..].iter().copied()
+            }
+
+            #[inline]
+            fn next_value_direct(self) -> Option<Option<Self>> {
+                let index = Self::enumerable_to_index(&self)?;
+                Some(Self::VALUES.get(index + 1).copied())
+            }
+
+            #[inline]
+            fn previous_value_direct(self) -> Option<Option<Self>> {
+                let index = Self::enumerable_to_index(&self)?;
+                Some(index.checked_sub(1).map(|index| Self::VALUES[index]))
+            }
+
+            #[inline]
+            fn enumerable_from_index(index: usize) -> Option<Self> {
+                Self::VALUES.get(index).copied()
+            }
+
+            #[inline]
+            fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+                Self::VALUES.iter().position(|v| v == value).map(|index| index as u128)
+            }
+
+            #[inline]
+            fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+                Some(Self::enumerator_since(Self::VALUES.iter().position(|v| *v == value)?))
+            }
+
+            #[inline]
+            fn enumerator_reversed() -> ReversedEnumerator<Self> {
+                ReversedEnumerator::from_double_ended()
+            }
+
+            const ENUMERABLE_SIZE_OPTION: Option<usize> = Some(Self::VALUES.len());
+
+            const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some(Self::VALUES.len() as u128);
+        }
+
+        impl ExactSizeEnumerable for $ty {
+            type ExactSizeEnumerator = core::iter::Copied<core::slice::Iter<'static, $ty>>;
+        }
+
+        impl ConstEnumerable for $ty {
+            const VALUES: &'static [Self] = &[$($value),*];
+        }
+    };
+}
+
+impl_enumerable_for_listed_type! {
+    /// This is an implementation of the `Enumerable` trait for [`core::cmp::Ordering`], yielding
+    /// `Less`, `Equal` and then `Greater`.
+    core::cmp::Ordering = [
+        core::cmp::Ordering::Less,
+        core::cmp::Ordering::Equal,
+        core::cmp::Ordering::Greater,
+    ]
+}
+
+impl OrderedEnumerable for core::cmp::Ordering {}
+
+impl_enumerable_for_listed_type! {
+    /// This is an implementation of the `Enumerable` trait for [`core::sync::atomic::Ordering`],
+    /// yielding `Relaxed`, `Release`, `Acquire`, `AcqRel` and then `SeqCst`, the order in which
+    /// they're declared.
+    ///
+    /// The enum is `#[non_exhaustive]`. Variants added to it in the future aren't enumerated until
+    /// they're listed here, and [`next_value`](Enumerable::next_value) and
+    /// [`enumerable_to_index`](Enumerable::enumerable_to_index) return `None` for them instead of
+    /// failing.
+    core::sync::atomic::Ordering = [
+        core::sync::atomic::Ordering::Relaxed,
+        core::sync::atomic::Ordering::Release,
+        core::sync::atomic::Ordering::Acquire,
+        core::sync::atomic::Ordering::AcqRel,
+        core::sync::atomic::Ordering::SeqCst,
+    ]
+}
+
+impl_enumerable_for_listed_type! {
+    /// This is an implementation of the `Enumerable` trait for [`core::num::FpCategory`], yielding
+    /// `Nan`, `Infinite`, `Zero`, `Subnormal` and then `Normal`, the order in which they're
+    /// declared.
+    core::num::FpCategory = [
+        core::num::FpCategory::Nan,
+        core::num::FpCategory::Infinite,
+        core::num::FpCategory::Zero,
+        core::num::FpCategory::Subnormal,
+        core::num::FpCategory::Normal,
+    ]
+}
+
+impl_enumerable_for_listed_type! {
+    /// This is an implementation of the `Enumerable` trait for [`core::fmt::Alignment`], yielding
+    /// `Left`, `Right` and then `Center`, the order in which they're declared.
     ///
     /// ## Example
+    ///
     /// ```
+    /// use core::fmt::Alignment;
     /// use enumerable::Enumerable;
     ///
-    /// assert_eq!(char::enumerator().skip(0x41).next(), Some('\u{41}'));
+    /// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    /// struct Spec {
+    ///     align: Alignment,
+    ///     width: Option<bool>,
+    /// }
+    ///
+    /// assert_eq!(Spec::ENUMERABLE_SIZE_OPTION, Some(3 * 3));
+    /// assert_eq!(
+    ///     Spec::enumerator().nth(3),
+    ///     Some(Spec { align: Alignment::Right, width: None })
+    /// );
     /// ```
-    fn enumerator() -> Self::Enumerator {
-        ('\u{0}'..='\u{D7FF}').chain('\u{E000}'..='\u{10FFFF}')
-    }
+    core::fmt::Alignment = [
+        core::fmt::Alignment::Left,
+        core::fmt::Alignment::Right,
+        core::fmt::Alignment::Center,
+    ]
+}
+
+/// Macro to implement the `Enumerable` trait for an uninhabited type, which has no values.
+macro_rules! impl_enumerable_for_uninhabited_type {
+    ($(#[$attr:meta])* $ty:ty) => {
+        $(#[$attr])*
+        impl Enumerable for $ty {
+            type Enumerator = core::iter::Empty<$ty>;
+
+            #[inline]
+            fn enumerator() -> Self::Enumerator {
+                core::iter::empty()
+            }
+
+            #[inline]
+            fn first_value() -> Option<Self> {
+                None
+            }
+
+            #[inline]
+            fn last_value() -> Option<Self> {
+                None
+            }
+
+            #[inline]
+            fn next_value_direct(self) -> Option<Option<Self>> {
+                match self {}
+            }
+
+            #[inline]
+            fn previous_value_direct(self) -> Option<Option<Self>> {
+                match self {}
+            }
+
+            #[inline]
+            fn enumerable_from_index(_index: usize) -> Option<Self> {
+                None
+            }
+
+            #[inline]
+            fn enumerable_from_index_u128(_index: u128) -> Option<Self> {
+                None
+            }
+
+            #[inline]
+            fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+                match *value {}
+            }
+
+            #[inline]
+            fn enumerator_since(_start: usize) -> Self::Enumerator {
+                core::iter::empty()
+            }
+
+            #[inline]
+            fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+                match value {}
+            }
+
+            const ENUMERABLE_SIZE_OPTION: Option<usize> = Some(0);
+
+            const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some(0);
+        }
+
+        impl OrderedEnumerable for $ty {}
+
+        impl ExactSizeEnumerable for $ty {
+            type ExactSizeEnumerator = core::iter::Empty<$ty>;
+        }
 
-    const ENUMERABLE_SIZE_OPTION: Option<usize> = Some((0xD7FF + 1) + (0x10FFFF - 0xE000 + 1));
+        impl ConstEnumerable for $ty {
+            const VALUES: &'static [Self] = &[];
+        }
+    };
 }
 
-/// `OptionEnumerator` is an iterator over possible values of `Option<T>`.
-/// It yields `None` first, then yields `Some(item)` for each possible value of `T`.
-pub struct OptionEnumerator<T: Enumerable> {
-    first: bool,
-    inner: <T as Enumerable>::Enumerator,
+impl_enumerable_for_uninhabited_type! {
+    /// This is an implementation of the `Enumerable` trait for [`core::convert::Infallible`],
+    /// which has no values.
+    core::convert::Infallible
 }
 
-impl<T> OptionEnumerator<T>
-where
-    T: Enumerable,
-{
-    /// Creates a new `OptionEnumerator` that wraps the enumerator of `T`.
-    pub(crate) fn new() -> Self {
+#[cfg(enumerable_never_type)]
+impl_enumerable_for_uninhabited_type! {
+    /// This is an implementation of the `Enumerable` trait for the never type `!`, which has no
+    /// values. It's only available on nightly with `--cfg enumerable_never_type`.
+    !
+}
+
+/// Macro to implement the `Enumerable` trait for a unit-like type, which has exactly one value.
+macro_rules! impl_enumerable_for_unit_type {
+    ($(#[$attr:meta])* [$($generics:tt)*] $ty:ty = $value:expr) => {
+        $(#[$attr])*
+        impl<$($generics)*> Enumerable for $ty {
+            type Enumerator = core::iter::Once<$ty>;
+
+            #[inline]
+            fn enumerator() -> Self::Enumerator {
+                core::iter::once($value)
+            }
+
+            #[inline]
+            fn first_value() -> Option<Self> {
+                Some($value)
+            }
+
+            #[inline]
+            fn last_value() -> Option<Self> {
+                Some($value)
+            }
+
+            #[inline]
+            fn next_value_direct(self) -> Option<Option<Self>> {
+                Some(None)
+            }
+
+            #[inline]
+            fn previous_value_direct(self) -> Option<Option<Self>> {
+                Some(None)
+            }
+
+            #[inline]
+            fn enumerable_from_index(index: usize) -> Option<Self> {
+                (index == 0).then_some($value)
+            }
+
+            #[inline]
+            fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+                (index == 0).then_some($value)
+            }
+
+            #[inline]
+            fn enumerable_to_index_direct(_value: &Self) -> Option<u128> {
+                Some(0)
+            }
+
+            #[inline]
+            fn enumerator_since(start: usize) -> Self::Enumerator {
+                let mut enumerator = core::iter::once($value);
+                if start > 0 {
+                    enumerator.next();
+                }
+
+                enumerator
+            }
+
+            #[inline]
+            fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+                Some(core::iter::once(value))
+            }
+
+            const ENUMERABLE_SIZE_OPTION: Option<usize> = Some(1);
+
+            const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some(1);
+        }
+
+        impl<$($generics)*> ExactSizeEnumerable for $ty {
+            type ExactSizeEnumerator = core::iter::Once<$ty>;
+        }
+    };
+    ($(#[$attr:meta])* $ty:ty = $value:expr) => {
+        impl_enumerable_for_unit_type!($(#[$attr])* [] $ty = $value);
+
+        impl ConstEnumerable for $ty {
+            const VALUES: &'static [Self] = &[$value];
+        }
+    };
+}
+
+impl_enumerable_for_unit_type! {
+    /// This is an implementation of the `Enumerable` trait for [`core::marker::PhantomData<T>`],
+    /// which has exactly one value whatever `T` is, so `T` needn't implement `Enumerable`.
+    [T: ?Sized] core::marker::PhantomData<T> = core::marker::PhantomData
+}
+
+impl<T: ?Sized> OrderedEnumerable for core::marker::PhantomData<T> {}
+
+impl<T: ?Sized + 'static> ConstEnumerable for core::marker::PhantomData<T> {
+    const VALUES: &'static [Self] = &[core::marker::PhantomData];
+}
+
+impl_enumerable_for_unit_type! {
+    /// This is an implementation of the `Enumerable` trait for [`core::marker::PhantomPinned`],
+    /// which has exactly one value.
+    core::marker::PhantomPinned = core::marker::PhantomPinned
+}
+
+impl OrderedEnumerable for core::marker::PhantomPinned {}
+
+impl_enumerable_for_unit_type! {
+    /// This is an implementation of the `Enumerable` trait for [`core::fmt::Error`], which has
+    /// exactly one value.
+    core::fmt::Error = core::fmt::Error
+}
+
+impl OrderedEnumerable for core::fmt::Error {}
+
+impl_enumerable_for_unit_type! {
+    /// This is an implementation of the `Enumerable` trait for [`core::ops::RangeFull`], i.e.
+    /// `..`, which has exactly one value.
+    core::ops::RangeFull = ..
+}
+
+/// `CharEnumerator` is an iterator over possible values of `char`.
+///
+/// It yields all Unicode scalar values from `U+0000` to `U+10FFFF` in ascending order, skipping
+/// the surrogate code points. It keeps the indices of the next values from both ends, so `nth` and
+/// `next_back` take O(1) time.
+#[derive(Clone, Debug)]
+pub struct CharEnumerator {
+    /// The index of the next value to yield from the front.
+    front: u32,
+    /// One past the index of the next value to yield from the back.
+    back: u32,
+}
+
+impl CharEnumerator {
+    /// The number of Unicode scalar values.
+    const COUNT: u32 = 0x10FFFF + 1 - (0xE000 - 0xD800);
+
+    /// Creates a new `CharEnumerator`, yielding the same values in the same order as
+    /// `<char as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::since(0)
+    }
+
+    /// Creates a new enumerator yielding values from the index `start` on.
+    #[inline]
+    fn since(start: usize) -> Self {
         Self {
-            first: true,
-            inner: T::enumerator(),
+            front: u32::try_from(start).map_or(Self::COUNT, |start| start.min(Self::COUNT)),
+            back: Self::COUNT,
         }
     }
-}
 
-/// This is an implementation of the `Iterator` trait for `Option<T>` where `T` is `Enumerable`.
-impl<T> Iterator for OptionEnumerator<T>
-where
-    T: Enumerable,
-{
-    type Item = Option<T>;
+    /// Creates a new enumerator yielding values with indices in `front..back`, which must be
+    /// within `0..=COUNT`.
+    #[inline]
+    pub(crate) fn with_indices(front: u32, back: u32) -> Self {
+        debug_assert!(front <= back && back <= Self::COUNT);
+        Self { front, back }
+    }
 
-    /// Returns the next item from the `OptionEnumerator`.
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.first {
-            self.first = false;
-            Some(None)
+    /// The value at `index`, which must be less than [`Self::COUNT`].
+    #[inline]
+    fn value_at(index: u32) -> char {
+        // indices from `0xD800` on are shifted past the surrogate code points
+        let code = if index < 0xD800 {
+            index
         } else {
-            self.inner.next().map(Some)
-        }
+            index + (0xE000 - 0xD800)
+        };
+
+        // `code` is at most `0x10FFFF` and not a surrogate code point
+        char::from_u32(code).unwrap()
     }
 }
 
-/// This is an implementation of the `Enumerable` trait for `Option<T>` where `T` is `Enumerable`.
-impl<T> Enumerable for Option<T>
-where
-    T: Enumerable,
-{
-    type Enumerator = OptionEnumerator<T>;
+impl Default for CharEnumerator {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-    /// This method returns an iterator over all possible values of `Option<T>`.
-    fn enumerator() -> Self::Enumerator {
-        OptionEnumerator::new()
+impl Iterator for CharEnumerator {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        Some(Self::value_at(self.front - 1))
     }
 
-    const ENUMERABLE_SIZE_OPTION: Option<usize> = {
-        match <T as Enumerable>::ENUMERABLE_SIZE_OPTION {
-            Some(size) => size.checked_add(1),
-            None => None,
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.back - self.front) as usize;
+        (remaining, Some(remaining))
+    }
+
+    /// Returns the `n`-th next value in O(1) time, hopping over the surrogate code points.
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= (self.back - self.front) as usize {
+            self.front = self.back;
+            return None;
         }
-    };
-}
 
-/// Implementation of the `Enumerable` trait for `Result<T, E>`, with core::iter::Chain and core::iter::Map.
-impl<T, E> Enumerable for Result<T, E>
-where
-    T: Enumerable,
-    E: Enumerable,
-{
-    type Enumerator = core::iter::Chain<
-        core::iter::Map<<T as Enumerable>::Enumerator, fn(T) -> Result<T, E>>,
-        core::iter::Map<<E as Enumerable>::Enumerator, fn(E) -> Result<T, E>>,
-    >;
+        // `n` is less than the number of remaining values, so it fits in `u32`
+        self.front += n as u32 + 1;
+        Some(Self::value_at(self.front - 1))
+    }
 
-    /// This method returns an iterator over all possible values of `Result<T, E>`.
-    fn enumerator() -> Self::Enumerator {
-        let t: fn(T) -> Result<T, E> = Ok;
-        let e: fn(E) -> Result<T, E> = Err;
+    #[inline]
+    fn count(self) -> usize {
+        (self.back - self.front) as usize
+    }
 
-        <T as Enumerable>::enumerator()
-            .map(t)
-            .chain(<E as Enumerable>::enumerator().map(e))
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for CharEnumerator {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(Self::value_at(self.back))
     }
 
-    const ENUMERABLE_SIZE_OPTION: Option<usize> = {
-        match (
-            <T as Enumerable>::ENUMERABLE_SIZE_OPTION,
-            <E as Enumerable>::ENUMERABLE_SIZE_OPTION,
-        ) {
-            (Some(t), Some(e)) => t.checked_add(e),
-            _ => None,
+    /// Returns the `n`-th value from the back in O(1) time, hopping over the surrogate code points.
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= (self.back - self.front) as usize {
+            self.back = self.front;
+            return None;
         }
+
+        self.back -= n as u32 + 1;
+        Some(Self::value_at(self.back))
+    }
+}
+
+impl ExactSizeIterator for CharEnumerator {}
+
+impl FusedIterator for CharEnumerator {}
+
+/// This is an implementation of the `Enumerable` trait for `char`.
+impl Enumerable for char {
+    type Enumerator = CharEnumerator;
+
+    /// This method returns an iterator over all possible values of `char`, which is `U+0000` to
+    /// `U+10FFFF`, excluding the surrogate code points.
+    ///
+    /// ## Example
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// assert_eq!(char::enumerator().skip(0x41).next(), Some('\u{41}'));
+    /// ```
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        CharEnumerator::new()
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        Some('\u{10FFFF}')
+    }
+
+    /// This method returns the next Unicode scalar value, skipping the surrogate code points.
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        Some(match self {
+            '\u{D7FF}' => Some('\u{E000}'),
+            c => char::from_u32(c as u32 + 1),
+        })
+    }
+
+    /// This method returns the previous Unicode scalar value, skipping the surrogate code points.
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        Some(match self {
+            '\u{E000}' => Some('\u{D7FF}'),
+            c => (c as u32).checked_sub(1).and_then(char::from_u32),
+        })
+    }
+
+    /// This method returns an iterator over all possible values of `char` starting from the
+    /// `start`-th one, skipping the surrogate code points, positioned in O(1) time.
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        CharEnumerator::since(start)
+    }
+
+    /// This method returns the index of `value`, skipping the surrogate code points.
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        Some(match *value as u128 {
+            code @ ..=0xD7FF => code,
+            code => code - (0xE000 - 0xD800),
+        })
+    }
+
+    /// This method returns the value at `index`, skipping the surrogate code points, in O(1) time.
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        Self::enumerable_from_index_u128(index as u128)
+    }
+
+    /// This method returns the value at `index`, skipping the surrogate code points, in O(1) time.
+    #[inline]
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        let code = match u32::try_from(index).ok()? {
+            code @ ..=0xD7FF => code,
+            // indices from `0xD800` on are shifted past the surrogate code points
+            index => index.checked_add(0xE000 - 0xD800)?,
+        };
+
+        char::from_u32(code)
+    }
+
+    /// This method returns an iterator over all possible values of `char` starting from `value`,
+    /// skipping the surrogate code points.
+    #[inline]
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        Self::enumerable_to_index(&value).map(CharEnumerator::since)
+    }
+
+    #[inline]
+    fn enumerator_reversed() -> ReversedEnumerator<Self> {
+        ReversedEnumerator::from_double_ended()
+    }
+
+    #[inline]
+    fn enumerator_step_by(start: usize, step: usize) -> StepByEnumerator<Self> {
+        StepByEnumerator::by_index(start, step)
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> = Some(CharEnumerator::COUNT as usize);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some(CharEnumerator::COUNT as u128);
+}
+
+impl OrderedEnumerable for char {}
+
+impl ExactSizeEnumerable for char {
+    type ExactSizeEnumerator = CharEnumerator;
+}
+
+/// `OptionEnumerator` is an iterator over possible values of `Option<T>`.
+/// It yields `None` first, then yields `Some(item)` for each possible value of `T`.
+///
+/// It implements `Clone` and `Debug` if the enumerator of `T` does.
+pub struct OptionEnumerator<T: Enumerable> {
+    first: bool,
+    inner: <T as Enumerable>::Enumerator,
+}
+
+impl<T> OptionEnumerator<T>
+where
+    T: Enumerable,
+{
+    /// Creates a new `OptionEnumerator` that wraps the enumerator of `T`.
+    ///
+    /// It yields the same values in the same order as `<Option<T> as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            first: true,
+            inner: T::enumerator(),
+        }
+    }
+}
+
+impl<T> Default for OptionEnumerator<T>
+where
+    T: Enumerable,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for OptionEnumerator<T>
+where
+    T: Enumerable,
+    T::Enumerator: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            first: self.first,
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for OptionEnumerator<T>
+where
+    T: Enumerable,
+    T::Enumerator: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OptionEnumerator")
+            .field("first", &self.first)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+/// This is an implementation of the `Iterator` trait for `Option<T>` where `T` is `Enumerable`.
+impl<T> Iterator for OptionEnumerator<T>
+where
+    T: Enumerable,
+{
+    type Item = Option<T>;
+
+    /// Returns the next item from the `OptionEnumerator`.
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first {
+            self.first = false;
+            Some(None)
+        } else {
+            self.inner.next().map(Some)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        if self.first {
+            (
+                lower.saturating_add(1),
+                upper.and_then(|upper| upper.checked_add(1)),
+            )
+        } else {
+            (lower, upper)
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for OptionEnumerator<T>
+where
+    T: Enumerable,
+    T::Enumerator: DoubleEndedIterator,
+{
+    /// Returns the next item from the back of the `OptionEnumerator`, yielding the leading `None`
+    /// last, unless it has already been yielded from the front.
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.inner.next_back() {
+            Some(value) => Some(Some(value)),
+            None if self.first => {
+                self.first = false;
+                Some(None)
+            }
+            None => None,
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for OptionEnumerator<T>
+where
+    T: Enumerable,
+    T::Enumerator: ExactSizeIterator,
+{
+}
+
+/// This is an implementation of the `Enumerable` trait for `Option<T>` where `T` is `Enumerable`.
+impl<T> Enumerable for Option<T>
+where
+    T: Enumerable,
+{
+    type Enumerator = OptionEnumerator<T>;
+
+    /// This method returns an iterator over all possible values of `Option<T>`.
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        OptionEnumerator::new()
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        Some(T::last_value())
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        match self {
+            None => Some(T::first_value().map(Some)),
+            Some(value) => value.next_value_direct().map(|next| next.map(Some)),
+        }
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        match self {
+            None => Some(None),
+            // `None` comes right before the first `Some(_)`
+            Some(value) => value.previous_value_direct().map(Some),
+        }
+    }
+
+    /// Returns the value at `index`, as efficient as `T::enumerable_from_index`.
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        match index {
+            0 => Some(None),
+            index => T::enumerable_from_index(index - 1).map(Some),
+        }
+    }
+
+    /// Returns the value at `index`, as efficient as `T::enumerable_from_index_u128`.
+    #[inline]
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        match index {
+            0 => Some(None),
+            index => T::enumerable_from_index_u128(index - 1).map(Some),
+        }
+    }
+
+    /// This method returns an iterator over all possible values of `Option<T>` starting from the
+    /// `start`-th one, as efficient as `T::enumerator_since`.
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        match start {
+            0 => OptionEnumerator::new(),
+            start => OptionEnumerator {
+                first: false,
+                inner: T::enumerator_since(start - 1),
+            },
+        }
+    }
+
+    #[inline]
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        match value {
+            None => Some(OptionEnumerator::new()),
+            Some(value) => T::enumerator_from_direct(value).map(|inner| OptionEnumerator {
+                first: false,
+                inner,
+            }),
+        }
+    }
+
+    /// Computes the index as efficient as `T::enumerable_to_index_direct`.
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        match value {
+            None => Some(0),
+            Some(value) => T::enumerable_to_index_direct(value)?.checked_add(1),
+        }
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> =
+        size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = {
+        match <T as Enumerable>::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => size.checked_add(1),
+            None => None,
+        }
+    };
+
+    const IS_INHABITED: bool = true;
+}
+
+impl<T: OrderedEnumerable> OrderedEnumerable for Option<T> {}
+
+impl<T: ExactSizeEnumerable> ExactSizeEnumerable for Option<T> {
+    type ExactSizeEnumerator = OptionEnumerator<T>;
+}
+
+impl<T: Enumerable> SumEnumerable for Option<T> {
+    type VariantSizes = [u128; 2];
+
+    const VARIANT_SIZES: Self::VariantSizes = [
+        1,
+        match T::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => size,
+            None => panic!("the number of values of `T` is unknown or exceeds `u128::MAX`"),
+        },
+    ];
+}
+
+/// `PollEnumerator` is an iterator over possible values of `Poll<T>`.
+/// It yields `Pending` first, then yields `Ready(item)` for each possible value of `T`.
+///
+/// It implements `Clone` and `Debug` if the enumerator of `T` does.
+pub struct PollEnumerator<T: Enumerable> {
+    first: bool,
+    inner: <T as Enumerable>::Enumerator,
+}
+
+impl<T> PollEnumerator<T>
+where
+    T: Enumerable,
+{
+    /// Creates a new `PollEnumerator` that wraps the enumerator of `T`.
+    ///
+    /// It yields the same values in the same order as `<Poll<T> as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            first: true,
+            inner: T::enumerator(),
+        }
+    }
+}
+
+impl<T> Default for PollEnumerator<T>
+where
+    T: Enumerable,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for PollEnumerator<T>
+where
+    T: Enumerable,
+    T::Enumerator: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            first: self.first,
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> core::fmt::Debug for PollEnumerator<T>
+where
+    T: Enumerable,
+    T::Enumerator: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PollEnumerator")
+            .field("first", &self.first)
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T> Iterator for PollEnumerator<T>
+where
+    T: Enumerable,
+{
+    type Item = Poll<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.first {
+            self.first = false;
+            Some(Poll::Pending)
+        } else {
+            self.inner.next().map(Poll::Ready)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        if self.first {
+            (
+                lower.saturating_add(1),
+                upper.and_then(|upper| upper.checked_add(1)),
+            )
+        } else {
+            (lower, upper)
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for PollEnumerator<T>
+where
+    T: Enumerable,
+    T::Enumerator: ExactSizeIterator,
+{
+}
+
+/// This is an implementation of the `Enumerable` trait for `Poll<T>` where `T` is `Enumerable`.
+///
+/// Like `Option<T>`, `Pending` is yielded first, then `Ready(item)` for each possible value of
+/// `T`. Note that `Poll` declares `Ready` first, so `Pending` compares greater than all `Ready(_)`
+/// and the enumeration order is not ascending.
+impl<T> Enumerable for Poll<T>
+where
+    T: Enumerable,
+{
+    type Enumerator = PollEnumerator<T>;
+
+    /// This method returns an iterator over all possible values of `Poll<T>`.
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        PollEnumerator::new()
+    }
+
+    #[inline]
+    fn first_value() -> Option<Self> {
+        Some(Poll::Pending)
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        Some(T::last_value().map_or(Poll::Pending, Poll::Ready))
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        match self {
+            Poll::Pending => Some(T::first_value().map(Poll::Ready)),
+            Poll::Ready(value) => value.next_value_direct().map(|next| next.map(Poll::Ready)),
+        }
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        match self {
+            Poll::Pending => Some(None),
+            // `Pending` comes right before the first `Ready(_)`
+            Poll::Ready(value) => value
+                .previous_value_direct()
+                .map(|previous| Some(previous.map_or(Poll::Pending, Poll::Ready))),
+        }
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        Self::enumerable_from_index_u128(index as u128)
+    }
+
+    #[inline]
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        match index {
+            0 => Some(Poll::Pending),
+            index => T::enumerable_from_index_u128(index - 1).map(Poll::Ready),
+        }
+    }
+
+    /// This method returns an iterator over all possible values of `Poll<T>` starting from the
+    /// `start`-th one, as efficient as `T::enumerator_since`.
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        match start {
+            0 => PollEnumerator::new(),
+            start => PollEnumerator {
+                first: false,
+                inner: T::enumerator_since(start - 1),
+            },
+        }
+    }
+
+    #[inline]
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        match value {
+            Poll::Pending => Some(PollEnumerator::new()),
+            Poll::Ready(value) => T::enumerator_from_direct(value).map(|inner| PollEnumerator {
+                first: false,
+                inner,
+            }),
+        }
+    }
+
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        match value {
+            Poll::Pending => Some(0),
+            Poll::Ready(value) => T::enumerable_to_index_direct(value)?.checked_add(1),
+        }
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> =
+        size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = {
+        match <T as Enumerable>::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => size.checked_add(1),
+            None => None,
+        }
+    };
+
+    const IS_INHABITED: bool = true;
+}
+
+impl<T: ExactSizeEnumerable> ExactSizeEnumerable for Poll<T> {
+    type ExactSizeEnumerator = PollEnumerator<T>;
+}
+
+impl<T: Enumerable> SumEnumerable for Poll<T> {
+    type VariantSizes = [u128; 2];
+
+    const VARIANT_SIZES: Self::VariantSizes = [
+        1,
+        match T::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => size,
+            None => panic!("the number of values of `T` is unknown or exceeds `u128::MAX`"),
+        },
+    ];
+}
+
+/// `ResultEnumerator` is an iterator over possible values of `Result<T, E>`.
+/// It yields `Ok(item)` for each possible value of `T`, then `Err(error)` for each possible value
+/// of `E`.
+///
+/// It implements `Clone` and `Debug` if the enumerators of both `T` and `E` do.
+pub struct ResultEnumerator<T: Enumerable, E: Enumerable> {
+    ok: <T as Enumerable>::Enumerator,
+    err: <E as Enumerable>::Enumerator,
+}
+
+impl<T, E> ResultEnumerator<T, E>
+where
+    T: Enumerable,
+    E: Enumerable,
+{
+    /// Creates a new `ResultEnumerator` that wraps the enumerators of `T` and `E`.
+    ///
+    /// It yields the same values in the same order as `<Result<T, E> as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            ok: T::enumerator(),
+            err: E::enumerator(),
+        }
+    }
+}
+
+impl<T, E> Default for ResultEnumerator<T, E>
+where
+    T: Enumerable,
+    E: Enumerable,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, E> Clone for ResultEnumerator<T, E>
+where
+    T: Enumerable,
+    E: Enumerable,
+    T::Enumerator: Clone,
+    E::Enumerator: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            ok: self.ok.clone(),
+            err: self.err.clone(),
+        }
+    }
+}
+
+impl<T, E> core::fmt::Debug for ResultEnumerator<T, E>
+where
+    T: Enumerable,
+    E: Enumerable,
+    T::Enumerator: core::fmt::Debug,
+    E::Enumerator: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ResultEnumerator")
+            .field("ok", &self.ok)
+            .field("err", &self.err)
+            .finish()
+    }
+}
+
+impl<T, E> Iterator for ResultEnumerator<T, E>
+where
+    T: Enumerable,
+    E: Enumerable,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.ok.next() {
+            Some(value) => Some(Ok(value)),
+            None => self.err.next().map(Err),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (ok_lower, ok_upper) = self.ok.size_hint();
+        let (err_lower, err_upper) = self.err.size_hint();
+        let upper = match (ok_upper, err_upper) {
+            (Some(ok_upper), Some(err_upper)) => ok_upper.checked_add(err_upper),
+            _ => None,
+        };
+
+        (ok_lower.saturating_add(err_lower), upper)
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
+        match self.ok.size_hint() {
+            // the `Ok` values left are known, so they're skipped in one go
+            (ok_remaining, Some(upper)) if ok_remaining == upper => {
+                if n < ok_remaining {
+                    return self.ok.nth(n).map(Ok);
+                }
+
+                if ok_remaining > 0 {
+                    self.ok.nth(ok_remaining - 1);
+                }
+                n -= ok_remaining;
+            }
+            _ => {
+                while n > 0 {
+                    if self.ok.next().is_none() {
+                        break;
+                    }
+                    n -= 1;
+                }
+
+                if let Some(value) = self.ok.next() {
+                    return Some(Ok(value));
+                }
+            }
+        }
+
+        self.err.nth(n).map(Err)
+    }
+}
+
+impl<T, E> DoubleEndedIterator for ResultEnumerator<T, E>
+where
+    T: Enumerable,
+    E: Enumerable,
+    T::Enumerator: DoubleEndedIterator,
+    E::Enumerator: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.err.next_back() {
+            Some(value) => Some(Err(value)),
+            None => self.ok.next_back().map(Ok),
+        }
+    }
+}
+
+impl<T, E> ExactSizeIterator for ResultEnumerator<T, E>
+where
+    T: Enumerable,
+    E: Enumerable,
+    T::Enumerator: ExactSizeIterator,
+    E::Enumerator: ExactSizeIterator,
+{
+}
+
+impl<T, E> FusedIterator for ResultEnumerator<T, E>
+where
+    T: Enumerable,
+    E: Enumerable,
+    T::Enumerator: FusedIterator,
+    E::Enumerator: FusedIterator,
+{
+}
+
+/// This is an implementation of the `Enumerable` trait for `Result<T, E>` where `T` and `E` are
+/// `Enumerable`.
+impl<T, E> Enumerable for Result<T, E>
+where
+    T: Enumerable,
+    E: Enumerable,
+{
+    type Enumerator = ResultEnumerator<T, E>;
+
+    /// This method returns an iterator over all possible values of `Result<T, E>`.
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        ResultEnumerator::new()
+    }
+
+    #[inline]
+    fn first_value() -> Option<Self> {
+        match T::first_value() {
+            Some(t) => Some(Ok(t)),
+            None => E::first_value().map(Err),
+        }
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        match E::last_value() {
+            Some(e) => Some(Err(e)),
+            None => T::last_value().map(Ok),
+        }
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        match self {
+            Ok(t) => t
+                .next_value_direct()
+                .map(|next| next.map(Ok).or_else(|| E::first_value().map(Err))),
+            Err(e) => e.next_value_direct().map(|next| next.map(Err)),
+        }
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        match self {
+            Ok(t) => t.previous_value_direct().map(|previous| previous.map(Ok)),
+            Err(e) => e
+                .previous_value_direct()
+                .map(|previous| previous.map(Err).or_else(|| T::last_value().map(Ok))),
+        }
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        Self::enumerable_from_index_u128(index as u128)
+    }
+
+    /// Looks up the value at `index` in `T` or `E`, as efficient as
+    /// `T::enumerable_from_index_u128` and `E::enumerable_from_index_u128`.
+    ///
+    /// If the number of values of `T` is unknown, the first `Err` value can't be located, so the
+    /// enumerator is stepped through instead, taking O(`index`) time.
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        match T::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size_t) if index >= size_t => {
+                E::enumerable_from_index_u128(index - size_t).map(Err)
+            }
+            Some(_) => T::enumerable_from_index_u128(index).map(Ok),
+            None => usize::try_from(index)
+                .ok()
+                .and_then(|index| Self::enumerator().nth(index)),
+        }
+    }
+
+    /// This method returns an iterator over all possible values of `Result<T, E>` starting from the
+    /// `start`-th one, as efficient as `T::enumerator_since` and `E::enumerator_since`.
+    ///
+    /// If the number of values of `T` is unknown, the enumerator of `T` is positioned at `start`,
+    /// and skipping past it into the `Err` values takes O(`start`) time.
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        match <T as Enumerable>::ENUMERABLE_SIZE_OPTION {
+            Some(size_t) if start >= size_t => ResultEnumerator {
+                ok: <T as Enumerable>::enumerator_since(size_t),
+                err: <E as Enumerable>::enumerator_since(start - size_t),
+            },
+            _ => ResultEnumerator {
+                ok: <T as Enumerable>::enumerator_since(start),
+                err: <E as Enumerable>::enumerator(),
+            },
+        }
+    }
+
+    #[inline]
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        match value {
+            Ok(value) => Some(ResultEnumerator {
+                ok: <T as Enumerable>::enumerator_from_direct(value)?,
+                err: <E as Enumerable>::enumerator(),
+            }),
+            Err(value) => {
+                // the enumerator of `T` stepped past its last value, if any
+                let exhausted = match <T as Enumerable>::last_value() {
+                    Some(last) => {
+                        let mut exhausted = <T as Enumerable>::enumerator_from_direct(last)?;
+                        exhausted.next();
+                        exhausted
+                    }
+                    None => <T as Enumerable>::enumerator(),
+                };
+
+                Some(ResultEnumerator {
+                    ok: exhausted,
+                    err: <E as Enumerable>::enumerator_from_direct(value)?,
+                })
+            }
+        }
+    }
+
+    /// Computes the index as efficient as `T::enumerable_to_index_direct` and
+    /// `E::enumerable_to_index_direct`. Indices of `Err` values are offset by the number of values
+    /// of `T`, so they are `None` if it's unknown.
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        match value {
+            Ok(t) => T::enumerable_to_index_direct(t),
+            Err(e) => {
+                E::enumerable_to_index_direct(e)?.checked_add(T::ENUMERABLE_SIZE_OPTION_U128?)
+            }
+        }
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> =
+        size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = {
+        match (
+            <T as Enumerable>::ENUMERABLE_SIZE_OPTION_U128,
+            <E as Enumerable>::ENUMERABLE_SIZE_OPTION_U128,
+        ) {
+            (Some(t), Some(e)) => t.checked_add(e),
+            _ => None,
+        }
+    };
+
+    const IS_INHABITED: bool = T::IS_INHABITED || E::IS_INHABITED;
+}
+
+impl<T: OrderedEnumerable, E: OrderedEnumerable> OrderedEnumerable for Result<T, E> {}
+
+impl<T: ExactSizeEnumerable, E: ExactSizeEnumerable> ExactSizeEnumerable for Result<T, E> {
+    type ExactSizeEnumerator = ResultEnumerator<T, E>;
+}
+
+impl<T: Enumerable, E: Enumerable> SumEnumerable for Result<T, E> {
+    type VariantSizes = [u128; 2];
+
+    const VARIANT_SIZES: Self::VariantSizes = [
+        match T::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => size,
+            None => panic!("the number of values of `T` is unknown or exceeds `u128::MAX`"),
+        },
+        match E::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => size,
+            None => panic!("the number of values of `E` is unknown or exceeds `u128::MAX`"),
+        },
+    ];
+}
+
+/// Implementation of the `Enumerable` trait for `ControlFlow<B, C>`, with core::iter::Chain and
+/// core::iter::Map.
+///
+/// All `Continue(c)` values are yielded first, in the enumeration order of `C`, and then all
+/// `Break(b)` values, in the enumeration order of `B`. It's the same order as
+/// `Result<C, B>`, as `Continue` is the "keep going" variant like `Ok`.
+impl<B, C> Enumerable for ControlFlow<B, C>
+where
+    B: Enumerable,
+    C: Enumerable,
+{
+    type Enumerator = core::iter::Chain<
+        core::iter::Map<<C as Enumerable>::Enumerator, fn(C) -> ControlFlow<B, C>>,
+        core::iter::Map<<B as Enumerable>::Enumerator, fn(B) -> ControlFlow<B, C>>,
+    >;
+
+    /// This method returns an iterator over all possible values of `ControlFlow<B, C>`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::ops::ControlFlow;
+    /// use enumerable::Enumerable;
+    ///
+    /// let mut iter = <ControlFlow<bool, ()>>::enumerator();
+    /// assert_eq!(iter.next(), Some(ControlFlow::Continue(())));
+    /// assert_eq!(iter.next(), Some(ControlFlow::Break(false)));
+    /// assert_eq!(iter.next(), Some(ControlFlow::Break(true)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        Self::enumerator_since(0)
+    }
+
+    #[inline]
+    fn first_value() -> Option<Self> {
+        match C::first_value() {
+            Some(c) => Some(ControlFlow::Continue(c)),
+            None => B::first_value().map(ControlFlow::Break),
+        }
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        match B::last_value() {
+            Some(b) => Some(ControlFlow::Break(b)),
+            None => C::last_value().map(ControlFlow::Continue),
+        }
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        match self {
+            ControlFlow::Continue(c) => c.next_value_direct().map(|next| {
+                next.map(ControlFlow::Continue)
+                    .or_else(|| B::first_value().map(ControlFlow::Break))
+            }),
+            ControlFlow::Break(b) => b
+                .next_value_direct()
+                .map(|next| next.map(ControlFlow::Break)),
+        }
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        match self {
+            ControlFlow::Continue(c) => c
+                .previous_value_direct()
+                .map(|previous| previous.map(ControlFlow::Continue)),
+            ControlFlow::Break(b) => b.previous_value_direct().map(|previous| {
+                previous
+                    .map(ControlFlow::Break)
+                    .or_else(|| C::last_value().map(ControlFlow::Continue))
+            }),
+        }
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        Self::enumerable_from_index_u128(index as u128)
+    }
+
+    /// Looks up the value at `index` in `C` or `B`, as efficient as
+    /// `C::enumerable_from_index_u128` and `B::enumerable_from_index_u128`.
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        match C::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size_c) if index >= size_c => {
+                B::enumerable_from_index_u128(index - size_c).map(ControlFlow::Break)
+            }
+            Some(_) => C::enumerable_from_index_u128(index).map(ControlFlow::Continue),
+            // the first `Break` value can't be located without the size of `C`
+            None => usize::try_from(index)
+                .ok()
+                .and_then(|index| Self::enumerator().nth(index)),
+        }
+    }
+
+    /// This method returns an iterator over all possible values of `ControlFlow<B, C>` starting
+    /// from the `start`-th one, as efficient as `C::enumerator_since` and `B::enumerator_since`.
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        let c: fn(C) -> ControlFlow<B, C> = ControlFlow::Continue;
+        let b: fn(B) -> ControlFlow<B, C> = ControlFlow::Break;
+
+        match <C as Enumerable>::ENUMERABLE_SIZE_OPTION {
+            Some(size_c) if start >= size_c => <C as Enumerable>::enumerator_since(size_c)
+                .map(c)
+                .chain(<B as Enumerable>::enumerator_since(start - size_c).map(b)),
+            _ => <C as Enumerable>::enumerator_since(start)
+                .map(c)
+                .chain(<B as Enumerable>::enumerator().map(b)),
+        }
+    }
+
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        match value {
+            ControlFlow::Continue(c) => C::enumerable_to_index_direct(c),
+            ControlFlow::Break(b) => {
+                B::enumerable_to_index_direct(b)?.checked_add(C::ENUMERABLE_SIZE_OPTION_U128?)
+            }
+        }
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> =
+        size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = {
+        match (
+            <C as Enumerable>::ENUMERABLE_SIZE_OPTION_U128,
+            <B as Enumerable>::ENUMERABLE_SIZE_OPTION_U128,
+        ) {
+            (Some(c), Some(b)) => c.checked_add(b),
+            _ => None,
+        }
+    };
+
+    const IS_INHABITED: bool = B::IS_INHABITED || C::IS_INHABITED;
+}
+
+impl<B: Enumerable, C: Enumerable> SumEnumerable for ControlFlow<B, C> {
+    type VariantSizes = [u128; 2];
+
+    const VARIANT_SIZES: Self::VariantSizes = [
+        match C::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => size,
+            None => panic!("the number of values of `C` is unknown or exceeds `u128::MAX`"),
+        },
+        match B::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => size,
+            None => panic!("the number of values of `B` is unknown or exceeds `u128::MAX`"),
+        },
+    ];
+}
+
+/// Implementation of the `Enumerable` trait for `Bound<T>`, with core::iter::Chain,
+/// core::iter::Map and core::iter::Once.
+///
+/// Variants are yielded in the order in which they're declared: `Included(item)` for each
+/// possible value of `T`, then `Excluded(item)` for each possible value of `T`, and finally
+/// `Unbounded`.
+impl<T> Enumerable for Bound<T>
+where
+    T: Enumerable,
+{
+    type Enumerator = core::iter::Chain<
+        core::iter::Chain<
+            core::iter::Map<<T as Enumerable>::Enumerator, fn(T) -> Bound<T>>,
+            core::iter::Map<<T as Enumerable>::Enumerator, fn(T) -> Bound<T>>,
+        >,
+        core::iter::Once<Bound<T>>,
+    >;
+
+    /// This method returns an iterator over all possible values of `Bound<T>`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::ops::Bound;
+    /// use enumerable::Enumerable;
+    ///
+    /// let mut iter = <Bound<bool>>::enumerator();
+    /// assert_eq!(iter.next(), Some(Bound::Included(false)));
+    /// assert_eq!(iter.next(), Some(Bound::Included(true)));
+    /// assert_eq!(iter.next(), Some(Bound::Excluded(false)));
+    /// assert_eq!(iter.next(), Some(Bound::Excluded(true)));
+    /// assert_eq!(iter.next(), Some(Bound::Unbounded));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        let included: fn(T) -> Bound<T> = Bound::Included;
+        let excluded: fn(T) -> Bound<T> = Bound::Excluded;
+
+        <T as Enumerable>::enumerator()
+            .map(included)
+            .chain(<T as Enumerable>::enumerator().map(excluded))
+            .chain(core::iter::once(Bound::Unbounded))
+    }
+
+    #[inline]
+    fn first_value() -> Option<Self> {
+        Some(T::first_value().map_or(Bound::Unbounded, Bound::Included))
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        Some(Bound::Unbounded)
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        match self {
+            Bound::Included(value) => value.next_value_direct().map(|next| {
+                Some(match next {
+                    Some(next) => Bound::Included(next),
+                    // `value` exists, so the first value does too
+                    None => T::first_value().map_or(Bound::Unbounded, Bound::Excluded),
+                })
+            }),
+            Bound::Excluded(value) => value
+                .next_value_direct()
+                .map(|next| Some(next.map_or(Bound::Unbounded, Bound::Excluded))),
+            Bound::Unbounded => Some(None),
+        }
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        match self {
+            Bound::Included(value) => value
+                .previous_value_direct()
+                .map(|previous| previous.map(Bound::Included)),
+            Bound::Excluded(value) => {
+                value
+                    .previous_value_direct()
+                    .map(|previous| match previous {
+                        Some(previous) => Some(Bound::Excluded(previous)),
+                        None => T::last_value().map(Bound::Included),
+                    })
+            }
+            Bound::Unbounded => Some(T::last_value().map(Bound::Excluded)),
+        }
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        Self::enumerable_from_index_u128(index as u128)
+    }
+
+    /// Looks up the value at `index` in the segment it falls in, as efficient as
+    /// `T::enumerable_from_index_u128`.
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        match T::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) if index < size => T::enumerable_from_index_u128(index).map(Bound::Included),
+            Some(size) if index - size < size => {
+                T::enumerable_from_index_u128(index - size).map(Bound::Excluded)
+            }
+            Some(size) if index - size == size => Some(Bound::Unbounded),
+            Some(_) => None,
+            // the segments can't be located without the size of `T`
+            None => usize::try_from(index)
+                .ok()
+                .and_then(|index| Self::enumerator().nth(index)),
+        }
+    }
+
+    /// This method returns an iterator over all possible values of `Bound<T>` starting from the
+    /// `start`-th one, as efficient as `T::enumerator_since`.
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        let included: fn(T) -> Bound<T> = Bound::Included;
+        let excluded: fn(T) -> Bound<T> = Bound::Excluded;
+
+        let Some(size) = <T as Enumerable>::ENUMERABLE_SIZE_OPTION else {
+            let mut enumerator = Self::enumerator();
+            if start > 0 {
+                enumerator.nth(start - 1);
+            }
+
+            return enumerator;
+        };
+
+        let (included_start, excluded_start) = if start < size {
+            (start, 0)
+        } else {
+            (size, (start - size).min(size))
+        };
+        // `Unbounded` is skipped only if `start` is past it, i.e. past both segments
+        let mut unbounded = core::iter::once(Bound::Unbounded);
+        if start - included_start > excluded_start {
+            unbounded.next();
+        }
+
+        <T as Enumerable>::enumerator_since(included_start)
+            .map(included)
+            .chain(<T as Enumerable>::enumerator_since(excluded_start).map(excluded))
+            .chain(unbounded)
+    }
+
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        match value {
+            Bound::Included(value) => T::enumerable_to_index_direct(value),
+            Bound::Excluded(value) => {
+                T::enumerable_to_index_direct(value)?.checked_add(T::ENUMERABLE_SIZE_OPTION_U128?)
+            }
+            Bound::Unbounded => T::ENUMERABLE_SIZE_OPTION_U128?.checked_mul(2),
+        }
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> =
+        size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = {
+        match <T as Enumerable>::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => match size.checked_mul(2) {
+                Some(size) => size.checked_add(1),
+                None => None,
+            },
+            None => None,
+        }
+    };
+
+    const IS_INHABITED: bool = true;
+}
+
+impl<T: Enumerable> SumEnumerable for Bound<T> {
+    type VariantSizes = [u128; 3];
+
+    const VARIANT_SIZES: Self::VariantSizes = {
+        let size = match T::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => size,
+            None => panic!("the number of values of `T` is unknown or exceeds `u128::MAX`"),
+        };
+
+        [size, size, 1]
     };
 }
@@ -0,0 +1,249 @@
+//! Implementation of [`Enumerable`] for [`Either`], enabled by the `either` feature.
+
+use crate::{Enumerable, ExactSizeEnumerable, OrderedEnumerable, ResultEnumerator, SumEnumerable};
+use core::iter::FusedIterator;
+use either::Either;
+
+/// Converts `Ok` to `Left` and `Err` to `Right`, which keeps the enumeration order of `Result`.
+#[inline]
+fn either_from_result<L, R>(value: Result<L, R>) -> Either<L, R> {
+    match value {
+        Ok(left) => Either::Left(left),
+        Err(right) => Either::Right(right),
+    }
+}
+
+/// Converts `Left` to `Ok` and `Right` to `Err`, the inverse of [`either_from_result`].
+#[inline]
+fn result_from_either<L, R>(value: Either<L, R>) -> Result<L, R> {
+    match value {
+        Either::Left(left) => Ok(left),
+        Either::Right(right) => Err(right),
+    }
+}
+
+/// `EitherEnumerator` is an iterator over possible values of [`Either<L, R>`].
+/// It yields `Left(item)` for each possible value of `L`, then `Right(item)` for each possible
+/// value of `R`.
+///
+/// It implements `Clone` and `Debug` if the enumerators of both `L` and `R` do.
+pub struct EitherEnumerator<L: Enumerable, R: Enumerable> {
+    inner: ResultEnumerator<L, R>,
+}
+
+impl<L, R> EitherEnumerator<L, R>
+where
+    L: Enumerable,
+    R: Enumerable,
+{
+    /// Creates a new `EitherEnumerator` that wraps the enumerators of `L` and `R`.
+    ///
+    /// It yields the same values in the same order as `<Either<L, R> as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: ResultEnumerator::new(),
+        }
+    }
+}
+
+impl<L, R> Default for EitherEnumerator<L, R>
+where
+    L: Enumerable,
+    R: Enumerable,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L, R> Clone for EitherEnumerator<L, R>
+where
+    L: Enumerable,
+    R: Enumerable,
+    L::Enumerator: Clone,
+    R::Enumerator: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<L, R> core::fmt::Debug for EitherEnumerator<L, R>
+where
+    L: Enumerable,
+    R: Enumerable,
+    L::Enumerator: core::fmt::Debug,
+    R::Enumerator: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EitherEnumerator")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<L, R> Iterator for EitherEnumerator<L, R>
+where
+    L: Enumerable,
+    R: Enumerable,
+{
+    type Item = Either<L, R>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(either_from_result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(either_from_result)
+    }
+}
+
+impl<L, R> DoubleEndedIterator for EitherEnumerator<L, R>
+where
+    L: Enumerable,
+    R: Enumerable,
+    L::Enumerator: DoubleEndedIterator,
+    R::Enumerator: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(either_from_result)
+    }
+}
+
+impl<L, R> ExactSizeIterator for EitherEnumerator<L, R>
+where
+    L: Enumerable,
+    R: Enumerable,
+    L::Enumerator: ExactSizeIterator,
+    R::Enumerator: ExactSizeIterator,
+{
+}
+
+impl<L, R> FusedIterator for EitherEnumerator<L, R>
+where
+    L: Enumerable,
+    R: Enumerable,
+    L::Enumerator: FusedIterator,
+    R::Enumerator: FusedIterator,
+{
+}
+
+/// This is an implementation of the `Enumerable` trait for [`Either<L, R>`] where `L` and `R` are
+/// `Enumerable`.
+///
+/// Like `Result<L, R>`, all `Left` values are yielded before all `Right` values, and lookups are
+/// as efficient as those of `Result<L, R>`.
+///
+/// ## Example
+///
+/// ```rust
+/// use either::Either;
+/// use enumerable::Enumerable;
+///
+/// let mut iter = <Either<bool, ()>>::enumerator();
+/// assert_eq!(iter.next(), Some(Either::Left(false)));
+/// assert_eq!(iter.next(), Some(Either::Left(true)));
+/// assert_eq!(iter.next(), Some(Either::Right(())));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[automatically_derived]
+impl<L, R> Enumerable for Either<L, R>
+where
+    L: Enumerable,
+    R: Enumerable,
+{
+    type Enumerator = EitherEnumerator<L, R>;
+
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        EitherEnumerator::new()
+    }
+
+    #[inline]
+    fn first_value() -> Option<Self> {
+        <Result<L, R>>::first_value().map(either_from_result)
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        <Result<L, R>>::last_value().map(either_from_result)
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        result_from_either(self)
+            .next_value_direct()
+            .map(|next| next.map(either_from_result))
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        result_from_either(self)
+            .previous_value_direct()
+            .map(|previous| previous.map(either_from_result))
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        <Result<L, R>>::enumerable_from_index(index).map(either_from_result)
+    }
+
+    #[inline]
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        <Result<L, R>>::enumerable_from_index_u128(index).map(either_from_result)
+    }
+
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        EitherEnumerator {
+            inner: <Result<L, R>>::enumerator_since(start),
+        }
+    }
+
+    #[inline]
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        <Result<L, R>>::enumerator_from_direct(result_from_either(value))
+            .map(|inner| EitherEnumerator { inner })
+    }
+
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        match value {
+            Either::Left(l) => L::enumerable_to_index_direct(l),
+            Either::Right(r) => {
+                R::enumerable_to_index_direct(r)?.checked_add(L::ENUMERABLE_SIZE_OPTION_U128?)
+            }
+        }
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> = <Result<L, R>>::ENUMERABLE_SIZE_OPTION;
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = <Result<L, R>>::ENUMERABLE_SIZE_OPTION_U128;
+
+    const IS_INHABITED: bool = <Result<L, R>>::IS_INHABITED;
+}
+
+impl<L: OrderedEnumerable, R: OrderedEnumerable> OrderedEnumerable for Either<L, R> {}
+
+impl<L: ExactSizeEnumerable, R: ExactSizeEnumerable> ExactSizeEnumerable for Either<L, R> {
+    type ExactSizeEnumerator = EitherEnumerator<L, R>;
+}
+
+impl<L: Enumerable, R: Enumerable> SumEnumerable for Either<L, R> {
+    type VariantSizes = [u128; 2];
+
+    const VARIANT_SIZES: Self::VariantSizes = <Result<L, R>>::VARIANT_SIZES;
+}
@@ -0,0 +1,190 @@
+use crate::{
+    size_option_from_u128, Enumerable, OrderedEnumerable, ReversedEnumerator, StepByEnumerator,
+};
+use core::net::Ipv4Addr;
+
+/// The number of possible values of [`Ipv4Addr`].
+const IPV4_ADDR_COUNT: u64 = 1 << 32;
+
+/// `Ipv4AddrEnumerator` is an iterator over possible values of [`Ipv4Addr`].
+///
+/// It yields all addresses from `0.0.0.0` to `255.255.255.255`, in numeric (and thus ascending)
+/// order.
+pub struct Ipv4AddrEnumerator {
+    /// The numeric value of the next address to yield from the front.
+    front: u64,
+    /// One past the numeric value of the next address to yield from the back.
+    back: u64,
+}
+
+impl Ipv4AddrEnumerator {
+    /// Creates a new enumerator, yielding the same values in the same order as
+    /// `<Ipv4Addr as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::since(0)
+    }
+
+    /// Creates a new enumerator yielding addresses from the numeric value `start` on.
+    #[inline]
+    fn since(start: u64) -> Self {
+        Self {
+            front: start.min(IPV4_ADDR_COUNT),
+            back: IPV4_ADDR_COUNT,
+        }
+    }
+
+    /// The number of addresses left to yield.
+    #[inline]
+    fn remaining(&self) -> u64 {
+        self.back - self.front
+    }
+}
+
+impl Default for Ipv4AddrEnumerator {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Ipv4AddrEnumerator {
+    type Item = Ipv4Addr;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let n = u64::try_from(n).unwrap_or(u64::MAX);
+        if n >= self.remaining() {
+            self.front = self.back;
+            return None;
+        }
+
+        // the value is less than `back`, so it fits in `u32`
+        let value = self.front + n;
+        self.front = value + 1;
+        Some(Ipv4Addr::from(value as u32))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        // like `Iterator::count`, it doesn't guard against overflows on 32-bit targets
+        self.remaining() as usize
+    }
+}
+
+impl DoubleEndedIterator for Ipv4AddrEnumerator {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(Ipv4Addr::from(self.back as u32))
+    }
+}
+
+/// This is an implementation of the `Enumerable` trait for [`Ipv4Addr`].
+///
+/// Addresses are enumerated in numeric order, i.e. the order of their `u32` representations,
+/// which is also the order of [`Ord`].
+#[automatically_derived]
+impl Enumerable for Ipv4Addr {
+    type Enumerator = Ipv4AddrEnumerator;
+
+    /// Returns an iterator over all possible values of this type, from `0.0.0.0` to
+    /// `255.255.255.255`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::net::Ipv4Addr;
+    /// use enumerable::Enumerable;
+    ///
+    /// let mut iter = Ipv4Addr::enumerator_from(Ipv4Addr::new(10, 0, 0, 255));
+    /// assert_eq!(iter.next(), Some(Ipv4Addr::new(10, 0, 0, 255)));
+    /// assert_eq!(iter.next(), Some(Ipv4Addr::new(10, 0, 1, 0)));
+    /// ```
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        Ipv4AddrEnumerator::new()
+    }
+
+    #[inline]
+    fn first_value() -> Option<Self> {
+        Some(Ipv4Addr::UNSPECIFIED)
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        Some(Ipv4Addr::BROADCAST)
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        Some(u32::from(self).checked_add(1).map(Ipv4Addr::from))
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        Some(u32::from(self).checked_sub(1).map(Ipv4Addr::from))
+    }
+
+    /// Returns an iterator over all possible values of this type starting from the `start`-th
+    /// one, positioned in O(1) time.
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        Ipv4AddrEnumerator::since(u64::try_from(start).unwrap_or(u64::MAX))
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        u32::try_from(index).ok().map(Ipv4Addr::from)
+    }
+
+    #[inline]
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        u32::try_from(index).ok().map(Ipv4Addr::from)
+    }
+
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        Some(u32::from(*value) as u128)
+    }
+
+    #[inline]
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        Some(Ipv4AddrEnumerator::since(u32::from(value) as u64))
+    }
+
+    #[inline]
+    fn enumerator_reversed() -> ReversedEnumerator<Self> {
+        ReversedEnumerator::from_double_ended()
+    }
+
+    #[inline]
+    fn enumerator_step_by(start: usize, step: usize) -> StepByEnumerator<Self> {
+        StepByEnumerator::by_index(start, step)
+    }
+
+    // `None` on targets where `usize` has 32 bits or fewer
+    const ENUMERABLE_SIZE_OPTION: Option<usize> =
+        size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some(IPV4_ADDR_COUNT as u128);
+}
+
+impl OrderedEnumerable for Ipv4Addr {}
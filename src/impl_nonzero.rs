@@ -0,0 +1,391 @@
+use crate::{
+    size_option_from_u128, Enumerable, ExactSizeEnumerable, OrderedEnumerable, ReversedEnumerator,
+    StepByEnumerator,
+};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use core::ops::RangeInclusive;
+
+/// `NonZeroEnumerator` is an iterator over possible values of the unsigned non-zero integer types,
+/// e.g. [`NonZeroU8`] for `NonZeroEnumerator<u8>`.
+///
+/// It yields all values from `1` to the maximum one, in ascending order.
+pub struct NonZeroEnumerator<T> {
+    inner: RangeInclusive<T>,
+}
+
+/// Macro to implement the `Enumerable` trait for an unsigned non-zero integer type.
+macro_rules! impl_enumerable_for_unsigned_non_zero_type {
+    ($nz:ty, $ty:ty) => {
+        impl NonZeroEnumerator<$ty> {
+            /// Creates a new enumerator, yielding the same values in the same order as
+            #[doc = concat!("`<", stringify!($nz), " as Enumerable>::enumerator()`.")]
+            #[inline]
+            pub fn new() -> Self {
+                Self {
+                    inner: 1..=<$ty>::MAX,
+                }
+            }
+        }
+
+        impl Default for NonZeroEnumerator<$ty> {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl Iterator for NonZeroEnumerator<$ty> {
+            type Item = $nz;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                // the range never yields 0, so `new` never fails
+                self.inner.next().and_then(<$nz>::new)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.inner.nth(n).and_then(<$nz>::new)
+            }
+        }
+
+        impl DoubleEndedIterator for NonZeroEnumerator<$ty> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back().and_then(<$nz>::new)
+            }
+        }
+
+        #[automatically_derived]
+        impl Enumerable for $nz {
+            type Enumerator = NonZeroEnumerator<$ty>;
+
+            /// Returns an iterator over all possible values of this type, from `1` to the maximum
+            /// one.
+            #[inline]
+            fn enumerator() -> Self::Enumerator {
+                NonZeroEnumerator::<$ty>::new()
+            }
+
+            #[inline]
+            fn first_value() -> Option<Self> {
+                Some(<$nz>::MIN)
+            }
+
+            #[inline]
+            fn last_value() -> Option<Self> {
+                Some(<$nz>::MAX)
+            }
+
+            #[inline]
+            fn next_value_direct(self) -> Option<Option<Self>> {
+                Some(self.checked_add(1))
+            }
+
+            #[inline]
+            fn previous_value_direct(self) -> Option<Option<Self>> {
+                Some(<$nz>::new(self.get() - 1))
+            }
+
+            /// Returns an iterator over all possible values of this type starting from the
+            /// `start`-th one, positioned in O(1) time.
+            #[inline]
+            fn enumerator_since(start: usize) -> Self::Enumerator {
+                let mut result = NonZeroEnumerator::<$ty>::new();
+                match Self::ENUMERABLE_SIZE_OPTION {
+                    // an exhausted range
+                    Some(size) if start >= size => {
+                        result.inner = <$ty>::MAX..=<$ty>::MAX;
+                        result.inner.next();
+                    }
+                    // `start` is less than the size, so the addition doesn't overflow
+                    _ => result.inner = (start as $ty + 1)..=<$ty>::MAX,
+                }
+                result
+            }
+
+            #[inline]
+            fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+                match Self::ENUMERABLE_SIZE_OPTION_U128 {
+                    Some(size) if index >= size => None,
+                    // `index` is less than the size, so the addition doesn't overflow
+                    _ => <$nz>::new(index as $ty + 1),
+                }
+            }
+
+            #[inline]
+            fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+                Some((value.get() - 1) as u128)
+            }
+
+            #[inline]
+            fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+                Some(NonZeroEnumerator {
+                    inner: value.get()..=<$ty>::MAX,
+                })
+            }
+
+            #[inline]
+            fn enumerator_reversed() -> ReversedEnumerator<Self> {
+                ReversedEnumerator::from_double_ended()
+            }
+
+            #[inline]
+            fn enumerator_step_by(start: usize, step: usize) -> StepByEnumerator<Self> {
+                StepByEnumerator::by_index(start, step)
+            }
+
+            const ENUMERABLE_SIZE_OPTION: Option<usize> =
+                size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+            const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some(<$ty>::MAX as u128);
+        }
+
+        impl OrderedEnumerable for $nz {}
+    };
+}
+
+impl_enumerable_for_unsigned_non_zero_type!(NonZeroU8, u8);
+impl_enumerable_for_unsigned_non_zero_type!(NonZeroU16, u16);
+impl_enumerable_for_unsigned_non_zero_type!(NonZeroU32, u32);
+impl_enumerable_for_unsigned_non_zero_type!(NonZeroU64, u64);
+impl_enumerable_for_unsigned_non_zero_type!(NonZeroU128, u128);
+impl_enumerable_for_unsigned_non_zero_type!(NonZeroUsize, usize);
+
+// `RangeInclusive` of wider types is not an `ExactSizeIterator`, as the length may overflow
+// `usize` on some platforms.
+impl ExactSizeIterator for NonZeroEnumerator<u8> {}
+
+impl ExactSizeIterator for NonZeroEnumerator<u16> {}
+
+impl ExactSizeEnumerable for NonZeroU8 {
+    type ExactSizeEnumerator = NonZeroEnumerator<u8>;
+}
+
+impl ExactSizeEnumerable for NonZeroU16 {
+    type ExactSizeEnumerator = NonZeroEnumerator<u16>;
+}
+
+/// `NonZeroSignedEnumerator` is an iterator over possible values of the signed non-zero integer
+/// types, e.g. [`NonZeroI8`] for `NonZeroSignedEnumerator<i8>`.
+///
+/// It yields all values from the minimum one to `-1`, then from `1` to the maximum one, i.e. all
+/// values in ascending order skipping `0`.
+pub struct NonZeroSignedEnumerator<T> {
+    negative: RangeInclusive<T>,
+    positive: RangeInclusive<T>,
+}
+
+/// Macro to implement the `Enumerable` trait for a signed non-zero integer type.
+macro_rules! impl_enumerable_for_signed_non_zero_type {
+    ($nz:ty, $ty:ty) => {
+        impl NonZeroSignedEnumerator<$ty> {
+            /// Creates a new enumerator, yielding the same values in the same order as
+            #[doc = concat!("`<", stringify!($nz), " as Enumerable>::enumerator()`.")]
+            #[inline]
+            pub fn new() -> Self {
+                Self::starting_at(<$ty>::MIN)
+            }
+
+            /// Returns an exhausted range.
+            #[inline]
+            fn exhausted() -> RangeInclusive<$ty> {
+                let mut range = 0..=0;
+                range.next();
+                range
+            }
+
+            /// Creates a new enumerator yielding values from the non-zero `value`.
+            #[inline]
+            fn starting_at(value: $ty) -> Self {
+                if value < 0 {
+                    Self {
+                        negative: value..=-1,
+                        positive: 1..=<$ty>::MAX,
+                    }
+                } else {
+                    Self {
+                        negative: Self::exhausted(),
+                        positive: value..=<$ty>::MAX,
+                    }
+                }
+            }
+        }
+
+        impl Default for NonZeroSignedEnumerator<$ty> {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl Iterator for NonZeroSignedEnumerator<$ty> {
+            type Item = $nz;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                // neither range yields 0, so `new` never fails
+                self.negative
+                    .next()
+                    .or_else(|| self.positive.next())
+                    .and_then(<$nz>::new)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let (negative_lower, negative_upper) = self.negative.size_hint();
+                let (positive_lower, positive_upper) = self.positive.size_hint();
+                let upper = match (negative_upper, positive_upper) {
+                    (Some(negative), Some(positive)) => negative.checked_add(positive),
+                    _ => None,
+                };
+
+                (negative_lower.saturating_add(positive_lower), upper)
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                match self.negative.size_hint() {
+                    // skipping all negative values
+                    (_, Some(negative)) if n >= negative => {
+                        self.negative = Self::exhausted();
+                        self.positive.nth(n - negative).and_then(<$nz>::new)
+                    }
+                    _ => self.negative.nth(n).and_then(<$nz>::new),
+                }
+            }
+        }
+
+        impl DoubleEndedIterator for NonZeroSignedEnumerator<$ty> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.positive
+                    .next_back()
+                    .or_else(|| self.negative.next_back())
+                    .and_then(<$nz>::new)
+            }
+        }
+
+        #[automatically_derived]
+        impl Enumerable for $nz {
+            type Enumerator = NonZeroSignedEnumerator<$ty>;
+
+            /// Returns an iterator over all possible values of this type, from the minimum one to
+            /// `-1`, then from `1` to the maximum one.
+            #[inline]
+            fn enumerator() -> Self::Enumerator {
+                NonZeroSignedEnumerator::<$ty>::new()
+            }
+
+            #[inline]
+            fn first_value() -> Option<Self> {
+                Some(<$nz>::MIN)
+            }
+
+            #[inline]
+            fn last_value() -> Option<Self> {
+                Some(<$nz>::MAX)
+            }
+
+            #[inline]
+            fn next_value_direct(self) -> Option<Option<Self>> {
+                Some(match self.get() {
+                    -1 => <$nz>::new(1),
+                    value => value.checked_add(1).and_then(<$nz>::new),
+                })
+            }
+
+            #[inline]
+            fn previous_value_direct(self) -> Option<Option<Self>> {
+                Some(match self.get() {
+                    1 => <$nz>::new(-1),
+                    value => value.checked_sub(1).and_then(<$nz>::new),
+                })
+            }
+
+            /// Returns an iterator over all possible values of this type starting from the
+            /// `start`-th one, positioned in O(1) time.
+            #[inline]
+            fn enumerator_since(start: usize) -> Self::Enumerator {
+                match Self::enumerable_from_index_u128(start as u128) {
+                    Some(value) => NonZeroSignedEnumerator::<$ty>::starting_at(value.get()),
+                    // an exhausted enumerator
+                    None => NonZeroSignedEnumerator {
+                        negative: NonZeroSignedEnumerator::<$ty>::exhausted(),
+                        positive: NonZeroSignedEnumerator::<$ty>::exhausted(),
+                    },
+                }
+            }
+
+            #[inline]
+            fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+                match Self::ENUMERABLE_SIZE_OPTION_U128 {
+                    Some(size) if index >= size => None,
+                    // `index` is less than the size, so the wrapping addition is exact, and values
+                    // from `0` on are shifted by one to skip it
+                    _ => match <$ty>::MIN.wrapping_add(index as $ty) {
+                        value if value < 0 => <$nz>::new(value),
+                        value => <$nz>::new(value + 1),
+                    },
+                }
+            }
+
+            #[inline]
+            fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+                let index = value.get().abs_diff(<$ty>::MIN);
+                Some((if value.get() < 0 { index } else { index - 1 }) as u128)
+            }
+
+            #[inline]
+            fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+                Some(NonZeroSignedEnumerator::<$ty>::starting_at(value.get()))
+            }
+
+            #[inline]
+            fn enumerator_reversed() -> ReversedEnumerator<Self> {
+                ReversedEnumerator::from_double_ended()
+            }
+
+            #[inline]
+            fn enumerator_step_by(start: usize, step: usize) -> StepByEnumerator<Self> {
+                StepByEnumerator::by_index(start, step)
+            }
+
+            const ENUMERABLE_SIZE_OPTION: Option<usize> =
+                size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+            // one less than the number of values of the underlying type, which always fits
+            const ENUMERABLE_SIZE_OPTION_U128: Option<u128> =
+                Some(<$ty>::MAX.abs_diff(<$ty>::MIN) as u128);
+        }
+
+        impl OrderedEnumerable for $nz {}
+    };
+}
+
+impl_enumerable_for_signed_non_zero_type!(NonZeroI8, i8);
+impl_enumerable_for_signed_non_zero_type!(NonZeroI16, i16);
+impl_enumerable_for_signed_non_zero_type!(NonZeroI32, i32);
+impl_enumerable_for_signed_non_zero_type!(NonZeroI64, i64);
+impl_enumerable_for_signed_non_zero_type!(NonZeroI128, i128);
+impl_enumerable_for_signed_non_zero_type!(NonZeroIsize, isize);
+
+impl ExactSizeIterator for NonZeroSignedEnumerator<i8> {}
+
+impl ExactSizeIterator for NonZeroSignedEnumerator<i16> {}
+
+impl ExactSizeEnumerable for NonZeroI8 {
+    type ExactSizeEnumerator = NonZeroSignedEnumerator<i8>;
+}
+
+impl ExactSizeEnumerable for NonZeroI16 {
+    type ExactSizeEnumerator = NonZeroSignedEnumerator<i16>;
+}
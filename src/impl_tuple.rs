@@ -1,4 +1,6 @@
-use crate::Enumerable;
+use crate::{
+    ConstEnumerable, Enumerable, ExactSizeEnumerable, OrderedEnumerable, ProductEnumerable,
+};
 
 /// This is an implementation of the `Enumerable` trait for `()`.
 impl Enumerable for () {
@@ -13,15 +15,56 @@ impl Enumerable for () {
     /// assert_eq!(iter.next(), Some(()));
     /// assert_eq!(iter.next(), None);
     /// ```
+    #[inline]
     fn enumerator() -> Self::Enumerator {
         core::iter::once(())
     }
 
+    #[inline]
+    fn last_value() -> Option<Self> {
+        Some(())
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        Some(None)
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        Some(None)
+    }
+
+    #[inline]
+    fn enumerator_from_direct(_value: Self) -> Option<Self::Enumerator> {
+        Some(core::iter::once(()))
+    }
+
+    #[inline]
+    fn enumerable_to_index_direct(_value: &Self) -> Option<u128> {
+        Some(0)
+    }
+
     const ENUMERABLE_SIZE_OPTION: Option<usize> = Some(1);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some(1);
 }
 
-/// Enumerator for `(A,)`.
-#[doc(hidden)]
+impl OrderedEnumerable for () {}
+
+impl ExactSizeEnumerable for () {
+    type ExactSizeEnumerator = core::iter::Once<()>;
+}
+
+impl ConstEnumerable for () {
+    const VALUES: &'static [Self] = &[()];
+}
+
+/// `Tuple1Enumerator` is an iterator over possible values of `(A,)`, i.e.
+/// `<(A,) as Enumerable>::Enumerator`.
+///
+/// It yields `(item,)` for each possible value of `A`. It implements `Clone` and `Debug` if the
+/// enumerator of `A` does.
 pub struct Tuple1Enumerator<A>
 where
     A: Enumerable,
@@ -29,12 +72,73 @@ where
     a_enumerator: A::Enumerator,
 }
 
+impl<A: Enumerable> Tuple1Enumerator<A> {
+    /// Creates a new enumerator, yielding the same values in the same order as
+    /// `<(A,) as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            a_enumerator: A::enumerator(),
+        }
+    }
+}
+
+impl<A> Clone for Tuple1Enumerator<A>
+where
+    A: Enumerable,
+    A::Enumerator: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            a_enumerator: self.a_enumerator.clone(),
+        }
+    }
+}
+
+impl<A> core::fmt::Debug for Tuple1Enumerator<A>
+where
+    A: Enumerable,
+    A::Enumerator: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Tuple1Enumerator")
+            .field("a_enumerator", &self.a_enumerator)
+            .finish()
+    }
+}
+
+impl<A: Enumerable> Default for Tuple1Enumerator<A> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<A: Enumerable> Iterator for Tuple1Enumerator<A> {
     type Item = (A,);
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         self.a_enumerator.next().map(|a| (a,))
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.a_enumerator.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.a_enumerator.nth(n).map(|a| (a,))
+    }
+}
+
+impl<A> ExactSizeIterator for Tuple1Enumerator<A>
+where
+    A: Enumerable,
+    A::Enumerator: ExactSizeIterator,
+{
 }
 
 impl<A> Enumerable for (A,)
@@ -43,14 +147,78 @@ where
 {
     type Enumerator = Tuple1Enumerator<A>;
 
+    #[inline]
     fn enumerator() -> Self::Enumerator {
-        Tuple1Enumerator {
-            a_enumerator: A::enumerator(),
-        }
+        Tuple1Enumerator::new()
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        A::last_value().map(|a| (a,))
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        self.0.next_value_direct().map(|next| next.map(|a| (a,)))
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        self.0
+            .previous_value_direct()
+            .map(|previous| previous.map(|a| (a,)))
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        A::enumerable_from_index(index).map(|a| (a,))
+    }
+
+    #[inline]
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        A::enumerable_from_index_u128(index).map(|a| (a,))
+    }
+
+    #[inline]
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        A::enumerator_from_direct(value.0).map(|a_enumerator| Tuple1Enumerator { a_enumerator })
+    }
+
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        A::enumerable_to_index_direct(&value.0)
     }
 
     const ENUMERABLE_SIZE_OPTION: Option<usize> = A::ENUMERABLE_SIZE_OPTION;
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = A::ENUMERABLE_SIZE_OPTION_U128;
+
+    const IS_INHABITED: bool = A::IS_INHABITED;
+}
+
+impl<A: ExactSizeEnumerable> ExactSizeEnumerable for (A,) {
+    type ExactSizeEnumerator = Tuple1Enumerator<A>;
+}
+
+impl<A: Enumerable> ProductEnumerable for (A,) {
+    type FieldIndices = [u128; 1];
+
+    const FIELD_SIZES: Self::FieldIndices = [match A::ENUMERABLE_SIZE_OPTION_U128 {
+        Some(size) => size,
+        None => {
+            panic!("the number of values of a field of `(A,)` is unknown or exceeds `u128::MAX`")
+        }
+    }];
+
+    #[inline]
+    fn from_field_indices(indices: Self::FieldIndices) -> Option<Self> {
+        A::enumerable_from_index_u128(indices[0]).map(|a| (a,))
+    }
 }
 
 // impl Enumerable for tuples of size 2..=16
 enumerable_derive::__impl_enumerable_for_tuples!(2, 16);
+
+// impl Enumerable for tuples of size 17..=24, which are opt-in as they take a while to compile
+#[cfg(feature = "large-tuples")]
+enumerable_derive::__impl_enumerable_for_tuples!(17, 24);
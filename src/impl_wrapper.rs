@@ -0,0 +1,363 @@
+use crate::{Enumerable, ExactSizeEnumerable, OrderedEnumerable, ReversedEnumerator};
+use core::cmp::Reverse;
+use core::mem::ManuallyDrop;
+use core::num::{Saturating, Wrapping};
+
+/// Macro to implement the `Enumerable` trait for a transparent wrapper type, enumerating values in
+/// the same order as the wrapped type. `$wrap` and `$unwrap` convert values to and from the
+/// wrapper, and `$unwrap_ref` borrows the wrapped value of `$value`.
+macro_rules! impl_enumerable_for_wrapper_type {
+    ($wrapper:ident, $enumerator:ident, $wrap:expr, $unwrap:expr, |$value:ident| $unwrap_ref:expr) => {
+        #[doc = concat!("`", stringify!($enumerator), "` is an iterator over possible values of `", stringify!($wrapper), "<T>`.")]
+        ///
+        /// It yields the values of `T` in their enumeration order, each wrapped.
+        pub struct $enumerator<T: Enumerable> {
+            inner: T::Enumerator,
+        }
+
+        impl<T: Enumerable> $enumerator<T> {
+            /// Creates a new enumerator, yielding the same values in the same order as
+            #[doc = concat!("`<", stringify!($wrapper), "<T> as Enumerable>::enumerator()`.")]
+            #[inline]
+            pub fn new() -> Self {
+                Self {
+                    inner: T::enumerator(),
+                }
+            }
+        }
+
+        impl<T: Enumerable> Default for $enumerator<T> {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<T: Enumerable> Iterator for $enumerator<T> {
+            type Item = $wrapper<T>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next().map($wrap)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.inner.nth(n).map($wrap)
+            }
+        }
+
+        impl<T> DoubleEndedIterator for $enumerator<T>
+        where
+            T: Enumerable,
+            T::Enumerator: DoubleEndedIterator,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back().map($wrap)
+            }
+        }
+
+        impl<T> ExactSizeIterator for $enumerator<T>
+        where
+            T: Enumerable,
+            T::Enumerator: ExactSizeIterator,
+        {
+        }
+
+        #[doc = concat!("This is an implementation of the `Enumerable` trait for `", stringify!($wrapper), "<T>` where `T` is `Enumerable`.")]
+        ///
+        /// Values are enumerated in the same order as `T`, and all sizes and lookups are forwarded
+        /// to `T`.
+        #[automatically_derived]
+        impl<T: Enumerable> Enumerable for $wrapper<T> {
+            type Enumerator = $enumerator<T>;
+
+            #[inline]
+            fn enumerator() -> Self::Enumerator {
+                $enumerator::new()
+            }
+
+            #[inline]
+            fn first_value() -> Option<Self> {
+                T::first_value().map($wrap)
+            }
+
+            #[inline]
+            fn last_value() -> Option<Self> {
+                T::last_value().map($wrap)
+            }
+
+            #[inline]
+            fn next_value_direct(self) -> Option<Option<Self>> {
+                ($unwrap)(self)
+                    .next_value_direct()
+                    .map(|next| next.map($wrap))
+            }
+
+            #[inline]
+            fn previous_value_direct(self) -> Option<Option<Self>> {
+                ($unwrap)(self)
+                    .previous_value_direct()
+                    .map(|previous| previous.map($wrap))
+            }
+
+            #[inline]
+            fn enumerable_from_index(index: usize) -> Option<Self> {
+                T::enumerable_from_index(index).map($wrap)
+            }
+
+            #[inline]
+            fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+                T::enumerable_from_index_u128(index).map($wrap)
+            }
+
+            #[inline]
+            fn enumerator_since(start: usize) -> Self::Enumerator {
+                $enumerator {
+                    inner: T::enumerator_since(start),
+                }
+            }
+
+            #[inline]
+            fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+                T::enumerator_from_direct(($unwrap)(value)).map(|inner| $enumerator { inner })
+            }
+
+            /// Forwards to `T::enumerable_to_index_direct`. `Self: PartialEq` doesn't imply
+            /// `T: PartialEq`, so `T::enumerable_to_index` can't be called, and values without
+            /// direct indices are found by stepping through the enumerator of `T` instead.
+            #[inline]
+            fn enumerable_to_index($value: &Self) -> Option<usize>
+            where
+                Self: PartialEq,
+            {
+                match T::enumerable_to_index_direct($unwrap_ref) {
+                    Some(index) => usize::try_from(index).ok(),
+                    None => T::enumerator().position(|inner| ($wrap)(inner) == *$value),
+                }
+            }
+
+            #[inline]
+            fn enumerable_to_index_direct($value: &Self) -> Option<u128> {
+                T::enumerable_to_index_direct($unwrap_ref)
+            }
+
+            #[inline]
+            fn enumerator_reversed() -> ReversedEnumerator<Self> {
+                T::enumerator_reversed().into_wrapper()
+            }
+
+            const ENUMERABLE_SIZE_OPTION: Option<usize> = T::ENUMERABLE_SIZE_OPTION;
+
+            const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = T::ENUMERABLE_SIZE_OPTION_U128;
+
+            const IS_INHABITED: bool = T::IS_INHABITED;
+        }
+
+        impl<T: ExactSizeEnumerable> ExactSizeEnumerable for $wrapper<T> {
+            type ExactSizeEnumerator = $enumerator<T>;
+        }
+
+        impl<T: OrderedEnumerable> OrderedEnumerable for $wrapper<T> {}
+    };
+}
+
+impl_enumerable_for_wrapper_type!(
+    ManuallyDrop,
+    ManuallyDropEnumerator,
+    ManuallyDrop::new,
+    ManuallyDrop::into_inner,
+    |value| &**value
+);
+impl_enumerable_for_wrapper_type!(
+    Saturating,
+    SaturatingEnumerator,
+    Saturating,
+    |value: Saturating<T>| value.0,
+    |value| &value.0
+);
+impl_enumerable_for_wrapper_type!(
+    Wrapping,
+    WrappingEnumerator,
+    Wrapping,
+    |value: Wrapping<T>| value.0,
+    |value| &value.0
+);
+
+/// `ReverseEnumerator` is an iterator over possible values of [`Reverse<T>`].
+///
+/// It yields `Reverse(value)` for each value of `T` in the reverse enumeration order of `T`, as
+/// [`Enumerable::enumerator_reversed`] does.
+pub struct ReverseEnumerator<T: Enumerable> {
+    inner: ReversedEnumerator<T>,
+}
+
+impl<T: Enumerable> ReverseEnumerator<T> {
+    /// Creates a new enumerator, yielding the same values in the same order as
+    /// `<Reverse<T> as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            inner: T::enumerator_reversed(),
+        }
+    }
+}
+
+impl<T: Enumerable> Default for ReverseEnumerator<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Enumerable> Iterator for ReverseEnumerator<T> {
+    type Item = Reverse<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(Reverse)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(Reverse)
+    }
+}
+
+impl<T: ExactSizeEnumerable> ExactSizeIterator for ReverseEnumerator<T> {}
+
+/// This is an implementation of the `Enumerable` trait for `Reverse<T>` where `T` is `Enumerable`.
+///
+/// As `Reverse` inverts comparisons, values are enumerated in the reverse enumeration order of
+/// `T`, so that the enumeration order of `Reverse<T>` is ascending whenever that of `T` is. The
+/// enumerator is [`T::enumerator_reversed`](Enumerable::enumerator_reversed), so it's as efficient
+/// as that. Enumerators are positioned by the `u128` indices of `T` if the size of `T` is known.
+#[automatically_derived]
+impl<T: Enumerable> Enumerable for Reverse<T> {
+    type Enumerator = ReverseEnumerator<T>;
+
+    /// This method returns an iterator over all possible values of `Reverse<T>`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use core::cmp::Reverse;
+    /// use enumerable::Enumerable;
+    ///
+    /// let mut iter = <Reverse<bool>>::enumerator();
+    /// assert_eq!(iter.next(), Some(Reverse(true)));
+    /// assert_eq!(iter.next(), Some(Reverse(false)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        ReverseEnumerator::new()
+    }
+
+    #[inline]
+    fn first_value() -> Option<Self> {
+        T::last_value().map(Reverse)
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        T::first_value().map(Reverse)
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        self.0.previous_value_direct().map(|next| next.map(Reverse))
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        self.0
+            .next_value_direct()
+            .map(|previous| previous.map(Reverse))
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        Self::enumerable_from_index_u128(index as u128)
+    }
+
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        match T::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) if index < size => T::enumerable_from_index_u128(size - 1 - index),
+            Some(_) => None,
+            // the last value can't be located without the size, so the enumerator is stepped
+            None => usize::try_from(index)
+                .ok()
+                .and_then(|index| T::enumerator_reversed().nth(index)),
+        }
+        .map(Reverse)
+    }
+
+    /// Returns an iterator over all possible values of `Reverse<T>` starting from the `start`-th
+    /// one, as efficient as `T::enumerable_from_index_u128` if the size of `T` is known.
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        match T::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => ReverseEnumerator {
+                inner: ReversedEnumerator::with_remaining(size.saturating_sub(start as u128)),
+            },
+            None => {
+                let mut enumerator = Self::enumerator();
+                if start > 0 {
+                    enumerator.nth(start - 1);
+                }
+                enumerator
+            }
+        }
+    }
+
+    /// Positions the enumerator by the index of `value` in `T`, which is found by the exact size
+    /// hint of the enumerator of `T` from `value`. It's `None` if the size of `T` is unknown, or
+    /// more than `usize::MAX` values follow `value` in `T`.
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        let size = T::ENUMERABLE_SIZE_OPTION_U128?;
+        let mut following = T::enumerator_from_direct(value.0)?;
+        following.next()?;
+        let (lower, upper) = following.size_hint();
+        if upper != Some(lower) {
+            return None;
+        }
+
+        // `value` and all values before it in `T` are left
+        Some(ReverseEnumerator {
+            inner: ReversedEnumerator::with_remaining(size.saturating_sub(lower as u128)),
+        })
+    }
+
+    /// Computes the index from the index of the wrapped value, which requires the number of values
+    /// of `T` to be known.
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        let size = T::ENUMERABLE_SIZE_OPTION_U128?;
+        // the index of the wrapped value is less than `size`
+        Some(size - 1 - T::enumerable_to_index_direct(&value.0)?)
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> = T::ENUMERABLE_SIZE_OPTION;
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = T::ENUMERABLE_SIZE_OPTION_U128;
+
+    const IS_INHABITED: bool = T::IS_INHABITED;
+}
+
+impl<T: ExactSizeEnumerable> ExactSizeEnumerable for Reverse<T> {
+    type ExactSizeEnumerator = ReverseEnumerator<T>;
+}
+
+impl<T: OrderedEnumerable> OrderedEnumerable for Reverse<T> {}
@@ -0,0 +1,92 @@
+//! Ordering of values by their indices in the enumeration.
+
+use crate::Enumerable;
+use core::cmp::Ordering;
+
+/// A transparent wrapper comparing values by their indices in enumeration order, instead of by
+/// their own [`Ord`] implementations (if any).
+///
+/// Indices are computed by [`Enumerable::enumerable_to_index`], in O(1) time or time linear in the
+/// number of fields for built-in and derived types, and by stepping through the enumerator for
+/// other types. `Indexed` values can be made from indices by [`Indexed::from_index`]. When built
+/// on nightly with `--cfg enumerable_step`, `Indexed<T>` implements [`core::iter::Step`], so ranges
+/// like `Indexed(Enum3::A)..=Indexed(Enum3::C)` are iterators.
+///
+/// Comparisons panic if the index of a value exceeds `usize::MAX`. For types whose indices are
+/// computed by stepping through the enumerator, reaching such an index takes effectively forever
+/// instead.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::{Enumerable, Indexed};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Eq, Enumerable)]
+/// enum Level {
+///     Low,
+///     Medium,
+///     High,
+/// }
+///
+/// assert!(Indexed(Level::Low) < Indexed(Level::High));
+/// assert_eq!(Indexed(Level::Medium).index(), 1);
+/// assert_eq!(Indexed::from_index(2), Some(Indexed(Level::High)));
+/// assert!(Indexed('\u{D7FF}') < Indexed('\u{E000}'));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Indexed<T>(pub T);
+
+impl<T: Enumerable + PartialEq> Indexed<T> {
+    /// Returns the index of the wrapped value in enumeration order.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the index exceeds `usize::MAX`.
+    #[inline]
+    pub fn index(&self) -> usize {
+        T::enumerable_to_index(&self.0).expect("index of the value exceeds `usize::MAX`")
+    }
+
+    /// Returns the value at `index` in enumeration order wrapped, or `None` if `index` is out of
+    /// range.
+    #[inline]
+    pub fn from_index(index: usize) -> Option<Self> {
+        T::enumerable_from_index(index).map(Self)
+    }
+}
+
+impl<T: Enumerable + Eq> PartialOrd for Indexed<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Enumerable + Eq> Ord for Indexed<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index().cmp(&other.index())
+    }
+}
+
+#[cfg(enumerable_step)]
+impl<T: Enumerable + Eq> core::iter::Step for Indexed<T> {
+    #[inline]
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        match end.index().checked_sub(start.index()) {
+            Some(steps) => (steps, Some(steps)),
+            None => (0, None),
+        }
+    }
+
+    #[inline]
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        start.index().checked_add(count).and_then(Self::from_index)
+    }
+
+    #[inline]
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        start.index().checked_sub(count).and_then(Self::from_index)
+    }
+}
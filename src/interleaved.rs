@@ -0,0 +1,136 @@
+//! Interleaved enumeration of sum types, advancing all variants fairly.
+
+use crate::Enumerable;
+
+/// A trait for sum types, i.e. `Option`, `Result` and enums, whose values are grouped by variants,
+/// allowing them to be enumerated in an interleaved order by
+/// [`enumerator_interleaved`](SumEnumerable::enumerator_interleaved).
+///
+/// Values of each variant must be contiguous in enumeration order, with variants in the order of
+/// [`VARIANT_SIZES`](SumEnumerable::VARIANT_SIZES). It's implemented for `Option` and `Result`,
+/// and derived for all enums.
+pub trait SumEnumerable: Enumerable {
+    /// The numbers of values of the variants, `[u128; N]` for `N` variants.
+    type VariantSizes: Copy + AsRef<[u128]> + AsMut<[u128]>;
+
+    /// The number of possible values of each variant, in enumeration order.
+    ///
+    /// Evaluating it fails to compile if the number of values of any variant is unknown or exceeds
+    /// `u128::MAX`.
+    const VARIANT_SIZES: Self::VariantSizes;
+
+    /// Returns an iterator over all possible values of the implementing type, taking one value
+    /// from each variant in turn.
+    ///
+    /// Enumeration order exhausts a variant before moving to the next one, so the first values
+    /// may all come from the same variant. Interleaved order instead yields the first value of each
+    /// inhabited variant, then the second value of each variant with at least two values, and so
+    /// on. Values of each variant stay in their enumeration order, exhausted variants are skipped,
+    /// and every value is still yielded exactly once.
+    ///
+    /// Values are looked up by [`enumerable_from_index_u128`](Enumerable::enumerable_from_index_u128).
+    /// Like [`VARIANT_SIZES`](SumEnumerable::VARIANT_SIZES), calling it fails to compile if the
+    /// number of values of any variant is unknown.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the total number of values exceeds `u128::MAX`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::SumEnumerable;
+    ///
+    /// let mut iter = Result::<u8, bool>::enumerator_interleaved();
+    /// assert_eq!(
+    ///     iter.by_ref().take(5).collect::<Vec<_>>(),
+    ///     vec![Ok(0), Err(false), Ok(1), Err(true), Ok(2)]
+    /// );
+    /// assert_eq!(iter.count(), 256 + 2 - 5);
+    /// ```
+    fn enumerator_interleaved() -> InterleavedEnumerator<Self> {
+        InterleavedEnumerator::new()
+    }
+}
+
+/// The iterator returned by [`SumEnumerable::enumerator_interleaved`].
+pub struct InterleavedEnumerator<T: SumEnumerable> {
+    /// The number of possible values of each variant.
+    sizes: T::VariantSizes,
+    /// The index of the first value of each variant.
+    offsets: T::VariantSizes,
+    /// The index of the next value to yield within its variant.
+    round: u128,
+    /// The variant to yield from next in the current round.
+    variant: usize,
+    /// The number of values left to yield.
+    remaining: u128,
+}
+
+impl<T: SumEnumerable> InterleavedEnumerator<T> {
+    /// Creates a new `InterleavedEnumerator`, yielding the same values in the same order as
+    /// `T::enumerator_interleaved()`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the total number of values exceeds `u128::MAX`.
+    #[inline]
+    pub fn new() -> Self {
+        let sizes = T::VARIANT_SIZES;
+        let mut offsets = sizes;
+        let mut total = 0u128;
+        for (offset, &size) in offsets.as_mut().iter_mut().zip(sizes.as_ref()) {
+            *offset = total;
+            total = total
+                .checked_add(size)
+                .expect("the number of values exceeds `u128::MAX`");
+        }
+
+        Self {
+            sizes,
+            offsets,
+            round: 0,
+            variant: 0,
+            remaining: total,
+        }
+    }
+}
+
+impl<T: SumEnumerable> Default for InterleavedEnumerator<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: SumEnumerable> Iterator for InterleavedEnumerator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            if self.variant >= self.sizes.as_ref().len() {
+                self.variant = 0;
+                self.round += 1;
+            }
+
+            let variant = self.variant;
+            self.variant += 1;
+
+            // exhausted variants are skipped
+            if self.round < self.sizes.as_ref()[variant] {
+                self.remaining -= 1;
+                return T::enumerable_from_index_u128(self.offsets.as_ref()[variant] + self.round);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
@@ -1,4 +1,12 @@
 #![doc = include_str!("./CRATE_DOC.md")]
+#![cfg_attr(enumerable_step, feature(step_trait))]
+#![cfg_attr(enumerable_never_type, feature(never_type))]
+
+extern crate alloc;
+
+// Lets code generated by `#[derive(Enumerable)]` refer to items of this crate by
+// `::enumerable::...` in this crate itself.
+extern crate self as enumerable;
 
 /// `Enumerable` is a trait for types that can have their possible values enumerated.
 ///
@@ -20,24 +28,90 @@
 /// - `bool`: Yields `false` and then `true`.
 /// - Numeric types: Yields all possible values of the type from the minimum to the maximum one.
 /// - [`Option`]: Yields `None` and then `Some(item)` for each possible value of `T`.
+/// - [`Poll`](core::task::Poll): Yields `Pending` and then `Ready(item)` for each possible value of
+///   `T`, like `Option`.
 /// - [`Result`]: Yields `Ok(item)` for each possible value of `T` and then `Err(error)` for each
 ///   possible value of `E`.
+/// - [`Either<L, R>`](https://docs.rs/either/latest/either/enum.Either.html), with the `either`
+///   feature: Yields `Left(item)` for each possible value of `L` and then `Right(item)` for each
+///   possible value of `R`, like `Result`.
+/// - [`ControlFlow<B, C>`](core::ops::ControlFlow): Yields `Continue(c)` for each possible value of
+///   `C` and then `Break(b)` for each possible value of `B`.
+/// - [`Bound<T>`](core::ops::Bound): Yields `Included(item)` for each possible value of `T`, then
+///   `Excluded(item)` for each possible value of `T`, and then `Unbounded`.
 /// - `char`: Yields all possible Unicode scalar values, i.e. all code points ranging from `U+0000`
 ///   to `U+10FFFF`, excluding the surrogate code points (`U+D800` to `U+DFFF`), from the lowest to
 ///   the highest one.
+/// - [`AsciiChar`]: Yields the 128 ASCII characters from `U+0000` to `U+007F`.
 /// - Tuples: Yields all possible values of the tuple with 1 to 16 elements, in a lexicographic
 ///   ordering (as [`core::cmp::Ord`] does), provided that all elements implement `Enumerable`.
+///   Tuples with 17 to 24 elements are also supported with the `large-tuples` feature.
 /// - `()`: Yields the unit value `()`.
+/// - [`PhantomData<T>`](core::marker::PhantomData): Yields `PhantomData`, for any `T`.
+/// - Other unit-like types, i.e. [`PhantomPinned`](core::marker::PhantomPinned),
+///   [`fmt::Error`](core::fmt::Error) and [`RangeFull`](core::ops::RangeFull): Yields their only
+///   value.
+/// - [`core::cmp::Ordering`]: Yields `Less`, `Equal` and then `Greater`.
+/// - [`core::sync::atomic::Ordering`]: Yields `Relaxed`, `Release`, `Acquire`, `AcqRel` and then
+///   `SeqCst`.
+/// - [`core::num::FpCategory`]: Yields `Nan`, `Infinite`, `Zero`, `Subnormal` and then `Normal`.
+/// - [`core::fmt::Alignment`]: Yields `Left`, `Right` and then `Center`.
+/// - [`core::convert::Infallible`]: Yields nothing, so e.g. `Result<T, Infallible>` has the same
+///   values as `T`.
+/// - Unsigned non-zero integer types (e.g. [`NonZeroU8`](core::num::NonZeroU8)): Yields all
+///   values from `1` to the maximum one.
+/// - Signed non-zero integer types (e.g. [`NonZeroI8`](core::num::NonZeroI8)): Yields all values
+///   from the minimum one to `-1`, and then from `1` to the maximum one.
+/// - [`Ipv4Addr`](core::net::Ipv4Addr): Yields all addresses from `0.0.0.0` to `255.255.255.255`,
+///   in numeric order.
+/// - [`TotalF32`] and [`TotalF64`], wrappers of floating-point numbers ordered by `total_cmp`:
+///   Yields all bit patterns in the total order, from negative `NaN`s to positive ones.
+/// - [`Bits<N>`](Bits): Yields all patterns of `N` bits in numeric order.
+/// - Arrays `[T; N]`: Yields all possible values of the array in a lexicographic ordering like
+///   tuples, provided that `T` implements `Enumerable`. `[T; 0]` has exactly one value.
+/// - [`RangedU8<MIN, MAX>`](RangedU8) and the other bounded integer types (e.g. [`RangedI32`]):
+///   Yields all values from `MIN` to `MAX`.
+/// - [`RangedChar<MIN, MAX>`](RangedChar): Yields all Unicode scalar values from `MIN` to `MAX`,
+///   skipping the surrogate code points like `char`.
+/// - [`Wrapping<T>`](core::num::Wrapping), [`Saturating<T>`](core::num::Saturating) and
+///   [`ManuallyDrop<T>`](core::mem::ManuallyDrop): Yields all possible values of `T` wrapped, in
+///   the same order as `T`.
+/// - [`Reverse<T>`](core::cmp::Reverse): Yields all possible values of `T` wrapped, in the reverse
+///   order of `T`, so that the order is ascending if that of `T` is.
 ///
 /// ## Derivable
 ///
 /// This trait can be derived using `#[derive(Enumerable)]` on structs and enums, if
 /// - they have no fields, or
-/// - all of their fields implement `Enumerable`.
+/// - all of their fields implement `Enumerable`, except the skipped ones (see "Skipping Variants
+///   and Fields" below).
+///
+/// If the type has generic parameters, all type parameters used in fields must implement
+/// [`Clone`]. Type parameters used only in `PhantomData` are not bounded, so they can be `?Sized`
+/// or not `Clone`, as long as the type itself implements `Clone`, e.g. by hand.
+///
+/// Const and lifetime parameters are carried over to the generated enumerator as they are, without
+/// any bounds. Const parameters can be used in array lengths or only as markers. Lifetimes can only
+/// be markers, e.g. in `PhantomData<&'a T>`, as fields of reference types can't be enumerated:
+///
+/// ```
+/// use enumerable::Enumerable;
 ///
-/// If the type has generic parameters, they must also meet the following requirements:
-/// - there are only type parameters, i.e. no lifetime or const parameters, and
-/// - all type parameters implement [`Copy`].
+/// #[derive(Clone, Debug, PartialEq, Enumerable)]
+/// struct Row<const W: usize> {
+///     cells: [bool; W],
+/// }
+///
+/// assert_eq!(Row::<3>::enumerator().count(), 8);
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Enumerable)]
+/// struct Tagged<'a, T> {
+///     value: bool,
+///     marker: core::marker::PhantomData<&'a T>,
+/// }
+///
+/// assert_eq!(Tagged::<u8>::enumerator().count(), 2);
+/// ```
 ///
 /// See "Guarantees and Limitations" below for more information.
 ///
@@ -58,21 +132,196 @@
 ///
 /// in these cases, the custom enumerator name will be ignored.
 ///
+/// ### Enumerating Variants by Discriminant
+///
+/// `#[enumerable(sort_by_discriminant)]` makes a fieldless enum enumerate its variants in the
+/// ascending order of their discriminants, instead of the declaration order. Explicit
+/// discriminants must be integer literals (possibly negative), implicit ones are evaluated as
+/// Rust does.
+///
+/// ```
+/// use enumerable::Enumerable;
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+/// #[enumerable(sort_by_discriminant)]
+/// #[repr(u8)]
+/// enum Opcode {
+///     Halt = 0xFF,
+///     Load = 0x10,
+///     Store, // 0x11
+///     Nop = 0x00,
+/// }
+///
+/// assert_eq!(
+///     Opcode::enumerator().collect::<Vec<_>>(),
+///     vec![Opcode::Nop, Opcode::Load, Opcode::Store, Opcode::Halt],
+/// );
+/// ```
+///
+/// ### Skipping Variants and Fields
+///
+/// `#[enumerable(skip)]` on an enum variant leaves it out of the enumeration. Its fields don't have
+/// to implement `Enumerable`, and it doesn't count towards the size of the enum. Values of skipped
+/// variants have neither next nor previous values, and enumerators starting from them yield
+/// nothing. An enum with all variants skipped has no values at all.
+///
+/// ```
+/// use enumerable::Enumerable;
+///
+/// #[derive(Clone, Debug, PartialEq, Enumerable)]
+/// enum Request {
+///     Ping,
+///     Echo(bool),
+///     #[enumerable(skip)]
+///     Raw(String),
+/// }
+///
+/// assert_eq!(Request::ENUMERABLE_SIZE, 3);
+/// assert_eq!(
+///     Request::enumerator().collect::<Vec<_>>(),
+///     vec![Request::Ping, Request::Echo(false), Request::Echo(true)],
+/// );
+/// ```
+///
+/// `#[enumerable(skip, default = expr)]` on a field of a struct or a variant holds it at `expr` in
+/// all enumerated values, instead of enumerating it. Like skipped variants, its type doesn't have
+/// to implement `Enumerable`, and it doesn't multiply the size. The expression is evaluated each
+/// time a value is constructed. Skipped fields of the values given to methods like
+/// [`next_value`](Enumerable::next_value) are ignored, and set to the defaults in the results.
+/// Likewise, [`enumerable_to_index`](Enumerable::enumerable_to_index) and
+/// [`enumerator_from`](Enumerable::enumerator_from) treat a value whose skipped fields differ from
+/// the defaults as its counterpart with the defaults, returning its index or enumerating from it,
+/// rather than `None` or nothing.
+///
+/// ```
+/// use enumerable::Enumerable;
+///
+/// #[derive(Clone, Debug, PartialEq, Enumerable)]
+/// struct Job {
+///     #[enumerable(skip, default = 0)]
+///     id: u64,
+///     urgent: bool,
+///     #[enumerable(skip, default = String::from("nightly"))]
+///     queue: String,
+/// }
+///
+/// assert_eq!(Job::ENUMERABLE_SIZE, 2);
+/// assert!(Job::enumerator().all(|job| job.id == 0 && job.queue == "nightly"));
+///
+/// let job = Job { id: 7, urgent: true, queue: "urgent".into() };
+/// assert_eq!(Job::enumerable_to_index(&job), Some(1));
+/// ```
+///
+/// ### Limiting the Number of Values
+///
+/// `#[enumerable(max_size = N)]` makes the compilation fail if the derived type has more than `N`
+/// possible values, or more than `usize::MAX` of them. It guards exhaustive tests against a field
+/// type change silently blowing up the number of cases.
+///
+/// ```
+/// use enumerable::Enumerable;
+///
+/// #[derive(Copy, Clone, Enumerable)]
+/// #[enumerable(max_size = 512)]
+/// struct Config {
+///     level: u8,
+///     verbose: bool,
+/// }
+/// ```
+///
+/// Non-generic types are checked where they are defined:
+///
+/// ```compile_fail
+/// use enumerable::Enumerable;
+///
+/// #[derive(Copy, Clone, Enumerable)]
+/// #[enumerable(max_size = 512)]
+/// struct Config {
+///     level: u16, // 131072 values in total, too many
+///     verbose: bool,
+/// }
+/// ```
+///
+/// Generic types are checked for each instantiation whose enumerator is used, when the code is
+/// built (`cargo check` does not report these errors):
+///
+/// ```compile_fail
+/// use enumerable::Enumerable;
+///
+/// #[derive(Copy, Clone, Enumerable)]
+/// #[enumerable(max_size = 256)]
+/// struct Wrapper<T> {
+///     value: T,
+/// }
+///
+/// let _ = Wrapper::<u8>::enumerator(); // fine
+/// let _ = Wrapper::<u16>::enumerator(); // too many values
+/// ```
+///
+/// ### Skipping the Size Computation
+///
+/// The derived `ENUMERABLE_SIZE_OPTION` is computed from the sizes of all field types, which can be
+/// slow to compile for deeply nested generic types. `#[enumerable(no_size)]` makes it `None`
+/// unconditionally instead, meaning the size is intentionally unreported. The enumerator is not
+/// affected, but [`ENUMERABLE_SIZE`](Enumerable::ENUMERABLE_SIZE) fails to evaluate, and types
+/// containing this type report `None` as their sizes as well. `no_size` cannot be combined with
+/// `max_size`.
+///
+/// ```
+/// use enumerable::Enumerable;
+///
+/// #[derive(Copy, Clone, Enumerable)]
+/// #[enumerable(no_size)]
+/// struct Pair<A, B> {
+///     first: A,
+///     second: B,
+/// }
+///
+/// assert_eq!(Pair::<bool, u8>::ENUMERABLE_SIZE_OPTION, None);
+/// assert_eq!(Pair::<bool, u8>::enumerator().count(), 512);
+/// ```
+///
+/// ### Exact-Size Enumerators
+///
+/// `#[enumerable(exact_size)]` implements [`ExactSizeEnumerable`] for the type, whose enumerator
+/// then implements [`ExactSizeIterator`]. All field types must be `ExactSizeEnumerable`, and
+/// `exact_size` cannot be combined with `no_size`. Fieldless enums always implement it.
+///
+/// ### Diagonal Enumeration
+///
+/// `#[enumerable(diagonal)]` on a struct with fields implements [`ProductEnumerable`] for it, like
+/// tuples, so it can also be enumerated by
+/// [`enumerator_diagonal`](ProductEnumerable::enumerator_diagonal), which advances all fields
+/// fairly instead of exhausting the last field first. The order of [`Enumerable::enumerator`] is
+/// unchanged.
+///
+/// ### Ordered Enumerators
+///
+/// `#[enumerable(ordered)]` declares that the enumerator yields values in ascending order of
+/// [`Ord`], implementing [`OrderedEnumerable`] for it. The type must implement `Ord`, and it's up
+/// to the user to make sure the order matches.
+///
 /// ## Guarantees and Requirements
 ///
 /// It is guaranteed that:
 /// - The derived implementations will enumerate over all possible variants of an enum in the order
-///   they are declared. Variants with fields of uninhabited types (e.g. empty enums) will be
-///   skipped.
+///   they are declared (or in the order of their discriminants, with
+///   `#[enumerable(sort_by_discriminant)]`). Variants with fields of uninhabited types (e.g. empty
+///   enums) will be skipped.
 /// - The derived implementations will yield all possible values of a struct (or a variant with some
 ///   fields of an enum) in a lexicographic ordering based on the top-to-bottom declaration order of
 ///   the fields, as built-in implementations for tuples do.
 ///
+/// `Enumerable` requires [`Clone`] but not [`Copy`], so types that intentionally don't implement
+/// `Copy` can be enumerated as well. Values are cloned where the enumeration needs to keep a copy,
+/// which is as cheap as copying for `Copy` types.
+///
 /// It is **NOT** guaranteed that:
-/// - The derived and the built-in implementations will return a specific type of [`Iterator`] as
-///   enumerators.
+/// - The derived implementations and the built-in implementations not listed in
+///   [Stable Enumerator Types](#stable-enumerator-types) will return a specific type of
+///   [`Iterator`] as enumerators.
 ///
-///   Do **NOT** rely on the specific type of the enumerator provided by an `Enumerable` type,
+///   Do **NOT** rely on the specific type of the enumerator provided by such an `Enumerable` type,
 ///   unless you are using `#[enumerator(...)]` and knowing that `#[derive(Enumerable)]` will
 ///   generate an enumerator type, use `<T as Enumerable>::Enumerator` instead in all other cases.
 ///
@@ -89,6 +338,94 @@
 /// Failed to meet the requirements will result in unexpected behavior when interacting with the
 /// derived implementations.
 ///
+/// ## Efficient Manual Implementations
+///
+/// Only `Enumerator`, `enumerator()` and `ENUMERABLE_SIZE_OPTION` are required, and all other
+/// methods step through the enumerator by default. To make them faster, override these first:
+///
+/// 1. [`enumerable_from_index`](Enumerable::enumerable_from_index) (and
+///    [`enumerable_from_index_u128`](Enumerable::enumerable_from_index_u128) for types with more
+///    than `usize::MAX` values), computing the value at an index arithmetically.
+/// 2. [`enumerator_from_direct`](Enumerable::enumerator_from_direct), positioning the enumerator at
+///    a value, and [`last_value`](Enumerable::last_value), which it's called with to position the
+///    enumerator past the end.
+///
+/// With both, [`enumerator_since`](Enumerable::enumerator_since),
+/// [`enumerator_range`](Enumerable::enumerator_range) and
+/// [`enumerator_from`](Enumerable::enumerator_from) are fast without further overrides. The
+/// default `enumerator_since` is built on them, so they must not call `enumerator_since` unless it
+/// is overridden as well.
+///
+/// ## Composing Enumerators by Hand
+///
+/// The enumerators of the built-in implementations can be created directly with their `new`
+/// constructors (e.g. [`OptionEnumerator::new`], [`Tuple2Enumerator::new`]), which is handy for
+/// manual implementations built on top of existing ones. The functions in the [`size`] module
+/// compute their sizes from those of the parts, with the same semantics as the derive:
+///
+/// ```
+/// use enumerable::{Enumerable, OptionEnumerator};
+///
+/// /// A volume level, or muted.
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// struct Volume(Option<u8>);
+///
+/// impl Enumerable for Volume {
+///     type Enumerator = core::iter::Map<OptionEnumerator<u8>, fn(Option<u8>) -> Volume>;
+///
+///     fn enumerator() -> Self::Enumerator {
+///         OptionEnumerator::new().map(Volume)
+///     }
+///
+///     const ENUMERABLE_SIZE_OPTION: Option<usize> = <Option<u8>>::ENUMERABLE_SIZE_OPTION;
+/// }
+///
+/// assert_eq!(Volume::enumerator().next(), Some(Volume(None)));
+/// assert_eq!(Volume::enumerator().count(), 257);
+/// ```
+///
+/// ## Stable Enumerator Types
+///
+/// The enumerator types of the following built-in implementations are part of the public API, and
+/// won't change in semver-compatible releases:
+///
+/// | Type | `<T as Enumerable>::Enumerator` |
+/// | --- | --- |
+/// | `char` | [`CharEnumerator`] |
+/// | `Option<T>` | [`OptionEnumerator<T>`](OptionEnumerator) |
+/// | `Result<T, E>` | [`ResultEnumerator<T, E>`](ResultEnumerator) |
+/// | `Poll<T>` | [`PollEnumerator<T>`](PollEnumerator) |
+/// | `(A,)` | [`Tuple1Enumerator<A>`](Tuple1Enumerator) |
+/// | `(A, B)`, `(A, B, C)`, ... | [`Tuple2Enumerator<A, B>`](Tuple2Enumerator), `Tuple3Enumerator<A, B, C>`, ... |
+/// | `Either<L, R>`, with the `either` feature | `EitherEnumerator<L, R>` |
+///
+/// They implement `Clone` and `Debug` whenever the enumerators they wrap do, so they can be named
+/// in fields of types deriving these traits. Cloning a partially consumed enumerator yields a copy
+/// which continues from the same position.
+///
+/// ```
+/// use enumerable::{Enumerable, OptionEnumerator, Tuple2Enumerator};
+///
+/// /// A search through all settings, which can be paused and resumed.
+/// #[derive(Clone, Debug)]
+/// struct Search {
+///     flags: Tuple2Enumerator<bool, bool>,
+///     level: OptionEnumerator<u8>,
+/// }
+///
+/// let mut search = Search {
+///     flags: <(bool, bool)>::enumerator(),
+///     level: <Option<u8>>::enumerator(),
+/// };
+/// assert_eq!(search.flags.next(), Some((false, false)));
+/// assert_eq!(search.level.next(), Some(None));
+///
+/// let checkpoint = search.clone();
+/// assert_eq!(search.flags.next(), Some((false, true)));
+/// assert_eq!(checkpoint.flags.clone().next(), Some((false, true)));
+/// assert_eq!(checkpoint.level.count(), 256);
+/// ```
+///
 /// ## Example
 ///
 /// ```
@@ -104,7 +441,7 @@
 /// assert_eq!(enumerated, vec![None, Some(SomeEnum::A), Some(SomeEnum::B), Some(SomeEnum::C)]);
 /// ```
 ///
-pub trait Enumerable: Copy {
+pub trait Enumerable: Clone {
     /// The type of the iterator that will be returned by the `enumerator` method.
     type Enumerator: Iterator<Item = Self>;
     /// Return an iterator over all possible values of the implementing type.
@@ -122,6 +459,27 @@ pub trait Enumerable: Copy {
     /// assert_eq!(<(usize, usize)>::ENUMERABLE_SIZE_OPTION, None);
     /// ```
     const ENUMERABLE_SIZE_OPTION: Option<usize>;
+
+    /// The number of elements in this enumerable wrapped in `Option::Some` if it does not exceed
+    /// `u128::MAX`, `None` otherwise.
+    ///
+    /// Unlike `ENUMERABLE_SIZE_OPTION`, it still counts types with more than `usize::MAX` values,
+    /// e.g. `(u16, u16, u16, u16)`. The default value is converted from `ENUMERABLE_SIZE_OPTION`.
+    /// Derived and built-in implementations calculate this constant instead, and define
+    /// `ENUMERABLE_SIZE_OPTION` in terms of it.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    /// assert_eq!(<(u16, u16, u16, u16)>::ENUMERABLE_SIZE_OPTION, None);
+    /// assert_eq!(<(u16, u16, u16, u16)>::ENUMERABLE_SIZE_OPTION_U128, Some(1 << 64));
+    /// assert_eq!(<(u128, bool)>::ENUMERABLE_SIZE_OPTION_U128, None);
+    /// ```
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = match Self::ENUMERABLE_SIZE_OPTION {
+        Some(size) => Some(size as u128),
+        None => None,
+    };
     /// The number of elements in this enumerable.
     /// If the number exceeds the `usize::MAX`, accessing this constant fails at compile time.
     ///
@@ -148,14 +506,1058 @@ pub trait Enumerable: Copy {
             }
         }
     };
+
+    /// Whether the implementing type has any values at all.
+    ///
+    /// The default value is `false` only if
+    /// [`ENUMERABLE_SIZE_OPTION_U128`](Enumerable::ENUMERABLE_SIZE_OPTION_U128) is `Some(0)`, so
+    /// types with unknown sizes are assumed to be inhabited. Derived and built-in implementations
+    /// compute it from their fields instead, so it's exact even with `#[enumerable(no_size)]`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// #[derive(Copy, Clone, Enumerable)]
+    /// enum Never {}
+    ///
+    /// assert!(u8::IS_INHABITED);
+    /// assert!(<Option<Never>>::IS_INHABITED);
+    /// assert!(!Never::IS_INHABITED);
+    /// assert!(!<(u128, u128, Never)>::IS_INHABITED);
+    /// assert!(!<Result<Never, Never>>::IS_INHABITED);
+    /// ```
+    const IS_INHABITED: bool = !matches!(Self::ENUMERABLE_SIZE_OPTION_U128, Some(0));
+
+    /// Returns the first value in enumeration order, or `None` if the type is uninhabited.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// assert_eq!(i8::first_value(), Some(-128));
+    /// assert_eq!(<Option<bool>>::first_value(), Some(None));
+    /// ```
+    fn first_value() -> Option<Self> {
+        if !Self::IS_INHABITED {
+            return None;
+        }
+
+        Self::enumerator().next()
+    }
+
+    /// Returns the last value in enumeration order, or `None` if the type is uninhabited.
+    ///
+    /// The default implementation steps through the whole enumerator. Built-in implementations and
+    /// `#[derive(Enumerable)]` override it to compute the last value directly, from the last values
+    /// of fields and the last inhabited variant.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// assert_eq!(u16::last_value(), Some(u16::MAX));
+    /// assert_eq!(<(bool, Option<char>)>::last_value(), Some((true, Some('\u{10FFFF}'))));
+    ///
+    /// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    /// enum Never {}
+    ///
+    /// assert_eq!(Never::last_value(), None);
+    /// assert_eq!(<Result<u8, (u8, Never)>>::last_value(), Some(Ok(255)));
+    /// ```
+    fn last_value() -> Option<Self> {
+        if !Self::IS_INHABITED {
+            return None;
+        }
+
+        Self::enumerator().last()
+    }
+
+    /// Returns the value enumerated right after `self`, or `None` if `self` is the last value.
+    ///
+    /// It's computed by [`next_value_direct`](Enumerable::next_value_direct) if possible, which
+    /// takes O(1) time for built-in types and time linear in the number of fields for derived types.
+    /// Otherwise, the enumerator is stepped through until `self` is found.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    /// struct Time {
+    ///     hour: bool,
+    ///     minute: Option<bool>,
+    /// }
+    ///
+    /// let time = Time { hour: false, minute: Some(true) };
+    /// assert_eq!(time.next_value(), Some(Time { hour: true, minute: None }));
+    /// assert_eq!(Time::last_value().unwrap().next_value(), None);
+    /// assert_eq!('\u{D7FF}'.next_value(), Some('\u{E000}'));
+    /// ```
+    fn next_value(self) -> Option<Self>
+    where
+        Self: PartialEq,
+    {
+        if let Some(next) = self.clone().next_value_direct() {
+            return next;
+        }
+
+        let mut enumerator = Self::enumerator();
+        enumerator.by_ref().find(|value| *value == self)?;
+        enumerator.next()
+    }
+
+    /// Returns `Some(self.next_value())` if it can be computed without comparing values, `None`
+    /// otherwise.
+    ///
+    /// The default implementation returns `None`. Built-in implementations and
+    /// `#[derive(Enumerable)]` override it, so that [`next_value`](Enumerable::next_value) of
+    /// types containing them can be computed from their fields, without requiring `PartialEq` on
+    /// the fields. Manual implementations can override it as well.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// assert_eq!(254u8.next_value_direct(), Some(Some(255)));
+    /// assert_eq!(255u8.next_value_direct(), Some(None));
+    /// ```
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        None
+    }
+
+    /// Returns the value enumerated right before `self`, or `None` if `self` is the first value.
+    ///
+    /// It's computed by [`previous_value_direct`](Enumerable::previous_value_direct) if possible,
+    /// which takes O(1) time for built-in types and time linear in the number of fields for derived
+    /// types. Otherwise, the enumerator is stepped through until `self` is found.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    /// struct Time {
+    ///     hour: bool,
+    ///     minute: Option<bool>,
+    /// }
+    ///
+    /// let time = Time { hour: true, minute: None };
+    /// assert_eq!(time.previous_value(), Some(Time { hour: false, minute: Some(true) }));
+    /// assert_eq!(Time::first_value().unwrap().previous_value(), None);
+    /// assert_eq!('\u{E000}'.previous_value(), Some('\u{D7FF}'));
+    /// ```
+    fn previous_value(self) -> Option<Self>
+    where
+        Self: PartialEq,
+    {
+        if let Some(previous) = self.clone().previous_value_direct() {
+            return previous;
+        }
+
+        let mut previous = None;
+        for value in Self::enumerator() {
+            if value == self {
+                return previous;
+            }
+            previous = Some(value);
+        }
+
+        None
+    }
+
+    /// Returns `Some(self.previous_value())` if it can be computed without comparing values, `None`
+    /// otherwise.
+    ///
+    /// Like [`next_value_direct`](Enumerable::next_value_direct), the default implementation
+    /// returns `None`, and built-in implementations and `#[derive(Enumerable)]` override it.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// assert_eq!(1u8.previous_value_direct(), Some(Some(0)));
+    /// assert_eq!(0u8.previous_value_direct(), Some(None));
+    /// ```
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        None
+    }
+
+    /// Returns the value enumerated right after `self`, wrapping around to the first value if
+    /// `self` is the last one.
+    ///
+    /// It's as efficient as [`next_value`](Enumerable::next_value). It never panics, as `self`
+    /// being a value means the type is inhabited.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    /// enum Theme {
+    ///     Light,
+    ///     Dark,
+    ///     System,
+    /// }
+    ///
+    /// assert_eq!(Theme::Dark.cycle_next(), Theme::System);
+    /// assert_eq!(Theme::System.cycle_next(), Theme::Light);
+    /// ```
+    fn cycle_next(self) -> Self
+    where
+        Self: PartialEq,
+    {
+        // `self` exists, so there is a first value
+        self.clone()
+            .next_value()
+            .or_else(Self::first_value)
+            .unwrap_or(self)
+    }
+
+    /// Returns the value enumerated right before `self`, wrapping around to the last value if
+    /// `self` is the first one.
+    ///
+    /// It's as efficient as [`previous_value`](Enumerable::previous_value). It never panics, as
+    /// `self` being a value means the type is inhabited.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    /// enum Theme {
+    ///     Light,
+    ///     Dark,
+    ///     System,
+    /// }
+    ///
+    /// assert_eq!(Theme::Dark.cycle_prev(), Theme::Light);
+    /// assert_eq!(Theme::Light.cycle_prev(), Theme::System);
+    /// ```
+    fn cycle_prev(self) -> Self
+    where
+        Self: PartialEq,
+    {
+        // `self` exists, so there is a last value
+        self.clone()
+            .previous_value()
+            .or_else(Self::last_value)
+            .unwrap_or(self)
+    }
+
+    /// Returns the value at `index` in enumeration order, or `None` if `index` is out of range.
+    ///
+    /// The default implementation steps through the enumerator, so it takes O(`index`) time.
+    /// Built-in implementations such as those of integers, `Option<T>`, `Result<T, E>` and tuples
+    /// override it to look up values directly. Tuples decompose `index` in mixed radix by the
+    /// sizes of their elements, and fall back to stepping if the size of any element is unknown.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// assert_eq!(u8::enumerable_from_index(42), Some(42));
+    /// assert_eq!(<Option<bool>>::enumerable_from_index(2), Some(Some(true)));
+    /// assert_eq!(bool::enumerable_from_index(2), None);
+    /// ```
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        if !Self::IS_INHABITED {
+            return None;
+        }
+
+        Self::enumerator().nth(index)
+    }
+
+    /// Returns the value at `index` in enumeration order, or `None` if `index` is out of range,
+    /// addressing all values of types with more than `usize::MAX` values.
+    ///
+    /// The default implementation delegates to
+    /// [`enumerable_from_index`](Enumerable::enumerable_from_index) if `index` fits in `usize`,
+    /// and returns `None` otherwise, as such values are out of reach of stepping through the
+    /// enumerator. Integers, `Option`, `Result`, tuples and `#[derive(Enumerable)]` override it
+    /// with O(1) mixed-radix arithmetic on `u128`, which pairs with
+    /// [`ENUMERABLE_SIZE_OPTION_U128`](Enumerable::ENUMERABLE_SIZE_OPTION_U128).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// let index = 1u128 << 100;
+    /// assert_eq!(u128::enumerable_from_index_u128(index), Some(1 << 100));
+    /// assert_eq!(<(u32, u64)>::enumerable_from_index_u128(index - 1), None);
+    /// assert_eq!(
+    ///     <(u32, u64)>::enumerable_from_index_u128((3 << 64) + 4),
+    ///     Some((3, 4))
+    /// );
+    /// ```
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        usize::try_from(index)
+            .ok()
+            .and_then(Self::enumerable_from_index)
+    }
+
+    /// Returns the value at `index` in enumeration order, or a [`FromIndexError`] carrying the
+    /// index and the number of values if `index` is out of range.
+    ///
+    /// It delegates to [`enumerable_from_index`](Enumerable::enumerable_from_index), so it's as
+    /// efficient as that.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// assert_eq!(u8::try_enumerable_from_index(42), Ok(42));
+    ///
+    /// let error = u8::try_enumerable_from_index(300).unwrap_err();
+    /// assert_eq!(error.index, 300);
+    /// assert_eq!(error.size, Some(256));
+    /// assert_eq!(error.to_string(), "index 300 out of range for type with 256 values");
+    /// ```
+    fn try_enumerable_from_index(index: usize) -> Result<Self, FromIndexError> {
+        Self::enumerable_from_index(index).ok_or_else(|| FromIndexError::new::<Self>(index))
+    }
+
+    /// Returns the index of `value` in enumeration order, the inverse of
+    /// [`enumerable_from_index`](Enumerable::enumerable_from_index), or `None` if the index
+    /// exceeds `usize::MAX`.
+    ///
+    /// The default implementation computes the index directly for built-in and derived types, in
+    /// O(1) time or time linear in the number of fields. Otherwise, it steps through the
+    /// enumerator until `value` is found, which takes O(index) time.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// assert_eq!(i8::enumerable_to_index(&0), Some(128));
+    /// assert_eq!(char::enumerable_to_index(&'\u{E000}'), Some(0xD800));
+    /// assert_eq!(<Option<bool>>::enumerable_to_index(&Some(true)), Some(2));
+    /// ```
+    fn enumerable_to_index(value: &Self) -> Option<usize>
+    where
+        Self: PartialEq,
+    {
+        match Self::enumerable_to_index_direct(value) {
+            Some(index) => usize::try_from(index).ok(),
+            None => Self::enumerator().position(|v| v == *value),
+        }
+    }
+
+    /// Returns `Some` index of `value` in enumeration order as a `u128`, if it can be computed
+    /// without comparing values, `None` otherwise.
+    ///
+    /// It's an implementation detail of built-in implementations and `#[derive(Enumerable)]`,
+    /// backing [`enumerable_to_index`](Enumerable::enumerable_to_index) and the enumerators
+    /// positioned by index, and not part of the public API. The default implementation returns
+    /// `None`.
+    #[doc(hidden)]
+    fn enumerable_to_index_direct(_value: &Self) -> Option<u128> {
+        None
+    }
+
+    /// Returns an iterator over all possible values of the implementing type, starting from the
+    /// value at index `start` in enumeration order.
+    ///
+    /// It yields the same values as `Self::enumerator().skip(start)`, but returns the enumerator
+    /// type itself. If [`enumerator_from_direct`](Enumerable::enumerator_from_direct) is supported
+    /// (probed with the first value), the default implementation looks up the value at `start` by
+    /// [`enumerable_from_index`](Enumerable::enumerable_from_index) and positions the enumerator
+    /// there, which takes time linear in the number of fields for derived types. Otherwise, it
+    /// steps through the enumerator in O(`start`) time. Integers, `char`, `bool`, `Option`,
+    /// `Result` and fieldless enums override it to position the enumerator in O(1) time. Tuples
+    /// decompose `start` like [`enumerable_from_index`](Enumerable::enumerable_from_index) and
+    /// position the enumerator of each element by its own `enumerator_since`, stepping through
+    /// the enumerator only if the size of any element is unknown.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// let mut iter = u8::enumerator_since(254);
+    /// assert_eq!(iter.next(), Some(254));
+    /// assert_eq!(iter.next(), Some(255));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(bool::enumerator_since(3).next(), None);
+    /// ```
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        let mut enumerator = Self::enumerator();
+        if start == 0 || !Self::IS_INHABITED {
+            return enumerator;
+        }
+
+        if Self::first_value()
+            .and_then(Self::enumerator_from_direct)
+            .is_some()
+        {
+            let positioned = match Self::enumerable_from_index(start) {
+                Some(value) => Self::enumerator_from_direct(value),
+                // `start` is out of range, so position the enumerator past the last value
+                None => Self::last_value()
+                    .and_then(Self::enumerator_from_direct)
+                    .map(|mut enumerator| {
+                        enumerator.next();
+                        enumerator
+                    }),
+            };
+
+            if let Some(positioned) = positioned {
+                return positioned;
+            }
+        }
+
+        enumerator.nth(start - 1);
+        enumerator
+    }
+
+    /// Returns an iterator over all possible values of the implementing type, starting from
+    /// `value` in enumeration order. `value` itself is the first value yielded.
+    ///
+    /// It yields the same values as `Self::enumerator().skip_while(|v| *v != value)`. It's
+    /// computed by [`enumerator_from_direct`](Enumerable::enumerator_from_direct) if possible,
+    /// which positions the enumerator in O(1) time for built-in types and time linear in the number
+    /// of fields for derived types. Otherwise, the enumerator is stepped through until `value` is
+    /// found.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    /// struct Time {
+    ///     hour: bool,
+    ///     minute: Option<bool>,
+    /// }
+    ///
+    /// let mut iter = Time::enumerator_from(Time { hour: true, minute: Some(false) });
+    /// assert_eq!(iter.next(), Some(Time { hour: true, minute: Some(false) }));
+    /// assert_eq!(iter.next(), Some(Time { hour: true, minute: Some(true) }));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(u8::enumerator_from(254).collect::<Vec<_>>(), vec![254, 255]);
+    /// ```
+    fn enumerator_from(value: Self) -> Self::Enumerator
+    where
+        Self: PartialEq,
+    {
+        if let Some(enumerator) = Self::enumerator_from_direct(value.clone()) {
+            return enumerator;
+        }
+
+        // `value` is always found, unless the implementation is inconsistent, in which case the
+        // enumerator is exhausted
+        let start = Self::enumerator()
+            .position(|v| v == value)
+            .unwrap_or(usize::MAX);
+        Self::enumerator_since(start)
+    }
+
+    /// Returns an iterator over all possible values of the implementing type, starting right after
+    /// `value` in enumeration order. `value` itself is not yielded.
+    ///
+    /// It's [`enumerator_from`](Enumerable::enumerator_from) stepped once, so it's as efficient as
+    /// that.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// assert_eq!(u8::enumerator_after(254).collect::<Vec<_>>(), vec![255]);
+    /// assert_eq!(u8::enumerator_after(255).next(), None);
+    /// ```
+    fn enumerator_after(value: Self) -> Self::Enumerator
+    where
+        Self: PartialEq,
+    {
+        let mut enumerator = Self::enumerator_from(value);
+        enumerator.next();
+        enumerator
+    }
+
+    /// Returns `Some(Self::enumerator_from(value))` if it can be computed without comparing
+    /// values, `None` otherwise.
+    ///
+    /// Like [`next_value_direct`](Enumerable::next_value_direct), the default implementation
+    /// returns `None`, and built-in implementations and `#[derive(Enumerable)]` override it.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// let mut iter = <Option<bool>>::enumerator_from_direct(Some(true)).unwrap();
+    /// assert_eq!(iter.next(), Some(Some(true)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn enumerator_from_direct(_value: Self) -> Option<Self::Enumerator> {
+        None
+    }
+
+    /// Returns an iterator over the values at indices `start..end` in enumeration order.
+    ///
+    /// It yields the same values as `Self::enumerator().skip(start).take(end - start)`, nothing if
+    /// `start >= end`, and stops at the last value if `end` exceeds the number of values. The
+    /// enumerator is positioned by [`enumerator_since`](Enumerable::enumerator_since), so it's as
+    /// efficient as that. The returned iterator reports an exact size hint if the number of values
+    /// is known.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// let window = u16::enumerator_range(1_000, 1_003).collect::<Vec<_>>();
+    /// assert_eq!(window, vec![1_000, 1_001, 1_002]);
+    ///
+    /// let tail = <Option<bool>>::enumerator_range(1, 100);
+    /// assert_eq!(tail.size_hint(), (2, Some(2)));
+    /// assert_eq!(tail.collect::<Vec<_>>(), vec![Some(false), Some(true)]);
+    /// ```
+    fn enumerator_range(start: usize, end: usize) -> RangeEnumerator<Self> {
+        RangeEnumerator::new(start, end)
+    }
+
+    /// Returns an iterator over the `shard`-th of `num_shards` contiguous, disjoint parts of all
+    /// possible values of the implementing type, e.g. to split an exhaustive search across
+    /// machines.
+    ///
+    /// The `shard`-th part holds the values at indices `size * shard / num_shards` up to (but not
+    /// including) `size * (shard + 1) / num_shards`, where `size` is the number of values, so the
+    /// parts differ in length by at most one and together they yield every value exactly once, in
+    /// enumeration order. It's built on [`enumerator_range`](Enumerable::enumerator_range), so
+    /// it's as efficient as that. If the size is unknown, the values are counted first by
+    /// stepping through the enumerator once, which takes time linear in the number of values.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `shard >= num_shards`, or if the type has more than `usize::MAX` values. If the
+    /// size is unknown, the latter is only detected after counting `usize::MAX` values, so in
+    /// practice the counting pass doesn't finish for such types.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// let shards = (0..3)
+    ///     .map(|shard| <Option<bool>>::enumerator_shard(shard, 3).collect::<Vec<_>>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(shards, vec![vec![None], vec![Some(false)], vec![Some(true)]]);
+    /// assert_eq!(u8::enumerator_shard(1, 3).next(), Some(85));
+    /// assert_eq!(u8::enumerator_shard(2, 3).count(), 86);
+    /// ```
+    fn enumerator_shard(shard: usize, num_shards: usize) -> RangeEnumerator<Self> {
+        assert!(
+            shard < num_shards,
+            "`shard` ({}) must be less than `num_shards` ({})",
+            shard,
+            num_shards
+        );
+
+        let size = match (
+            Self::ENUMERABLE_SIZE_OPTION,
+            Self::ENUMERABLE_SIZE_OPTION_U128,
+        ) {
+            (Some(size), _) => size,
+            (None, Some(_)) => panic!("types with more than `usize::MAX` values can't be sharded"),
+            // counted without wrapping around, panicking as soon as the count exceeds `usize::MAX`
+            (None, None) => Self::enumerator()
+                .try_fold(0usize, |count, _| count.checked_add(1))
+                .expect("types with more than `usize::MAX` values can't be sharded"),
+        };
+        // `shard + 1 <= num_shards`, so the bounds never exceed `size`
+        let bound = |shard: usize| (size as u128 * shard as u128 / num_shards as u128) as usize;
+
+        Self::enumerator_range(bound(shard), bound(shard + 1))
+    }
+
+    /// Returns an iterator over all possible values of the implementing type, in an order spreading
+    /// early values across the whole enumeration.
+    ///
+    /// The value at index `i` of the enumeration is yielded at position `reverse(i)`, where
+    /// `reverse` reverses the lowest `k` bits and `2^k` is the smallest power of two not less than
+    /// [`ENUMERABLE_SIZE`](Enumerable::ENUMERABLE_SIZE) (the van der Corput sequence in base 2).
+    /// Reversed indices out of range are skipped, so every value is yielded exactly once, and any
+    /// prefix of the iterator is roughly evenly distributed over the enumeration. Values are looked
+    /// up by [`enumerable_from_index`](Enumerable::enumerable_from_index).
+    ///
+    /// Like `ENUMERABLE_SIZE`, calling it fails to compile if the size exceeds `usize::MAX`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// let first = u8::enumerator_low_discrepancy().take(16).collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     first,
+    ///     vec![0, 128, 64, 192, 32, 160, 96, 224, 16, 144, 80, 208, 48, 176, 112, 240]
+    /// );
+    /// ```
+    fn enumerator_low_discrepancy() -> LowDiscrepancyEnumerator<Self> {
+        LowDiscrepancyEnumerator::new()
+    }
+
+    /// Returns an iterator over all possible values of the implementing type, in the bit-reversed
+    /// "spread" order, so that exploring only a prefix of a large product space still covers all
+    /// of it instead of a corner.
+    ///
+    /// It yields the same values in the same order as
+    /// [`enumerator_low_discrepancy`](Enumerable::enumerator_low_discrepancy): indices are
+    /// permuted by reversing their bits over the smallest power of two not less than the size,
+    /// skipping reversed indices out of range, so every value is yielded exactly once.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// let first = <(bool, u8)>::enumerator_spread().take(4).collect::<Vec<_>>();
+    /// assert_eq!(first, vec![(false, 0), (true, 0), (false, 128), (true, 128)]);
+    /// ```
+    fn enumerator_spread() -> LowDiscrepancyEnumerator<Self> {
+        Self::enumerator_low_discrepancy()
+    }
+
+    /// Returns an iterator over all possible values of the implementing type, in a pseudo-random
+    /// order determined by `seed`.
+    ///
+    /// Every value is yielded exactly once, and the same seed always gives the same order. Indices
+    /// are permuted by a Feistel network keyed by `seed` over the smallest domain of `4^k` indices
+    /// covering all values, with those out of range skipped, so nothing is allocated and fewer than
+    /// four indices are tried per value. Values are looked up by
+    /// [`enumerable_from_index`](Enumerable::enumerable_from_index). The order is meant to avoid the
+    /// bias of enumeration order when a search is cut short, and is not cryptographically secure.
+    ///
+    /// Like [`ENUMERABLE_SIZE`](Enumerable::ENUMERABLE_SIZE), calling it fails to compile if the
+    /// size exceeds `usize::MAX`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// let mut shuffled = u8::enumerator_shuffled(42).collect::<Vec<_>>();
+    /// assert_eq!(shuffled, u8::enumerator_shuffled(42).collect::<Vec<_>>());
+    /// assert_ne!(shuffled, u8::enumerator().collect::<Vec<_>>());
+    ///
+    /// shuffled.sort();
+    /// assert_eq!(shuffled, u8::enumerator().collect::<Vec<_>>());
+    /// ```
+    fn enumerator_shuffled(seed: u64) -> ShuffledEnumerator<Self> {
+        ShuffledEnumerator::new(seed)
+    }
+
+    /// Returns an iterator over all possible values of the implementing type, in exactly the
+    /// reverse order of [`enumerator`](Enumerable::enumerator).
+    ///
+    /// The default implementation looks up values by
+    /// [`enumerable_from_index_u128`](Enumerable::enumerable_from_index_u128) from the last index
+    /// down to 0, so types with more than `usize::MAX` values are covered as well. If the size is
+    /// unknown even as a `u128`, it starts from the index of the last value if it's computed
+    /// directly as in [`enumerable_to_index`](Enumerable::enumerable_to_index), or counts the
+    /// values first otherwise. Types whose enumerators implement
+    /// [`DoubleEndedIterator`] can override it with [`ReversedEnumerator::from_double_ended`],
+    /// as integers, `bool`, `char`, derived field-less enums and derived unit structs do. Other
+    /// derived types look up values in time linear in the number of fields, as long as their field
+    /// types look up values in constant time. Manual implementations that keep the default
+    /// `enumerable_from_index_u128`, which scans the enumerator, take O(n²) time in total to
+    /// reverse n values, and should override this method or `enumerable_from_index_u128`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    /// struct Point {
+    ///     x: bool,
+    ///     y: Option<bool>,
+    /// }
+    ///
+    /// let mut reversed = Point::enumerator_reversed();
+    /// assert_eq!(reversed.next(), Some(Point { x: true, y: Some(true) }));
+    /// assert_eq!(reversed.next(), Some(Point { x: true, y: Some(false) }));
+    /// assert_eq!(reversed.next(), Some(Point { x: true, y: None }));
+    /// assert_eq!(reversed.next(), Some(Point { x: false, y: Some(true) }));
+    ///
+    /// assert_eq!(u8::enumerator_reversed().take(2).collect::<Vec<_>>(), vec![255, 254]);
+    /// ```
+    fn enumerator_reversed() -> ReversedEnumerator<Self> {
+        ReversedEnumerator::new()
+    }
+
+    /// Returns an iterator over every `step`-th value of the implementing type in enumeration
+    /// order, starting from the value at index `start`.
+    ///
+    /// It yields the same values as `Self::enumerator().skip(start).step_by(step)`. The default
+    /// implementation steps through the enumerator positioned by
+    /// [`enumerator_since`](Enumerable::enumerator_since). Integers, `char` and derived types
+    /// override it with [`StepByEnumerator::by_index`], which looks up each value by
+    /// [`enumerable_from_index_u128`](Enumerable::enumerable_from_index_u128) instead of visiting
+    /// the values in between.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `step` is 0, like [`Iterator::step_by`].
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    /// struct Grid {
+    ///     x: u16,
+    ///     y: u16,
+    /// }
+    ///
+    /// let coarse = Grid::enumerator_step_by(0, 1 << 20).collect::<Vec<_>>();
+    /// assert_eq!(coarse.len(), 4096);
+    /// assert_eq!(coarse[1], Grid { x: 16, y: 0 });
+    /// assert_eq!(u8::enumerator_step_by(10, 100).collect::<Vec<_>>(), vec![10, 110, 210]);
+    /// ```
+    fn enumerator_step_by(start: usize, step: usize) -> StepByEnumerator<Self> {
+        StepByEnumerator::new(start, step)
+    }
+
+    /// Returns an iterator over all possible values of the implementing type, calling
+    /// `callback(done, total)` after every `every` values, where `done` is the number of values
+    /// yielded so far and `total` is [`ENUMERABLE_SIZE_OPTION`](Enumerable::ENUMERABLE_SIZE_OPTION).
+    ///
+    /// When the enumerator is exhausted, `callback` is called once more with the final count unless
+    /// it was just called with it, so the last call always reports all values. It doesn't print
+    /// anything itself, and the callback is only called once per batch to keep the overhead low.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `every` is 0.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// let mut reports = vec![];
+    /// let count = u8::enumerator_with_progress(100, |done, total| reports.push((done, total)))
+    ///     .count();
+    ///
+    /// assert_eq!(count, 256);
+    /// assert_eq!(reports, vec![(100, Some(256)), (200, Some(256)), (256, Some(256))]);
+    /// ```
+    fn enumerator_with_progress<F: FnMut(usize, Option<usize>)>(
+        every: usize,
+        callback: F,
+    ) -> ProgressEnumerator<Self, F> {
+        ProgressEnumerator::new(every, callback)
+    }
+
+    /// Calls `f` on all possible values of the implementing type, in the same order as
+    /// [`enumerator`](Enumerable::enumerator).
+    ///
+    /// The default implementation drives the enumerator. Implementations may override it with a
+    /// faster loop, e.g. nested loops over fields instead of a stateful enumerator.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// let mut sum = 0u32;
+    /// u8::for_each_value(|x| sum += x as u32);
+    /// assert_eq!(sum, 255 * 256 / 2);
+    /// ```
+    fn for_each_value(f: impl FnMut(Self)) {
+        Self::enumerator().for_each(f)
+    }
+
+    /// Calls `f` on possible values of the implementing type, in the same order as
+    /// [`enumerator`](Enumerable::enumerator), until it returns [`ControlFlow::Break`].
+    ///
+    /// Returns the value carried by the first `Break`, or `None` if `f` continues for all values.
+    /// Like [`for_each_value`](Enumerable::for_each_value), the default implementation drives the
+    /// enumerator, and implementations may override it with a faster loop.
+    ///
+    /// [`ControlFlow::Break`]: core::ops::ControlFlow::Break
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use core::ops::ControlFlow;
+    /// use enumerable::Enumerable;
+    ///
+    /// let found = u8::try_for_each_value(|x| {
+    ///     if x.wrapping_mul(7) == 1 {
+    ///         ControlFlow::Break(x)
+    ///     } else {
+    ///         ControlFlow::Continue(())
+    ///     }
+    /// });
+    /// assert_eq!(found, Some(183));
+    /// assert_eq!(bool::try_for_each_value(|_| ControlFlow::<()>::Continue(())), None);
+    /// ```
+    fn try_for_each_value<R>(f: impl FnMut(Self) -> core::ops::ControlFlow<R>) -> Option<R> {
+        match Self::enumerator().try_for_each(f) {
+            core::ops::ControlFlow::Break(result) => Some(result),
+            core::ops::ControlFlow::Continue(()) => None,
+        }
+    }
+
+    /// Returns an iterator over all possible values of the implementing type, in ascending order of
+    /// [`Ord`].
+    ///
+    /// All values are collected and sorted, taking O(n) memory and O(n log n) time, or O(n) time if
+    /// they are already sorted. Sorting is stable, so values that compare equal are yielded in
+    /// enumeration order. The enumerators of [`OrderedEnumerable`] types are sorted already, so
+    /// [`enumerator`](Enumerable::enumerator) is enough for them.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::Enumerable;
+    ///
+    /// // derived `Ord` compares variants by their discriminants, not by declaration order
+    /// #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Enumerable)]
+    /// enum Priority {
+    ///     High = 2,
+    ///     Low = 0,
+    ///     Medium = 1,
+    /// }
+    ///
+    /// let sorted = Priority::enumerator_sorted().collect::<Vec<_>>();
+    /// assert_eq!(sorted, vec![Priority::Low, Priority::Medium, Priority::High]);
+    /// ```
+    fn enumerator_sorted() -> SortedEnumerator<Self>
+    where
+        Self: Ord,
+    {
+        SortedEnumerator::new()
+    }
+}
+
+/// Converts a size in `u128` to `ENUMERABLE_SIZE_OPTION`, which is `None` if it exceeds
+/// `usize::MAX`.
+pub(crate) const fn size_option_from_u128(size: Option<u128>) -> Option<usize> {
+    match size {
+        Some(size) if size <= usize::MAX as u128 => Some(size as usize),
+        _ => None,
+    }
+}
+
+/// A marker trait for `Enumerable` types whose enumerators yield values in ascending order of
+/// [`Ord`], i.e. each value is less than the next one.
+///
+/// It's implemented for all built-in types whose enumerators are sorted, e.g. integers, `bool`,
+/// `char`, and `Option<T>` and `Result<T, E>` of such types.
+///
+/// The derive macro doesn't implement it by default, as the derived [`Ord`] of an enum compares
+/// discriminants, which may not follow the declaration order. If the order does match, add
+/// `#[enumerable(ordered)]` to implement it.
+pub trait OrderedEnumerable: Enumerable + Ord {
+    /// Returns an iterator over all values from `start` to `end` inclusive, in enumeration (and
+    /// thus ascending) order. It's empty if `start > end`.
+    ///
+    /// The enumerator is positioned at `start` by [`enumerator_from`](Enumerable::enumerator_from),
+    /// so it's as efficient as that.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use enumerable::{Enumerable, OrderedEnumerable};
+    ///
+    /// #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Enumerable)]
+    /// #[enumerable(ordered)]
+    /// enum Weekday {
+    ///     Monday,
+    ///     Tuesday,
+    ///     Wednesday,
+    ///     Thursday,
+    ///     Friday,
+    /// }
+    ///
+    /// let midweek = Weekday::values_between(Weekday::Tuesday, Weekday::Thursday);
+    /// assert_eq!(
+    ///     midweek.collect::<Vec<_>>(),
+    ///     vec![Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday]
+    /// );
+    /// assert_eq!(u8::values_between(250, 255).count(), 6);
+    /// assert_eq!(char::values_between('z', 'a').next(), None);
+    /// ```
+    fn values_between(start: Self, end: Self) -> BetweenEnumerator<Self> {
+        BetweenEnumerator::new(start, end)
+    }
+}
+
+/// A trait for `Enumerable` types whose enumerators implement [`ExactSizeIterator`], so the number
+/// of values left can be queried by `len()` at any time, e.g. to preallocate buffers.
+///
+/// `ExactSizeEnumerator` is always the same type as [`Enumerable::Enumerator`], which is how
+/// generic code bounded by `T: ExactSizeEnumerable` learns that `T::enumerator()` is exact-size.
+/// It's implemented for `bool`, `()`, integers narrower than 32 bits, and `Option<T>` and tuples
+/// of such types. `#[derive(Enumerable)]` implements it for enums without fields, and for other
+/// types with `#[enumerable(exact_size)]`, which requires all fields to be `ExactSizeEnumerable`.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::{Enumerable, ExactSizeEnumerable};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+/// #[enumerable(exact_size)]
+/// struct Cell {
+///     alive: bool,
+///     age: Option<u8>,
+/// }
+///
+/// fn collect_all<T: ExactSizeEnumerable>() -> Vec<T> {
+///     let enumerator = T::enumerator();
+///     let mut values = Vec::with_capacity(enumerator.len());
+///     values.extend(enumerator);
+///     values
+/// }
+///
+/// assert_eq!(collect_all::<Cell>().len(), 2 * 257);
+///
+/// let mut enumerator = Cell::enumerator();
+/// enumerator.nth(300);
+/// assert_eq!(enumerator.len(), 2 * 257 - 301);
+/// ```
+pub trait ExactSizeEnumerable:
+    Enumerable<Enumerator = <Self as ExactSizeEnumerable>::ExactSizeEnumerator>
+{
+    /// The type of the enumerator, which is [`Enumerable::Enumerator`] itself.
+    type ExactSizeEnumerator: ExactSizeIterator<Item = Self>;
+}
+
+/// A trait for `Enumerable` types whose values are all known at compile time, so they can be
+/// enumerated in const contexts, e.g. to build lookup tables.
+///
+/// `VALUES` holds all possible values in enumeration order. It's implemented for `bool`, `()`, and
+/// integers narrower than 32 bits, whose tables are small enough to be built at compile time.
+/// `#[derive(Enumerable)]` implements it for enums without fields and unit structs.
+///
+/// [`const_from_index`] and [`const_values`] read it in `const fn`s, as trait methods can't be
+/// called in const contexts.
+///
+/// Integers of 32 bits or wider and `char` have too many values to be tabulated, so they don't
+/// implement this trait. Instead, their values are looked up arithmetically by dedicated
+/// `const fn`s, such as [`const_u64_from_index`] and [`const_char_from_index`], which exist for all
+/// integer types. These can't back a generic function like [`const_from_index`], as trait methods
+/// can't be called in const contexts, while `VALUES` can be read.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::{const_from_index, const_values, ConstEnumerable, Enumerable};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+///
+/// const SUITS: [Suit; Suit::ENUMERABLE_SIZE] = const_values();
+/// const SYMBOLS: [char; Suit::ENUMERABLE_SIZE] = ['♣', '♦', '♥', '♠'];
+/// const THIRD: Option<Suit> = const_from_index(2);
+///
+/// assert_eq!(SUITS, [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]);
+/// assert_eq!(THIRD, Some(Suit::Hearts));
+/// assert_eq!(SYMBOLS[Suit::enumerable_to_index(&Suit::Spades).unwrap()], '♠');
+/// assert_eq!(<u8 as ConstEnumerable>::VALUES.len(), 256);
+/// ```
+pub trait ConstEnumerable: Enumerable + 'static {
+    /// All possible values of the type, in enumeration order.
+    const VALUES: &'static [Self];
 }
 
+mod adjacent;
+mod all;
+mod ascii;
+mod between;
+mod bits;
+mod cached;
+mod const_values;
+mod diagonal;
+mod dyn_enumerable;
+mod error;
+mod impl_array;
 mod impl_built_in;
+#[cfg(feature = "either")]
+mod impl_either;
+mod impl_net;
+mod impl_nonzero;
 mod impl_tuple;
+mod impl_wrapper;
+mod indexed;
+mod interleaved;
+mod low_discrepancy;
+mod optimize;
+mod partition;
+mod progress;
+mod quantifiers;
+mod range;
+mod ranged;
+mod reversed;
+#[cfg(feature = "schemars")]
+mod schema;
+mod shuffled;
+pub mod size;
+mod sorted;
+mod step_by;
+mod total_float;
+pub mod verify;
 
+pub use adjacent::*;
+pub use all::*;
+pub use ascii::*;
+pub use between::*;
+pub use bits::*;
+pub use cached::*;
+pub use const_values::*;
+pub use diagonal::*;
+pub use dyn_enumerable::*;
 pub use enumerable_derive::*;
+pub use error::*;
+pub use impl_array::*;
 pub use impl_built_in::*;
+#[cfg(feature = "either")]
+pub use impl_either::*;
+pub use impl_net::*;
+pub use impl_nonzero::*;
 pub use impl_tuple::*;
+pub use impl_wrapper::*;
+pub use indexed::*;
+pub use interleaved::*;
+pub use low_discrepancy::*;
+pub use optimize::*;
+pub use partition::*;
+pub use progress::*;
+pub use quantifiers::*;
+pub use range::*;
+pub use ranged::*;
+pub use reversed::*;
+#[cfg(feature = "schemars")]
+pub use schema::*;
+pub use shuffled::*;
+pub use sorted::*;
+pub use step_by::*;
+pub use total_float::*;
 
 #[cfg(test)]
 mod test;
@@ -0,0 +1,78 @@
+//! Enumeration in a low-discrepancy order, spreading early values across the whole enumeration.
+
+use crate::Enumerable;
+use core::marker::PhantomData;
+
+/// The iterator returned by [`Enumerable::enumerator_low_discrepancy`].
+///
+/// It yields all possible values of `T`, visiting indices in bit-reversed order.
+pub struct LowDiscrepancyEnumerator<T: Enumerable> {
+    /// The next position in the bit-reversed sequence, in `0..2^bits`.
+    position: u128,
+    /// The number of bits reversed, `2^bits` is the smallest power of two not less than `size`.
+    bits: u32,
+    /// The number of values of `T`.
+    size: usize,
+    /// The number of values yielded so far.
+    yielded: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Enumerable> LowDiscrepancyEnumerator<T> {
+    /// Creates a new `LowDiscrepancyEnumerator`, yielding the same values in the same order as
+    /// `T::enumerator_low_discrepancy()`.
+    #[inline]
+    pub fn new() -> Self {
+        let size = T::ENUMERABLE_SIZE;
+
+        Self {
+            position: 0,
+            bits: size
+                .checked_next_power_of_two()
+                .map_or(usize::BITS, usize::trailing_zeros),
+            size,
+            yielded: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Reverses the lowest `bits` bits of `position`.
+    #[inline]
+    fn reversed(&self) -> usize {
+        match self.bits {
+            0 => 0,
+            bits => (self.position as usize).reverse_bits() >> (usize::BITS - bits),
+        }
+    }
+}
+
+impl<T: Enumerable> Default for LowDiscrepancyEnumerator<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Enumerable> Iterator for LowDiscrepancyEnumerator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.yielded < self.size {
+            let index = self.reversed();
+            self.position += 1;
+
+            if index < self.size {
+                self.yielded += 1;
+                return T::enumerable_from_index(index);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.size - self.yielded;
+        (remaining, Some(remaining))
+    }
+}
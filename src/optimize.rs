@@ -0,0 +1,69 @@
+//! Exhaustive optimization over all values of [`Enumerable`] types.
+
+use crate::Enumerable;
+use core::cmp::Ordering;
+
+/// Returns the first value of `T` in enumeration order whose key is greater than or equal to the
+/// keys of all other values, or `None` if `T` is uninhabited.
+///
+/// Values whose keys are not comparable to themselves (e.g. NaN) are skipped. If the keys are only
+/// partially ordered otherwise, a key incomparable to the greatest one found so far doesn't replace
+/// it. `None` is also returned if all keys are skipped.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::argmax_by_key;
+///
+/// // `f64::NAN` is skipped, and the first of the two maxima is returned
+/// let score = |x: &u8| match *x {
+///     7 => f64::NAN,
+///     x => -((x as f64) - 100.5).abs(),
+/// };
+/// assert_eq!(argmax_by_key(score), Some(100));
+/// assert_eq!(argmax_by_key(|x: &bool| *x as u8), Some(true));
+/// ```
+pub fn argmax_by_key<T: Enumerable, K: PartialOrd>(f: impl FnMut(&T) -> K) -> Option<T> {
+    first_extreme_by_key(f, Ordering::Greater)
+}
+
+/// Returns the first value of `T` in enumeration order whose key is less than or equal to the keys
+/// of all other values, or `None` if `T` is uninhabited.
+///
+/// Values whose keys are not comparable to themselves (e.g. NaN) are skipped. If the keys are only
+/// partially ordered otherwise, a key incomparable to the least one found so far doesn't replace
+/// it. `None` is also returned if all keys are skipped.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::argmin_by_key;
+///
+/// assert_eq!(argmin_by_key(|x: &i8| (*x as i32 - 3).pow(2)), Some(3));
+/// assert_eq!(argmin_by_key(|x: &Option<bool>| x.is_some()), Some(None));
+/// ```
+pub fn argmin_by_key<T: Enumerable, K: PartialOrd>(f: impl FnMut(&T) -> K) -> Option<T> {
+    first_extreme_by_key(f, Ordering::Less)
+}
+
+/// Returns the first value of `T` whose key compares as `better` to, or equal to, all other keys.
+fn first_extreme_by_key<T: Enumerable, K: PartialOrd>(
+    mut f: impl FnMut(&T) -> K,
+    better: Ordering,
+) -> Option<T> {
+    let mut best: Option<(T, K)> = None;
+
+    for value in T::enumerator() {
+        let key = f(&value);
+        if key.partial_cmp(&key).is_none() {
+            continue;
+        }
+
+        match &best {
+            Some((_, best_key)) if key.partial_cmp(best_key) != Some(better) => {}
+            _ => best = Some((value, key)),
+        }
+    }
+
+    best.map(|(value, _)| value)
+}
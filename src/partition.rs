@@ -0,0 +1,75 @@
+//! Grouping all values of an [`Enumerable`] type by a key.
+
+use crate::Enumerable;
+use alloc::{collections::BTreeMap, vec::Vec};
+
+/// Enumerates all values of `T` and groups them by `key`.
+///
+/// The groups are sorted by their keys, and the values in each group are in enumeration order.
+/// Only keys of at least one value have a group, so the result is empty if `T` has no values. See
+/// [`partition_by_enumerable_key`] for a variant listing every possible key.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::partition_by_key;
+///
+/// let groups = partition_by_key(|x: &(bool, bool)| x.0 as u8 + x.1 as u8);
+/// assert_eq!(
+///     groups,
+///     vec![
+///         (0, vec![(false, false)]),
+///         (1, vec![(false, true), (true, false)]),
+///         (2, vec![(true, true)]),
+///     ]
+/// );
+/// ```
+pub fn partition_by_key<T: Enumerable, K: Ord>(mut key: impl FnMut(&T) -> K) -> Vec<(K, Vec<T>)> {
+    let mut groups = BTreeMap::<K, Vec<T>>::new();
+
+    for value in T::enumerator() {
+        groups.entry(key(&value)).or_default().push(value);
+    }
+
+    groups.into_iter().collect()
+}
+
+/// Enumerates all values of `T` and groups them by `key`, whose type `K` is also `Enumerable`.
+///
+/// Unlike [`partition_by_key`], there is a group for every value of `K`, in enumeration order of
+/// `K`, and groups may be empty. The values in each group are in enumeration order.
+///
+/// Keys are looked up linearly, so it takes O(|T|·|K|) comparisons in the worst case, and a group
+/// is allocated for each value of `K`. It's intended for key types with a few values.
+///
+/// ## Panics
+///
+/// Panics if a key equals none of the values enumerated by `K`, which is only possible if the
+/// `PartialEq` or `Enumerable` implementation of `K` is incorrect.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::partition_by_enumerable_key;
+///
+/// let groups = partition_by_enumerable_key(|x: &bool| if *x { Some(()) } else { None });
+/// assert_eq!(groups, vec![(None, vec![false]), (Some(()), vec![true])]);
+///
+/// let groups = partition_by_enumerable_key(|x: &u8| x.checked_sub(254).map(|_| ()));
+/// assert_eq!(groups[1], (Some(()), vec![254, 255]));
+/// ```
+pub fn partition_by_enumerable_key<T: Enumerable, K: Enumerable + PartialEq>(
+    mut key: impl FnMut(&T) -> K,
+) -> Vec<(K, Vec<T>)> {
+    let mut groups: Vec<(K, Vec<T>)> = K::enumerator().map(|k| (k, Vec::new())).collect();
+
+    for value in T::enumerator() {
+        let k = key(&value);
+        match groups.iter_mut().find(|(group_key, _)| *group_key == k) {
+            Some((_, group)) => group.push(value),
+            None => panic!("the key is not a value enumerated by `K::enumerator()`"),
+        }
+    }
+
+    groups
+}
@@ -0,0 +1,74 @@
+//! Enumeration reporting its progress to a callback.
+
+use crate::Enumerable;
+
+/// The iterator returned by [`Enumerable::enumerator_with_progress`], yielding all possible values
+/// of `T` in enumeration order and reporting the progress to a callback.
+pub struct ProgressEnumerator<T: Enumerable, F: FnMut(usize, Option<usize>)> {
+    inner: T::Enumerator,
+    callback: F,
+    /// The number of values yielded so far.
+    done: usize,
+    /// The number of values yielded between two calls to `callback`.
+    every: usize,
+    /// The number of values left to yield before the next call to `callback`.
+    until_report: usize,
+    /// Whether the enumerator is exhausted and the final progress is reported.
+    finished: bool,
+}
+
+impl<T: Enumerable, F: FnMut(usize, Option<usize>)> ProgressEnumerator<T, F> {
+    /// Creates a new `ProgressEnumerator`, calling `callback` every `every` values, and once more
+    /// when the enumerator is exhausted if the last call is not for the last value.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `every` is 0.
+    #[inline]
+    pub fn new(every: usize, callback: F) -> Self {
+        assert!(every != 0, "`every` must not be 0");
+
+        Self {
+            inner: T::enumerator(),
+            callback,
+            done: 0,
+            every,
+            until_report: every,
+            finished: false,
+        }
+    }
+}
+
+impl<T: Enumerable, F: FnMut(usize, Option<usize>)> Iterator for ProgressEnumerator<T, F> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.next() {
+            Some(value) => {
+                self.done += 1;
+                self.until_report -= 1;
+                if self.until_report == 0 {
+                    self.until_report = self.every;
+                    (self.callback)(self.done, T::ENUMERABLE_SIZE_OPTION);
+                }
+
+                Some(value)
+            }
+            None => {
+                // the final progress is already reported if the last value completes a batch
+                if !self.finished && (self.done == 0 || self.until_report != self.every) {
+                    (self.callback)(self.done, T::ENUMERABLE_SIZE_OPTION);
+                }
+
+                self.finished = true;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
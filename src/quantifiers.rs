@@ -0,0 +1,85 @@
+//! Quantifiers over all values of [`Enumerable`] types.
+
+use crate::Enumerable;
+
+/// Returns whether `pred` holds for all values of `T`.
+///
+/// It stops at the first value for which `pred` returns `false`. It's `true` if `T` is
+/// uninhabited, as there is no counterexample.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::forall;
+///
+/// assert!(forall(|x: u8| x.checked_add(1).is_some() || x == u8::MAX));
+/// assert!(!forall(|x: bool| x));
+/// ```
+pub fn forall<T: Enumerable>(pred: impl FnMut(T) -> bool) -> bool {
+    T::enumerator().all(pred)
+}
+
+/// Returns whether `pred` holds for at least one value of `T`.
+///
+/// It stops at the first value for which `pred` returns `true`. It's `false` if `T` is
+/// uninhabited, as there is no witness.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::exists;
+///
+/// assert!(exists(|x: u8| x.count_ones() == 8));
+/// assert!(!exists(|x: Option<bool>| x == Some(true) && x.is_none()));
+/// ```
+pub fn exists<T: Enumerable>(pred: impl FnMut(T) -> bool) -> bool {
+    T::enumerator().any(pred)
+}
+
+/// Returns the number of values of `T` for which `pred` holds.
+///
+/// It's 0 if `T` is uninhabited. `pred` is called for every value, as counting can't stop early.
+///
+/// ## Panics
+///
+/// Panics if the count exceeds `usize::MAX`. Use [`count_where_u128`] for types with more values.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::count_where;
+///
+/// assert_eq!(count_where(|x: u8| x % 3 == 0), 86);
+/// assert_eq!(count_where(|(a, b): (bool, bool)| a != b), 2);
+/// ```
+pub fn count_where<T: Enumerable>(mut pred: impl FnMut(T) -> bool) -> usize {
+    T::enumerator().fold(0usize, |count, value| {
+        if pred(value) {
+            count.checked_add(1).expect("count exceeds `usize::MAX`")
+        } else {
+            count
+        }
+    })
+}
+
+/// Returns the number of values of `T` for which `pred` holds, or `None` if it exceeds
+/// `u128::MAX`.
+///
+/// It's the checked variant of [`count_where`] for types with more than `usize::MAX` values.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::count_where_u128;
+///
+/// assert_eq!(count_where_u128(|x: u16| x < 1000), Some(1000));
+/// ```
+pub fn count_where_u128<T: Enumerable>(mut pred: impl FnMut(T) -> bool) -> Option<u128> {
+    T::enumerator().try_fold(0u128, |count, value| {
+        if pred(value) {
+            count.checked_add(1)
+        } else {
+            Some(count)
+        }
+    })
+}
@@ -0,0 +1,73 @@
+//! Enumeration of a window of values by their indices.
+
+use crate::Enumerable;
+
+/// The iterator returned by [`Enumerable::enumerator_range`], yielding the values at indices
+/// `start..end` in enumeration order.
+pub struct RangeEnumerator<T: Enumerable> {
+    inner: T::Enumerator,
+    /// The number of values left to yield, or an upper bound of it if the size of `T` is unknown.
+    remaining: usize,
+    /// Whether `remaining` is exact.
+    exact: bool,
+}
+
+impl<T: Enumerable> RangeEnumerator<T> {
+    /// Creates a new `RangeEnumerator`, yielding the same values in the same order as
+    /// `T::enumerator_range(start, end)`.
+    #[inline]
+    pub fn new(start: usize, end: usize) -> Self {
+        let requested = end.saturating_sub(start);
+        let (remaining, exact) = match T::ENUMERABLE_SIZE_OPTION_U128 {
+            Some(size) => {
+                let available = size.saturating_sub(start as u128);
+                (
+                    requested.min(available.try_into().unwrap_or(usize::MAX)),
+                    true,
+                )
+            }
+            None => (requested, false),
+        };
+
+        // don't bother positioning the enumerator if nothing is to be yielded
+        let inner = if remaining == 0 {
+            T::enumerator()
+        } else {
+            T::enumerator_since(start)
+        };
+
+        Self {
+            inner,
+            remaining,
+            exact,
+        }
+    }
+}
+
+impl<T: Enumerable> Iterator for RangeEnumerator<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.exact {
+            (self.remaining, Some(self.remaining))
+        } else {
+            let (lower, upper) = self.inner.size_hint();
+            let upper = match upper {
+                Some(upper) => upper.min(self.remaining),
+                None => self.remaining,
+            };
+            (lower.min(self.remaining), Some(upper))
+        }
+    }
+}
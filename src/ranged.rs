@@ -0,0 +1,532 @@
+//! Integers and characters bounded by a const-generic inclusive range, so that e.g. a field only
+//! enumerates `1..=6` instead of all 256 values of `u8`.
+
+use crate::{
+    size_option_from_u128, CharEnumerator, Enumerable, ExactSizeEnumerable, OrderedEnumerable,
+    ReversedEnumerator, StepByEnumerator,
+};
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::RangeInclusive;
+
+/// Macro to define an integer type bounded by const-generic inclusive bounds, and implement the
+/// `Enumerable` trait for it. `$unsigned` is the unsigned type of the same width, used to compute
+/// indices.
+macro_rules! define_ranged_integer_type {
+    ($name:ident, $enumerator:ident, $ty:ident, $unsigned:ident) => {
+        #[doc = concat!("A [`", stringify!($ty), "`] in the inclusive range `MIN..=MAX`, enumerating only the values in the range.")]
+        ///
+        /// It's handy as a field of types deriving `Enumerable`, to limit the enumeration of the
+        /// field to a few values. Using the type with `MIN > MAX` is a compile-time error.
+        ///
+        /// ## Example
+        ///
+        /// ```rust
+        #[doc = concat!("use enumerable::{Enumerable, ", stringify!($name), "};")]
+        ///
+        #[doc = concat!("let mut iter = ", stringify!($name), "::<1, 3>::enumerator().map(", stringify!($name), "::get);")]
+        /// assert_eq!(iter.next(), Some(1));
+        /// assert_eq!(iter.next(), Some(2));
+        /// assert_eq!(iter.next(), Some(3));
+        /// assert_eq!(iter.next(), None);
+        ///
+        #[doc = concat!("assert_eq!(", stringify!($name), "::<1, 3>::new(0), None);")]
+        /// ```
+        ///
+        /// ```rust,compile_fail
+        #[doc = concat!("use enumerable::", stringify!($name), ";")]
+        ///
+        #[doc = concat!("let _ = ", stringify!($name), "::<3, 1>::new(2);")]
+        /// ```
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name<const MIN: $ty, const MAX: $ty>($ty);
+
+        impl<const MIN: $ty, const MAX: $ty> $name<MIN, MAX> {
+            /// Fails to compile if the range is empty.
+            const VALID: () = assert!(MIN <= MAX, "the range `MIN..=MAX` must not be empty");
+
+            /// The number of values in the range.
+            const COUNT: u128 = {
+                let () = Self::VALID;
+                (MAX as $unsigned).wrapping_sub(MIN as $unsigned) as u128 + 1
+            };
+
+            /// Returns `value` bounded by the range, or `None` if it's out of the range.
+            #[inline]
+            pub const fn new(value: $ty) -> Option<Self> {
+                let () = Self::VALID;
+                if MIN <= value && value <= MAX {
+                    Some(Self(value))
+                } else {
+                    None
+                }
+            }
+
+            #[doc = concat!("Returns the value as a `", stringify!($ty), "`.")]
+            #[inline]
+            pub const fn get(self) -> $ty {
+                self.0
+            }
+
+            /// The offset of this value from `MIN`.
+            #[inline]
+            fn index(self) -> $unsigned {
+                (self.0 as $unsigned).wrapping_sub(MIN as $unsigned)
+            }
+
+            /// The value at the offset `index` from `MIN`, which must be less than the size.
+            #[inline]
+            fn from_index(index: $unsigned) -> Self {
+                Self((MIN as $unsigned).wrapping_add(index) as $ty)
+            }
+        }
+
+        impl<const MIN: $ty, const MAX: $ty> From<$name<MIN, MAX>> for $ty {
+            #[inline]
+            fn from(value: $name<MIN, MAX>) -> Self {
+                value.get()
+            }
+        }
+
+        impl<const MIN: $ty, const MAX: $ty> fmt::Display for $name<MIN, MAX> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        #[doc = concat!("`", stringify!($enumerator), "` is an iterator over possible values of [`", stringify!($name), "`].")]
+        ///
+        /// It yields all values from `MIN` to `MAX` in ascending order.
+        pub struct $enumerator<const MIN: $ty, const MAX: $ty> {
+            inner: RangeInclusive<$ty>,
+        }
+
+        impl<const MIN: $ty, const MAX: $ty> $enumerator<MIN, MAX> {
+            /// Creates a new enumerator, yielding the same values in the same order as
+            #[doc = concat!("`<", stringify!($name), "<MIN, MAX> as Enumerable>::enumerator()`.")]
+            #[inline]
+            pub fn new() -> Self {
+                let () = $name::<MIN, MAX>::VALID;
+                Self { inner: MIN..=MAX }
+            }
+
+            /// Creates an enumerator which yields nothing.
+            #[inline]
+            fn exhausted() -> Self {
+                let mut inner = MAX..=MAX;
+                inner.next();
+                Self { inner }
+            }
+        }
+
+        impl<const MIN: $ty, const MAX: $ty> Default for $enumerator<MIN, MAX> {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<const MIN: $ty, const MAX: $ty> Iterator for $enumerator<MIN, MAX> {
+            type Item = $name<MIN, MAX>;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next().map($name)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.inner.nth(n).map($name)
+            }
+        }
+
+        impl<const MIN: $ty, const MAX: $ty> DoubleEndedIterator for $enumerator<MIN, MAX> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back().map($name)
+            }
+        }
+
+        impl<const MIN: $ty, const MAX: $ty> FusedIterator for $enumerator<MIN, MAX> {}
+
+        #[doc = concat!("This is an implementation of the `Enumerable` trait for [`", stringify!($name), "`].")]
+        ///
+        /// Values are enumerated from `MIN` to `MAX` in ascending order, and looked up by index in
+        /// O(1) time.
+        #[automatically_derived]
+        impl<const MIN: $ty, const MAX: $ty> Enumerable for $name<MIN, MAX> {
+            type Enumerator = $enumerator<MIN, MAX>;
+
+            #[inline]
+            fn enumerator() -> Self::Enumerator {
+                $enumerator::new()
+            }
+
+            #[inline]
+            fn first_value() -> Option<Self> {
+                Self::new(MIN)
+            }
+
+            #[inline]
+            fn last_value() -> Option<Self> {
+                Self::new(MAX)
+            }
+
+            #[inline]
+            fn next_value_direct(self) -> Option<Option<Self>> {
+                Some(if self.0 < MAX {
+                    Some(Self(self.0 + 1))
+                } else {
+                    None
+                })
+            }
+
+            #[inline]
+            fn previous_value_direct(self) -> Option<Option<Self>> {
+                Some(if self.0 > MIN {
+                    Some(Self(self.0 - 1))
+                } else {
+                    None
+                })
+            }
+
+            /// Returns an iterator over all possible values of this type starting from the
+            /// `start`-th one, positioned in O(1) time.
+            #[inline]
+            fn enumerator_since(start: usize) -> Self::Enumerator {
+                match Self::enumerable_from_index(start) {
+                    Some(value) => $enumerator {
+                        inner: value.0..=MAX,
+                    },
+                    None => $enumerator::exhausted(),
+                }
+            }
+
+            #[inline]
+            fn enumerable_from_index(index: usize) -> Option<Self> {
+                Self::enumerable_from_index_u128(index as u128)
+            }
+
+            #[inline]
+            fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+                if index < Self::COUNT {
+                    Some(Self::from_index(index as $unsigned))
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+                Some(value.index() as u128)
+            }
+
+            #[inline]
+            fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+                Some($enumerator {
+                    inner: value.0..=MAX,
+                })
+            }
+
+            #[inline]
+            fn enumerator_reversed() -> ReversedEnumerator<Self> {
+                ReversedEnumerator::from_double_ended()
+            }
+
+            #[inline]
+            fn enumerator_step_by(start: usize, step: usize) -> StepByEnumerator<Self> {
+                StepByEnumerator::by_index(start, step)
+            }
+
+            const ENUMERABLE_SIZE_OPTION: Option<usize> =
+                size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+            const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some(Self::COUNT);
+        }
+
+        impl<const MIN: $ty, const MAX: $ty> OrderedEnumerable for $name<MIN, MAX> {}
+    };
+}
+
+/// Macro to implement the `ExactSizeEnumerable` trait for bounded integer types, whose enumerators
+/// wrap `RangeInclusive`s which are `ExactSizeIterator`s.
+macro_rules! impl_exact_size_enumerable_for_ranged_integer_types {
+    ($($name:ident, $enumerator:ident, $ty:ident);+ $(;)?) => {
+        $(
+            impl<const MIN: $ty, const MAX: $ty> ExactSizeIterator for $enumerator<MIN, MAX> {}
+
+            impl<const MIN: $ty, const MAX: $ty> ExactSizeEnumerable for $name<MIN, MAX> {
+                type ExactSizeEnumerator = $enumerator<MIN, MAX>;
+            }
+        )+
+    };
+}
+
+define_ranged_integer_type!(RangedU8, RangedU8Enumerator, u8, u8);
+define_ranged_integer_type!(RangedU16, RangedU16Enumerator, u16, u16);
+define_ranged_integer_type!(RangedU32, RangedU32Enumerator, u32, u32);
+define_ranged_integer_type!(RangedU64, RangedU64Enumerator, u64, u64);
+define_ranged_integer_type!(RangedI8, RangedI8Enumerator, i8, u8);
+define_ranged_integer_type!(RangedI16, RangedI16Enumerator, i16, u16);
+define_ranged_integer_type!(RangedI32, RangedI32Enumerator, i32, u32);
+define_ranged_integer_type!(RangedI64, RangedI64Enumerator, i64, u64);
+
+// like the primitive integers, only ranges of narrow types are always short enough for `usize`
+impl_exact_size_enumerable_for_ranged_integer_types!(
+    RangedU8, RangedU8Enumerator, u8;
+    RangedU16, RangedU16Enumerator, u16;
+    RangedI8, RangedI8Enumerator, i8;
+    RangedI16, RangedI16Enumerator, i16;
+);
+
+/// Returns the index of `c` among all `char`s, skipping the surrogate code points.
+#[inline]
+const fn char_index(c: char) -> u32 {
+    match c as u32 {
+        code @ ..=0xD7FF => code,
+        code => code - (0xE000 - 0xD800),
+    }
+}
+
+/// A [`char`] in the inclusive range `MIN..=MAX`, enumerating only the Unicode scalar values in
+/// the range.
+///
+/// Like `char`, the surrogate code points are skipped if the range spans them. It's handy as a
+/// field of types deriving `Enumerable`, to limit the enumeration of the field to e.g. lowercase
+/// letters. Using the type with `MIN > MAX` is a compile-time error.
+///
+/// ## Example
+///
+/// ```rust
+/// use enumerable::{Enumerable, RangedChar};
+///
+/// type Lowercase = RangedChar<'a', 'z'>;
+///
+/// let letters = Lowercase::enumerator().map(Lowercase::get).collect::<String>();
+/// assert_eq!(letters, "abcdefghijklmnopqrstuvwxyz");
+/// assert_eq!(Lowercase::new('A'), None);
+/// ```
+///
+/// ```rust,compile_fail
+/// use enumerable::RangedChar;
+///
+/// let _ = RangedChar::<'z', 'a'>::new('m');
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RangedChar<const MIN: char, const MAX: char>(char);
+
+impl<const MIN: char, const MAX: char> RangedChar<MIN, MAX> {
+    /// Fails to compile if the range is empty.
+    const VALID: () = assert!(
+        MIN as u32 <= MAX as u32,
+        "the range `MIN..=MAX` must not be empty"
+    );
+
+    /// The index of `MIN` among all `char`s.
+    const MIN_INDEX: u32 = {
+        let () = Self::VALID;
+        char_index(MIN)
+    };
+
+    /// One past the index of `MAX` among all `char`s.
+    const END_INDEX: u32 = char_index(MAX) + 1;
+
+    /// Returns `value` bounded by the range, or `None` if it's out of the range.
+    #[inline]
+    pub const fn new(value: char) -> Option<Self> {
+        let () = Self::VALID;
+        if MIN as u32 <= value as u32 && value as u32 <= MAX as u32 {
+            Some(Self(value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value as a `char`.
+    #[inline]
+    pub const fn get(self) -> char {
+        self.0
+    }
+
+    /// Creates an enumerator over the values from the index `start` on, relative to `MIN`.
+    #[inline]
+    fn enumerator_since_index(start: u128) -> RangedCharEnumerator<MIN, MAX> {
+        let count = Self::END_INDEX - Self::MIN_INDEX;
+        let start = u32::try_from(start).map_or(count, |start| start.min(count));
+        RangedCharEnumerator {
+            inner: CharEnumerator::with_indices(Self::MIN_INDEX + start, Self::END_INDEX),
+        }
+    }
+}
+
+impl<const MIN: char, const MAX: char> From<RangedChar<MIN, MAX>> for char {
+    #[inline]
+    fn from(value: RangedChar<MIN, MAX>) -> Self {
+        value.get()
+    }
+}
+
+impl<const MIN: char, const MAX: char> fmt::Display for RangedChar<MIN, MAX> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// `RangedCharEnumerator` is an iterator over possible values of [`RangedChar`].
+///
+/// It yields all Unicode scalar values from `MIN` to `MAX` in ascending order, skipping the
+/// surrogate code points.
+pub struct RangedCharEnumerator<const MIN: char, const MAX: char> {
+    inner: CharEnumerator,
+}
+
+impl<const MIN: char, const MAX: char> RangedCharEnumerator<MIN, MAX> {
+    /// Creates a new enumerator, yielding the same values in the same order as
+    /// `<RangedChar<MIN, MAX> as Enumerable>::enumerator()`.
+    #[inline]
+    pub fn new() -> Self {
+        RangedChar::<MIN, MAX>::enumerator_since_index(0)
+    }
+}
+
+impl<const MIN: char, const MAX: char> Default for RangedCharEnumerator<MIN, MAX> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const MIN: char, const MAX: char> Iterator for RangedCharEnumerator<MIN, MAX> {
+    type Item = RangedChar<MIN, MAX>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(RangedChar)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(RangedChar)
+    }
+}
+
+impl<const MIN: char, const MAX: char> DoubleEndedIterator for RangedCharEnumerator<MIN, MAX> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(RangedChar)
+    }
+}
+
+impl<const MIN: char, const MAX: char> ExactSizeIterator for RangedCharEnumerator<MIN, MAX> {}
+
+impl<const MIN: char, const MAX: char> FusedIterator for RangedCharEnumerator<MIN, MAX> {}
+
+/// This is an implementation of the `Enumerable` trait for [`RangedChar`].
+///
+/// Values are enumerated from `MIN` to `MAX` in ascending order, skipping the surrogate code
+/// points, and looked up by index in O(1) time.
+#[automatically_derived]
+impl<const MIN: char, const MAX: char> Enumerable for RangedChar<MIN, MAX> {
+    type Enumerator = RangedCharEnumerator<MIN, MAX>;
+
+    #[inline]
+    fn enumerator() -> Self::Enumerator {
+        RangedCharEnumerator::new()
+    }
+
+    #[inline]
+    fn first_value() -> Option<Self> {
+        Self::new(MIN)
+    }
+
+    #[inline]
+    fn last_value() -> Option<Self> {
+        Self::new(MAX)
+    }
+
+    #[inline]
+    fn next_value_direct(self) -> Option<Option<Self>> {
+        Some(if self.0 < MAX {
+            self.0.next_value().map(Self)
+        } else {
+            None
+        })
+    }
+
+    #[inline]
+    fn previous_value_direct(self) -> Option<Option<Self>> {
+        Some(if self.0 > MIN {
+            self.0.previous_value().map(Self)
+        } else {
+            None
+        })
+    }
+
+    /// Returns an iterator over all possible values of this type starting from the `start`-th
+    /// one, positioned in O(1) time.
+    #[inline]
+    fn enumerator_since(start: usize) -> Self::Enumerator {
+        Self::enumerator_since_index(start as u128)
+    }
+
+    #[inline]
+    fn enumerable_from_index(index: usize) -> Option<Self> {
+        Self::enumerable_from_index_u128(index as u128)
+    }
+
+    #[inline]
+    fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+        let index = u32::try_from(index).ok()?.checked_add(Self::MIN_INDEX)?;
+        if index < Self::END_INDEX {
+            char::enumerable_from_index_u128(index as u128).map(Self)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+        Some((char_index(value.0) - Self::MIN_INDEX) as u128)
+    }
+
+    #[inline]
+    fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+        Some(Self::enumerator_since_index(
+            (char_index(value.0) - Self::MIN_INDEX) as u128,
+        ))
+    }
+
+    #[inline]
+    fn enumerator_reversed() -> ReversedEnumerator<Self> {
+        ReversedEnumerator::from_double_ended()
+    }
+
+    #[inline]
+    fn enumerator_step_by(start: usize, step: usize) -> StepByEnumerator<Self> {
+        StepByEnumerator::by_index(start, step)
+    }
+
+    const ENUMERABLE_SIZE_OPTION: Option<usize> =
+        Some((Self::END_INDEX - Self::MIN_INDEX) as usize);
+
+    const ENUMERABLE_SIZE_OPTION_U128: Option<u128> =
+        Some((Self::END_INDEX - Self::MIN_INDEX) as u128);
+}
+
+impl<const MIN: char, const MAX: char> ExactSizeEnumerable for RangedChar<MIN, MAX> {
+    type ExactSizeEnumerator = RangedCharEnumerator<MIN, MAX>;
+}
+
+impl<const MIN: char, const MAX: char> OrderedEnumerable for RangedChar<MIN, MAX> {}
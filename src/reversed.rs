@@ -0,0 +1,173 @@
+//! Enumeration in reverse order, from the last value to the first one.
+
+use crate::{Enumerable, ExactSizeEnumerable};
+
+/// The iterator returned by [`Enumerable::enumerator_reversed`], yielding all possible values of
+/// `T` in exactly the reverse order of [`Enumerable::enumerator`].
+pub struct ReversedEnumerator<T: Enumerable> {
+    inner: Inner<T>,
+}
+
+enum Inner<T: Enumerable> {
+    /// Values looked up by [`Enumerable::enumerable_from_index_u128`], counting down the indices.
+    Indexed {
+        /// The index of the next value to yield, which is also the number of values left minus 1,
+        /// or `None` if exhausted. Types with exactly `u128::MAX + 1` values start from
+        /// `u128::MAX`.
+        next: Option<u128>,
+    },
+    /// The enumerator of `T`, consumed from the back by `DoubleEndedIterator::next_back` and
+    /// `DoubleEndedIterator::nth_back`.
+    DoubleEnded {
+        inner: T::Enumerator,
+        next_back: fn(&mut T::Enumerator) -> Option<T>,
+        nth_back: fn(&mut T::Enumerator, usize) -> Option<T>,
+    },
+}
+
+impl<T: Enumerable> ReversedEnumerator<T> {
+    /// Creates a new `ReversedEnumerator`, looking up values by
+    /// [`Enumerable::enumerable_from_index_u128`] from the last index down to 0.
+    ///
+    /// It's as efficient as `enumerable_from_index_u128` for each value, including for types with
+    /// more than `usize::MAX` values. If the size of `T` is unknown even as a `u128`, it starts
+    /// from the index of the last value if it's computed directly as in
+    /// [`Enumerable::enumerable_to_index`], or the values are counted by stepping through the
+    /// enumerator once first otherwise.
+    #[inline]
+    pub fn new() -> Self {
+        if let Some(size) = T::ENUMERABLE_SIZE_OPTION_U128 {
+            return Self::with_remaining(size);
+        }
+
+        if !T::IS_INHABITED {
+            return Self::with_remaining(0);
+        }
+
+        match T::last_value().and_then(|last| T::enumerable_to_index_direct(&last)) {
+            Some(last_index) => Self::with_next_index(Some(last_index)),
+            None => Self::with_remaining(T::enumerator().fold(0, |count, _| count + 1)),
+        }
+    }
+
+    /// Creates a new `ReversedEnumerator` yielding the values at indices `remaining - 1` down to 0,
+    /// looked up by [`Enumerable::enumerable_from_index_u128`].
+    #[inline]
+    pub(crate) fn with_remaining(remaining: u128) -> Self {
+        Self::with_next_index(remaining.checked_sub(1))
+    }
+
+    /// Creates a new `ReversedEnumerator` yielding the values at indices `next` down to 0, or
+    /// nothing if `next` is `None`.
+    #[inline]
+    fn with_next_index(next: Option<u128>) -> Self {
+        Self {
+            inner: Inner::Indexed { next },
+        }
+    }
+
+    /// Converts into the reversed enumerator of `W`, a transparent wrapper of `T` with the wrapped
+    /// values at the same indices, leaving the same values to yield.
+    ///
+    /// The enumerator of `W` can't be consumed from the back without `T::Enumerator:
+    /// DoubleEndedIterator`, so enumerators consumed from the back are converted to index lookups,
+    /// starting from the index of the next value computed by
+    /// `Enumerable::enumerable_to_index_direct`, or counting the values left if that's `None`.
+    pub(crate) fn into_wrapper<W: Enumerable>(self) -> ReversedEnumerator<W> {
+        let (mut inner, next_back) = match self.inner {
+            Inner::Indexed { next } => return ReversedEnumerator::with_next_index(next),
+            Inner::DoubleEnded {
+                inner, next_back, ..
+            } => (inner, next_back),
+        };
+
+        let Some(next) = next_back(&mut inner) else {
+            return ReversedEnumerator::with_remaining(0);
+        };
+
+        match T::enumerable_to_index_direct(&next) {
+            Some(index) => ReversedEnumerator::with_next_index(Some(index)),
+            None => ReversedEnumerator::with_remaining(
+                core::iter::from_fn(|| next_back(&mut inner)).fold(1, |count, _| count + 1),
+            ),
+        }
+    }
+
+    /// Creates a new `ReversedEnumerator`, consuming the enumerator of `T` from the back.
+    ///
+    /// It's available for all types whose enumerators implement [`DoubleEndedIterator`], and is
+    /// what built-in implementations, derived field-less enums and derived unit structs use.
+    #[inline]
+    pub fn from_double_ended() -> Self
+    where
+        T::Enumerator: DoubleEndedIterator,
+    {
+        Self {
+            inner: Inner::DoubleEnded {
+                inner: T::enumerator(),
+                next_back: DoubleEndedIterator::next_back,
+                nth_back: DoubleEndedIterator::nth_back,
+            },
+        }
+    }
+}
+
+impl<T: Enumerable> Default for ReversedEnumerator<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Enumerable> Iterator for ReversedEnumerator<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            Inner::Indexed { next } => {
+                let index = (*next)?;
+                *next = index.checked_sub(1);
+                T::enumerable_from_index_u128(index)
+            }
+            Inner::DoubleEnded {
+                inner, next_back, ..
+            } => next_back(inner),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match &mut self.inner {
+            Inner::Indexed { next } => {
+                let index = next.and_then(|next| next.checked_sub(n as u128));
+                *next = index.and_then(|index| index.checked_sub(1));
+                T::enumerable_from_index_u128(index?)
+            }
+            Inner::DoubleEnded {
+                inner, nth_back, ..
+            } => nth_back(inner, n),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Inner::Indexed { next: None } => (0, Some(0)),
+            Inner::Indexed { next: Some(next) } => {
+                match usize::try_from(*next)
+                    .ok()
+                    .and_then(|next| next.checked_add(1))
+                {
+                    Some(remaining) => (remaining, Some(remaining)),
+                    None => (usize::MAX, None),
+                }
+            }
+            Inner::DoubleEnded { inner, .. } => inner.size_hint(),
+        }
+    }
+}
+
+// the number of values left is exact when looking up values by index, and the enumerator is exact
+// when consumed from the back
+impl<T: ExactSizeEnumerable> ExactSizeIterator for ReversedEnumerator<T> {}
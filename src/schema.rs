@@ -0,0 +1,61 @@
+//! JSON Schema generation for [`Enumerable`] types, enabled by the `schemars` feature.
+
+use crate::Enumerable;
+use schemars::{json_schema, Schema, SchemaGenerator};
+use serde::Serialize;
+
+/// The default limit of [`json_schema_for`] on the number of values listed in a schema.
+pub const DEFAULT_SCHEMA_ENUM_LIMIT: usize = 256;
+
+/// Generates a JSON Schema whose `enum` lists the serialized form of every value of `T`.
+///
+/// If `T` has more than [`DEFAULT_SCHEMA_ENUM_LIMIT`] values, an unconstrained schema (`true`) is
+/// returned instead. Use [`json_schema_for_with_limit`] to choose another limit.
+///
+/// The signature fits `#[schemars(schema_with = "...")]`, so it can be used directly on fields of
+/// types deriving `JsonSchema`.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::{json_schema_for, Enumerable};
+/// use schemars::{json_schema, SchemaGenerator};
+/// use serde::Serialize;
+///
+/// #[derive(Copy, Clone, Enumerable, Serialize)]
+/// #[serde(rename_all = "lowercase")]
+/// enum Level {
+///     Low,
+///     High,
+/// }
+///
+/// let schema = json_schema_for::<Option<Level>>(&mut SchemaGenerator::default());
+/// assert_eq!(schema, json_schema!({ "enum": [null, "low", "high"] }));
+/// ```
+pub fn json_schema_for<T: Enumerable + Serialize>(generator: &mut SchemaGenerator) -> Schema {
+    json_schema_for_with_limit::<T>(generator, DEFAULT_SCHEMA_ENUM_LIMIT)
+}
+
+/// Generates a JSON Schema whose `enum` lists the serialized form of every value of `T`, or an
+/// unconstrained schema (`true`) if `T` has more than `limit` values.
+///
+/// The size of `T` is read from [`Enumerable::ENUMERABLE_SIZE_OPTION`], so no value is serialized
+/// if the limit is exceeded. An unconstrained schema is also returned if any value fails to
+/// serialize.
+pub fn json_schema_for_with_limit<T: Enumerable + Serialize>(
+    _generator: &mut SchemaGenerator,
+    limit: usize,
+) -> Schema {
+    match T::ENUMERABLE_SIZE_OPTION {
+        Some(size) if size <= limit => {}
+        _ => return Schema::default(),
+    }
+
+    match T::enumerator()
+        .map(serde_json::to_value)
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(values) => json_schema!({ "enum": values }),
+        Err(_) => Schema::default(),
+    }
+}
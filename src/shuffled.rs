@@ -0,0 +1,100 @@
+//! Enumeration in a pseudo-random order, determined by a seed.
+
+use crate::Enumerable;
+use core::marker::PhantomData;
+
+/// The number of rounds of the Feistel network.
+const ROUNDS: usize = 4;
+
+/// The iterator returned by [`Enumerable::enumerator_shuffled`].
+///
+/// It yields all possible values of `T`, visiting indices permuted by a Feistel network over the
+/// smallest domain of `2^(2 * half_bits)` indices covering all values, and skipping permuted
+/// indices out of range.
+pub struct ShuffledEnumerator<T: Enumerable> {
+    /// The next position in the domain to permute.
+    position: u64,
+    /// The number of bits of each half of an index in the Feistel network.
+    half_bits: u32,
+    /// The round keys derived from the seed.
+    keys: [u64; ROUNDS],
+    /// The number of values of `T`.
+    size: usize,
+    /// The number of values yielded so far.
+    yielded: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Enumerable> ShuffledEnumerator<T> {
+    /// Creates a new `ShuffledEnumerator`, yielding the same values in the same order as
+    /// `T::enumerator_shuffled(seed)`.
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        let size = T::ENUMERABLE_SIZE;
+        let bits = size
+            .checked_next_power_of_two()
+            .map_or(usize::BITS, usize::trailing_zeros);
+
+        let mut state = seed;
+        let keys = core::array::from_fn(|_| {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            mix(state)
+        });
+
+        Self {
+            position: 0,
+            half_bits: bits.div_ceil(2),
+            keys,
+            size,
+            yielded: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Permutes `position` within the domain of `2^(2 * half_bits)` indices.
+    #[inline]
+    fn permuted(&self, position: u64) -> u64 {
+        let mask = (1u64 << self.half_bits) - 1;
+        let (mut left, mut right) = (position >> self.half_bits, position & mask);
+
+        for key in self.keys {
+            (left, right) = (right, left ^ (mix(right ^ key) & mask));
+        }
+
+        (left << self.half_bits) | right
+    }
+}
+
+impl<T: Enumerable> Iterator for ShuffledEnumerator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.yielded < self.size {
+            let index = self.permuted(self.position);
+            // it wraps only after the last position of a domain of `2^64` indices
+            self.position = self.position.wrapping_add(1);
+
+            // at most 3 of 4 indices are skipped, as the domain is less than 4 times the size
+            if index < self.size as u64 {
+                self.yielded += 1;
+                return T::enumerable_from_index(index as usize);
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.size - self.yielded;
+        (remaining, Some(remaining))
+    }
+}
+
+/// The finalizer of SplitMix64, mixing the bits of `x` thoroughly.
+#[inline]
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
@@ -0,0 +1,235 @@
+//! Checked arithmetic on the sizes of [`Enumerable`](crate::Enumerable) types, for manual
+//! implementations.
+//!
+//! The number of values of a struct or tuple is the product of the numbers of values of its
+//! fields, and that of an enum is the sum over its variants. These functions compute them in
+//! constant contexts with the same semantics as `#[derive(Enumerable)]`, so that
+//! [`ENUMERABLE_SIZE_OPTION`](crate::Enumerable::ENUMERABLE_SIZE_OPTION) and
+//! [`ENUMERABLE_SIZE_OPTION_U128`](crate::Enumerable::ENUMERABLE_SIZE_OPTION_U128) can be defined
+//! without hand-rolling them.
+//!
+//! ## Example
+//!
+//! ```
+//! use enumerable::{size, Enumerable, Tuple2Enumerator};
+//!
+//! /// A screen position, on one of two screens if any.
+//! #[derive(Copy, Clone, Debug, PartialEq)]
+//! struct Position {
+//!     screen: Option<bool>,
+//!     column: u8,
+//! }
+//!
+//! impl Enumerable for Position {
+//!     type Enumerator =
+//!         core::iter::Map<Tuple2Enumerator<Option<bool>, u8>, fn((Option<bool>, u8)) -> Position>;
+//!
+//!     fn enumerator() -> Self::Enumerator {
+//!         Tuple2Enumerator::new().map(|(screen, column)| Position { screen, column })
+//!     }
+//!
+//!     const ENUMERABLE_SIZE_OPTION: Option<usize> = size::product(&[
+//!         <Option<bool>>::ENUMERABLE_SIZE_OPTION,
+//!         u8::ENUMERABLE_SIZE_OPTION,
+//!     ]);
+//!
+//!     const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = size::product_u128(&[
+//!         <Option<bool>>::ENUMERABLE_SIZE_OPTION_U128,
+//!         u8::ENUMERABLE_SIZE_OPTION_U128,
+//!     ]);
+//! }
+//!
+//! assert_eq!(Position::ENUMERABLE_SIZE_OPTION, Some(3 * 256));
+//! assert_eq!(Position::enumerator().count(), 3 * 256);
+//! ```
+
+/// Returns the product of `sizes`, the number of values of a product type whose fields have
+/// `sizes` values, or `None` if it's unknown or exceeds `usize::MAX`.
+///
+/// It's `Some(0)` if any size is `Some(0)`, even if other sizes are unknown, as a product with an
+/// uninhabited field is uninhabited. Otherwise, it's `None` if any size is `None`. The product of
+/// no sizes is `Some(1)`.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::size::product;
+///
+/// assert_eq!(product(&[Some(2), Some(3)]), Some(6));
+/// assert_eq!(product(&[None, Some(0)]), Some(0));
+/// assert_eq!(product(&[None, Some(2)]), None);
+/// assert_eq!(product(&[Some(usize::MAX), Some(2)]), None);
+/// assert_eq!(product(&[]), Some(1));
+/// ```
+pub const fn product(sizes: &[Option<usize>]) -> Option<usize> {
+    let mut result = Some(1usize);
+    let mut i = 0;
+    while i < sizes.len() {
+        result = match (result, sizes[i]) {
+            (_, Some(0)) => return Some(0),
+            (Some(result), Some(size)) => result.checked_mul(size),
+            _ => None,
+        };
+        i += 1;
+    }
+
+    result
+}
+
+/// Returns the product of `sizes` like [`product`], or `None` if it's unknown or exceeds
+/// `u128::MAX`.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::size::product_u128;
+///
+/// assert_eq!(product_u128(&[Some(1 << 64), Some(1 << 32)]), Some(1 << 96));
+/// assert_eq!(product_u128(&[Some(1 << 64), Some(1 << 64)]), None);
+/// ```
+pub const fn product_u128(sizes: &[Option<u128>]) -> Option<u128> {
+    let mut result = Some(1u128);
+    let mut i = 0;
+    while i < sizes.len() {
+        result = match (result, sizes[i]) {
+            (_, Some(0)) => return Some(0),
+            (Some(result), Some(size)) => result.checked_mul(size),
+            _ => None,
+        };
+        i += 1;
+    }
+
+    result
+}
+
+/// Returns the sum of `sizes`, the number of values of a sum type whose variants have `sizes`
+/// values, or `None` if it's unknown or exceeds `usize::MAX`.
+///
+/// It's `None` if any size is `None`. The sum of no sizes is `Some(0)`.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::size::sum;
+///
+/// assert_eq!(sum(&[Some(1), Some(256)]), Some(257));
+/// assert_eq!(sum(&[Some(0), None]), None);
+/// assert_eq!(sum(&[Some(usize::MAX), Some(1)]), None);
+/// assert_eq!(sum(&[]), Some(0));
+/// ```
+pub const fn sum(sizes: &[Option<usize>]) -> Option<usize> {
+    let mut result = 0usize;
+    let mut i = 0;
+    while i < sizes.len() {
+        result = match sizes[i] {
+            Some(size) => match result.checked_add(size) {
+                Some(result) => result,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+
+    Some(result)
+}
+
+/// Returns the sum of `sizes` like [`sum`], or `None` if it's unknown or exceeds `u128::MAX`.
+///
+/// ## Example
+///
+/// ```
+/// use enumerable::size::sum_u128;
+///
+/// assert_eq!(sum_u128(&[Some(u64::MAX as u128), Some(1)]), Some(1 << 64));
+/// assert_eq!(sum_u128(&[Some(u128::MAX), Some(1)]), None);
+/// ```
+pub const fn sum_u128(sizes: &[Option<u128>]) -> Option<u128> {
+    let mut result = 0u128;
+    let mut i = 0;
+    while i < sizes.len() {
+        result = match sizes[i] {
+            Some(size) => match result.checked_add(size) {
+                Some(result) => result,
+                None => return None,
+            },
+            None => return None,
+        };
+        i += 1;
+    }
+
+    Some(result)
+}
+
+/// Returns `size` if it's at most `max_size`, panics naming `type_name` otherwise, including if
+/// it's `None`.
+///
+/// It backs `#[enumerable(max_size = ...)]` and is not part of the public API. The panic message
+/// is assembled in a buffer of `N` bytes, as formatting macros are not available in const
+/// contexts, so `N` must be at least `type_name.len() + 165`.
+#[doc(hidden)]
+pub const fn check_max_size<const N: usize>(
+    size: Option<u128>,
+    max_size: u128,
+    type_name: &str,
+) -> Option<u128> {
+    /// Appends `bytes` to `message` at `len`, returning the new length.
+    const fn append<const N: usize>(message: &mut [u8; N], len: usize, bytes: &[u8]) -> usize {
+        let mut i = 0;
+        while i < bytes.len() {
+            message[len + i] = bytes[i];
+            i += 1;
+        }
+        len + bytes.len()
+    }
+
+    /// Appends `value` in decimal to `message` at `len`, returning the new length.
+    const fn append_decimal<const N: usize>(
+        message: &mut [u8; N],
+        len: usize,
+        mut value: u128,
+    ) -> usize {
+        // `u128::MAX` has 39 decimal digits.
+        let mut digits = [0u8; 39];
+        let mut start = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        append(message, len, digits.split_at(start).1)
+    }
+
+    let actual = match size {
+        Some(actual) if actual <= max_size => return size,
+        actual => actual,
+    };
+
+    let mut message = [0u8; N];
+    let mut len = append(&mut message, 0, b"`");
+    len = append(&mut message, len, type_name.as_bytes());
+    len = append(&mut message, len, b"` has ");
+    len = match actual {
+        Some(actual) => append_decimal(&mut message, len, actual),
+        None => append(&mut message, len, b"more than u128::MAX"),
+    };
+    len = append(
+        &mut message,
+        len,
+        b" possible values, exceeding the limit of ",
+    );
+    len = append_decimal(&mut message, len, max_size);
+    len = append(
+        &mut message,
+        len,
+        b" set by `#[enumerable(max_size = ...)]`",
+    );
+
+    match core::str::from_utf8(message.split_at(len).0) {
+        Ok(message) => panic!("{}", message),
+        Err(_) => unreachable!(),
+    }
+}
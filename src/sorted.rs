@@ -0,0 +1,45 @@
+//! Enumeration in ascending order of `Ord`.
+
+use crate::Enumerable;
+use alloc::vec::{IntoIter, Vec};
+
+/// The iterator returned by [`Enumerable::enumerator_sorted`], yielding all possible values of `T`
+/// in ascending order of [`Ord`].
+pub struct SortedEnumerator<T: Enumerable> {
+    /// All values of `T` collected and sorted.
+    inner: IntoIter<T>,
+}
+
+impl<T: Enumerable + Ord> SortedEnumerator<T> {
+    /// Creates a new `SortedEnumerator`, collecting and sorting all values of `T`.
+    #[inline]
+    pub fn new() -> Self {
+        let mut values: Vec<T> = T::enumerator().collect();
+        values.sort();
+
+        Self {
+            inner: values.into_iter(),
+        }
+    }
+}
+
+impl<T: Enumerable + Ord> Default for SortedEnumerator<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Enumerable> Iterator for SortedEnumerator<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
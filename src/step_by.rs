@@ -0,0 +1,102 @@
+//! Strided enumeration, yielding every `step`-th value.
+
+use crate::Enumerable;
+use core::iter::StepBy;
+
+/// The iterator returned by [`Enumerable::enumerator_step_by`], yielding every `step`-th value of
+/// `T` in enumeration order, starting from the value at index `start`.
+pub struct StepByEnumerator<T: Enumerable> {
+    inner: Inner<T>,
+}
+
+enum Inner<T: Enumerable> {
+    /// The enumerator of `T` positioned at `start`, stepped through by `Iterator::step_by`.
+    Stepped(StepBy<T::Enumerator>),
+    /// Values looked up by [`Enumerable::enumerable_from_index_u128`].
+    Indexed {
+        /// The index of the next value to yield, or `None` if it overflowed.
+        next: Option<u128>,
+        step: u128,
+    },
+}
+
+impl<T: Enumerable> StepByEnumerator<T> {
+    /// Creates a new `StepByEnumerator`, stepping through the enumerator of `T` positioned by
+    /// [`Enumerable::enumerator_since`].
+    ///
+    /// It takes time linear in the number of values skipped unless the `nth` method of the
+    /// enumerator is faster.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `step` is 0.
+    #[inline]
+    pub fn new(start: usize, step: usize) -> Self {
+        assert!(step != 0, "`step` must not be 0");
+
+        Self {
+            inner: Inner::Stepped(T::enumerator_since(start).step_by(step)),
+        }
+    }
+
+    /// Creates a new `StepByEnumerator`, looking up values by
+    /// [`Enumerable::enumerable_from_index_u128`] and jumping over the values in between.
+    ///
+    /// It's as efficient as `enumerable_from_index_u128` for each value, and is what integers,
+    /// `char` and derived types use.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `step` is 0.
+    #[inline]
+    pub fn by_index(start: usize, step: usize) -> Self {
+        assert!(step != 0, "`step` must not be 0");
+
+        Self {
+            inner: Inner::Indexed {
+                next: Some(start as u128),
+                step: step as u128,
+            },
+        }
+    }
+}
+
+impl<T: Enumerable> Iterator for StepByEnumerator<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            Inner::Stepped(inner) => inner.next(),
+            Inner::Indexed { next, step } => {
+                let value = T::enumerable_from_index_u128((*next)?);
+                *next = match value {
+                    Some(_) => next.and_then(|index| index.checked_add(*step)),
+                    None => None,
+                };
+                value
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            Inner::Stepped(inner) => inner.size_hint(),
+            Inner::Indexed { next: None, .. } => (0, Some(0)),
+            Inner::Indexed {
+                next: Some(next),
+                step,
+            } => match T::ENUMERABLE_SIZE_OPTION_U128 {
+                Some(size) => {
+                    let remaining = size.saturating_sub(*next).div_ceil(*step);
+                    match usize::try_from(remaining) {
+                        Ok(remaining) => (remaining, Some(remaining)),
+                        Err(_) => (usize::MAX, None),
+                    }
+                }
+                None => (0, None),
+            },
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::Enumerable;
+use super::{Enumerable, FromIndexError};
 use std::vec;
 
 mod testee;
@@ -17,6 +17,200 @@ mod primitive {
     #[test]
     fn test_option_bool() {
         assert_enumerator_eq(vec![None, Some(false), Some(true)]);
+        assert_enumerator_eq_with_size_hint(vec![None, Some(false), Some(true)]);
+        assert_enumerator_eq_with_size_hint(vec![
+            None,
+            Some(Enum3::A),
+            Some(Enum3::B),
+            Some(Enum3::C),
+        ]);
+        assert_enumerator_eq_with_size_hint(vec![None::<Enum0>]);
+        assert_enumerator_eq_with_size_hint(vec![
+            None,
+            Some(None),
+            Some(Some(false)),
+            Some(Some(true)),
+        ]);
+    }
+
+    #[test]
+    fn test_built_in_enumerator_clone_and_debug() {
+        let mut iter = Option::<Enum3>::enumerator();
+        iter.next();
+        iter.next();
+        let cloned = iter.clone();
+        assert_eq!(
+            cloned.collect::<Vec<_>>(),
+            vec![Some(Enum3::B), Some(Enum3::C)]
+        );
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            vec![Some(Enum3::B), Some(Enum3::C)]
+        );
+
+        let mut iter = Option::<bool>::enumerator();
+        iter.next();
+        assert!(format!("{iter:?}").starts_with("OptionEnumerator { first: false, inner: "));
+
+        let mut iter = Result::<bool, Enum3>::enumerator();
+        iter.nth(2);
+        assert_eq!(iter.clone().collect::<Vec<_>>(), iter.collect::<Vec<_>>());
+        assert!(format!("{:?}", Result::<bool, u8>::enumerator()).starts_with("ResultEnumerator {"));
+
+        let mut iter = core::task::Poll::<Enum3>::enumerator();
+        iter.next();
+        assert_eq!(iter.clone().collect::<Vec<_>>(), iter.collect::<Vec<_>>());
+        assert!(
+            format!("{:?}", core::task::Poll::<bool>::enumerator()).starts_with("PollEnumerator {")
+        );
+
+        let mut iter = char::enumerator();
+        iter.nth(0xD7FF);
+        assert_eq!(iter.clone().next(), Some('\u{E000}'));
+        assert_eq!(iter.next(), Some('\u{E000}'));
+        assert!(format!("{iter:?}").starts_with("CharEnumerator {"));
+    }
+
+    #[test]
+    fn test_stable_enumerator_types() {
+        use crate::{
+            CharEnumerator, OptionEnumerator, PollEnumerator, ResultEnumerator, Tuple1Enumerator,
+            Tuple2Enumerator, Tuple3Enumerator,
+        };
+        use core::task::Poll;
+
+        let _: CharEnumerator = char::enumerator();
+        let _: OptionEnumerator<bool> = Option::<bool>::enumerator();
+        let _: ResultEnumerator<u8, Enum3> = Result::<u8, Enum3>::enumerator();
+        let _: PollEnumerator<Enum4> = Poll::<Enum4>::enumerator();
+        let _: Tuple1Enumerator<bool> = <(bool,)>::enumerator();
+        let _: Tuple2Enumerator<bool, Option<u8>> = <(bool, Option<u8>)>::enumerator();
+        let _: Tuple3Enumerator<bool, Enum3, char> = <(bool, Enum3, char)>::enumerator();
+
+        // the constructors are public too
+        assert!(OptionEnumerator::<Enum3>::new().eq(Option::<Enum3>::enumerator()));
+        assert!(Tuple2Enumerator::<bool, Enum3>::new().eq(<(bool, Enum3)>::enumerator()));
+    }
+
+    #[test]
+    fn test_option_double_ended() {
+        let mut iter = Option::<bool>::enumerator();
+        assert_eq!(iter.next_back(), Some(Some(true)));
+        assert_eq!(iter.next(), Some(None));
+        assert_eq!(iter.next_back(), Some(Some(false)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        // the leading `None` is yielded exactly once, from whichever end reaches it first
+        let mut iter = Option::<bool>::enumerator();
+        assert_eq!(iter.next(), Some(None));
+        assert_eq!(iter.next_back(), Some(Some(true)));
+        assert_eq!(iter.next_back(), Some(Some(false)));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = Option::<bool>::enumerator();
+        assert_eq!(iter.next_back(), Some(Some(true)));
+        assert_eq!(iter.next_back(), Some(Some(false)));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back(), Some(None));
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(
+            Option::<Enum3>::enumerator().rev().collect::<Vec<_>>(),
+            vec![Some(Enum3::C), Some(Enum3::B), Some(Enum3::A), None]
+        );
+        assert_eq!(Option::<u8>::enumerator().rev().nth(256), Some(None));
+    }
+
+    #[test]
+    fn test_option_from_index() {
+        assert_eq!(Option::<u8>::enumerable_from_index(0), Some(None));
+        assert_eq!(Option::<u8>::enumerable_from_index(1), Some(Some(0)));
+        assert_eq!(Option::<u8>::enumerable_from_index(256), Some(Some(255)));
+        assert_eq!(Option::<u8>::enumerable_from_index(257), None);
+        assert_eq!(Option::<u8>::enumerator_since(257).next(), None);
+        assert_eq!(
+            Option::<u8>::enumerator_since(257).size_hint(),
+            (0, Some(0))
+        );
+        assert_eq!(
+            Option::<u8>::enumerator_since(256).collect::<Vec<_>>(),
+            vec![Some(255)]
+        );
+
+        // both are as cheap as those of `u64`
+        assert_eq!(
+            Option::<u64>::enumerable_from_index_u128(u64::MAX as u128 + 1),
+            Some(Some(u64::MAX))
+        );
+        assert_eq!(
+            Option::<u64>::enumerable_from_index(usize::MAX),
+            Some(Some(usize::MAX as u64 - 1))
+        );
+        assert_eq!(
+            Option::<u64>::enumerator_since(usize::MAX).next(),
+            Some(Some(usize::MAX as u64 - 1))
+        );
+        assert_eq!(
+            Option::<Option<bool>>::enumerable_from_index(2),
+            Some(Some(Some(false)))
+        );
+    }
+
+    #[test]
+    fn test_result_from_index() {
+        // the boundary between `Ok` and `Err` values is exactly at the size of `T`
+        assert_eq!(
+            Result::<u8, bool>::enumerable_from_index(255),
+            Some(Ok(255))
+        );
+        assert_eq!(
+            Result::<u8, bool>::enumerable_from_index(256),
+            Some(Err(false))
+        );
+        assert_eq!(
+            Result::<u8, bool>::enumerable_from_index(257),
+            Some(Err(true))
+        );
+        assert_eq!(Result::<u8, bool>::enumerable_from_index(258), None);
+        assert_eq!(
+            Result::<u8, bool>::enumerator_since(256).collect::<Vec<_>>(),
+            vec![Err(false), Err(true)]
+        );
+
+        // seeking into the `Err` values doesn't step through the `Ok` values
+        let size = u32::ENUMERABLE_SIZE_OPTION_U128.unwrap();
+        assert_eq!(
+            Result::<u32, bool>::enumerable_from_index_u128(size),
+            Some(Err(false))
+        );
+        assert_eq!(
+            Result::<u32, bool>::enumerable_from_index_u128(size - 1),
+            Some(Ok(u32::MAX))
+        );
+        assert_eq!(
+            Result::<u32, bool>::enumerable_from_index_u128(size + 2),
+            None
+        );
+        assert_eq!(
+            Result::<u64, u64>::enumerable_from_index_u128((1 << 64) + 5),
+            Some(Err(5))
+        );
+        assert_eq!(
+            Result::<Enum0, bool>::enumerable_from_index(0),
+            Some(Err(false))
+        );
+
+        // the `Err` values are found by stepping if the size of `T` is unknown
+        assert_eq!(
+            Result::<NoSizeStruct, bool>::enumerable_from_index(6),
+            Some(Err(false))
+        );
+        assert_eq!(
+            Result::<NoSizeStruct, bool>::enumerator_since(6).collect::<Vec<_>>(),
+            vec![Err(false), Err(true)]
+        );
     }
 
     #[test]
@@ -25,236 +219,4905 @@ mod primitive {
     }
 
     #[test]
-    fn test_primitive_numeric() {
-        assert_enumerator_eq_with_size_hint(u8::MIN..=u8::MAX);
-        assert_enumerator_eq_with_size_hint(i8::MIN..=i8::MAX);
-        assert_enumerator_eq_with_size_hint(i16::MIN..=i16::MAX);
-        assert_enumerator_eq_with_size_hint(u16::MIN..=u16::MAX);
-        /*
-           // Very slow, tested locally.
-           assert_enumerator_eq(i32::MIN..=i32::MAX);
-           assert_enumerator_eq(u32::MIN..=u32::MAX);
-        */
+    fn test_result_exact_size() {
+        assert_exact_size::<Result<bool, bool>>();
+        assert_exact_size::<Result<Enum0, Enum3>>();
+        assert_exact_size::<Result<Enum3, Enum0>>();
+        assert_exact_size::<Result<u8, Option<bool>>>();
+
+        let mut iter = Result::<u8, bool>::enumerator();
+        assert_eq!(iter.nth(255), Some(Ok(255)));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.nth(1), Some(Err(true)));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+
+        // `nth` skips the `Ok` values in one go, and across into the `Err` values
+        let mut iter = Result::<u32, u8>::enumerator();
+        assert_eq!(iter.nth(u32::MAX as usize), Some(Ok(u32::MAX)));
+        assert_eq!(iter.nth(1), Some(Err(1)));
+        assert_eq!(iter.size_hint(), (254, Some(254)));
+        let mut iter = Result::<u8, u32>::enumerator();
+        assert_eq!(iter.nth(300), Some(Err(44)));
+        assert_eq!(iter.size_hint(), ((1 << 32) - 45, Some((1 << 32) - 45)));
+        let mut iter = Result::<NoSizeStruct, bool>::enumerator();
+        assert_eq!(iter.nth(7), Some(Err(true)));
+
+        assert_eq!(
+            Result::<bool, Enum3>::enumerator()
+                .rev()
+                .collect::<Vec<_>>(),
+            vec![
+                Err(Enum3::C),
+                Err(Enum3::B),
+                Err(Enum3::A),
+                Ok(true),
+                Ok(false)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cmp_ordering() {
+        use core::cmp::Ordering;
+
+        assert_enumerator_eq_with_size_hint(vec![
+            Ordering::Less,
+            Ordering::Equal,
+            Ordering::Greater,
+        ]);
+        assert_exact_size::<Ordering>();
+        assert_first_last_value::<Ordering>();
+        assert_next_value_direct::<Ordering>();
+        assert_previous_value_direct::<Ordering>();
+        assert_eq!(
+            <Ordering as crate::ConstEnumerable>::VALUES,
+            &[Ordering::Less, Ordering::Equal, Ordering::Greater]
+        );
+        assert_ordered::<Ordering>();
+
+        for (index, value) in collect_all::<Ordering>().into_iter().enumerate() {
+            assert_eq!(Ordering::enumerable_from_index(index), Some(value));
+            assert_eq!(Ordering::enumerable_to_index(&value), Some(index));
+            assert_eq!(Ordering::enumerator_since(index).next(), Some(value));
+        }
+        assert_eq!(Ordering::enumerable_from_index(3), None);
+        assert_eq!(Ordering::enumerator_since(4).next(), None);
+        assert_eq!(
+            Ordering::enumerator_reversed().collect::<Vec<_>>(),
+            vec![Ordering::Greater, Ordering::Equal, Ordering::Less]
+        );
+    }
+
+    #[test]
+    fn test_atomic_ordering() {
+        use core::sync::atomic::Ordering;
+
+        assert_enumerator_eq_with_size_hint(vec![
+            Ordering::Relaxed,
+            Ordering::Release,
+            Ordering::Acquire,
+            Ordering::AcqRel,
+            Ordering::SeqCst,
+        ]);
+        assert_eq!(Ordering::ENUMERABLE_SIZE_OPTION, Some(5));
+        assert_exact_size::<Ordering>();
+        assert_first_last_value::<Ordering>();
+        assert_next_value_direct::<Ordering>();
+        assert_previous_value_direct::<Ordering>();
+        assert_eq!(Ordering::enumerable_from_index(3), Some(Ordering::AcqRel));
+        assert_eq!(Ordering::enumerable_to_index(&Ordering::SeqCst), Some(4));
+        assert_eq!(Ordering::enumerable_from_index(5), None);
+        assert_eq!(
+            <(Ordering, Ordering)>::enumerator().nth(6),
+            Some((Ordering::Release, Ordering::Release))
+        );
+    }
+
+    #[test]
+    fn test_fp_category() {
+        use core::num::FpCategory;
+
+        assert_enumerator_eq_with_size_hint(vec![
+            FpCategory::Nan,
+            FpCategory::Infinite,
+            FpCategory::Zero,
+            FpCategory::Subnormal,
+            FpCategory::Normal,
+        ]);
+        assert_exact_size::<FpCategory>();
+        assert_first_last_value::<FpCategory>();
+        assert_next_value_direct::<FpCategory>();
+        assert_previous_value_direct::<FpCategory>();
+
+        // every category is reached by some `f32`
+        let samples = [f32::NAN, f32::INFINITY, 0.0, 1e-40, 1.0];
+        for (index, sample) in samples.iter().enumerate() {
+            assert_eq!(
+                FpCategory::enumerable_from_index(index),
+                Some(sample.classify())
+            );
+            assert_eq!(
+                FpCategory::enumerable_to_index(&sample.classify()),
+                Some(index)
+            );
+        }
+        assert_eq!(FpCategory::enumerable_from_index(5), None);
+    }
+
+    #[test]
+    fn test_fmt_alignment() {
+        use core::fmt::Alignment;
+
+        assert_enumerator_eq_with_size_hint(vec![
+            Alignment::Left,
+            Alignment::Right,
+            Alignment::Center,
+        ]);
+        assert_exact_size::<Alignment>();
+        assert_first_last_value::<Alignment>();
+        assert_next_value_direct::<Alignment>();
+        assert_previous_value_direct::<Alignment>();
+        assert_eq!(Alignment::enumerable_to_index(&Alignment::Center), Some(2));
+        assert_eq!(
+            <Option<Alignment>>::enumerator().next_back(),
+            Some(Some(Alignment::Center))
+        );
+    }
+
+    #[test]
+    fn test_infallible() {
+        use core::convert::Infallible;
+
+        #[derive(Clone, Debug, PartialEq, Enumerable)]
+        struct Unreachable {
+            flag: bool,
+            never: Infallible,
+        }
+
+        assert!(collect_all::<Infallible>().is_empty());
+        assert_eq!(Infallible::ENUMERABLE_SIZE, 0);
+        const { assert!(!Infallible::IS_INHABITED) };
+        assert_exact_size::<Infallible>();
+        assert_first_last_value::<Infallible>();
+        assert_eq!(Infallible::enumerable_from_index(0), None);
+        assert_eq!(Infallible::enumerator_since(1).next(), None);
+
+        assert_eq!(Result::<bool, Infallible>::ENUMERABLE_SIZE, 2);
+        assert_eq!(
+            collect_all::<Result<bool, Infallible>>(),
+            vec![Ok(false), Ok(true)]
+        );
+        assert_eq!(Option::<Infallible>::ENUMERABLE_SIZE, 1);
+
+        assert_eq!(Unreachable::ENUMERABLE_SIZE, 0);
+        const { assert!(!Unreachable::IS_INHABITED) };
+        assert!(collect_all::<Unreachable>().is_empty());
+    }
+
+    #[cfg(enumerable_never_type)]
+    #[test]
+    fn test_never_type() {
+        assert!(collect_all::<!>().is_empty());
+        assert_eq!(<Result<u8, !>>::ENUMERABLE_SIZE, 256);
+    }
+
+    #[test]
+    fn test_unit_like() {
+        use core::marker::PhantomPinned;
+        use core::ops::RangeFull;
+
+        assert_enumerator_eq_with_size_hint(vec![PhantomPinned]);
+        assert_enumerator_eq_with_size_hint(vec![core::fmt::Error]);
+        assert_enumerator_eq_with_size_hint(vec![..]);
+        assert_exact_size::<PhantomPinned>();
+        assert_exact_size::<core::fmt::Error>();
+        assert_exact_size::<RangeFull>();
+
+        assert_eq!(RangeFull::enumerable_from_index(0), Some(..));
+        assert_eq!(RangeFull::enumerable_from_index(1), None);
+        assert_eq!(RangeFull::enumerable_to_index(&..), Some(0));
+        assert_eq!(PhantomPinned.next_value(), None);
+        assert_eq!(core::fmt::Error.previous_value(), None);
+        assert_eq!(
+            <core::fmt::Error as crate::ConstEnumerable>::VALUES,
+            &[core::fmt::Error]
+        );
+
+        #[derive(Clone, Debug, PartialEq, Enumerable)]
+        struct Pinned {
+            state: bool,
+            _pin: PhantomPinned,
+        }
+
+        assert_enumerator_eq(vec![
+            Pinned {
+                state: false,
+                _pin: PhantomPinned,
+            },
+            Pinned {
+                state: true,
+                _pin: PhantomPinned,
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_control_flow() {
+        use core::ops::ControlFlow::{self, Break, Continue};
+
+        assert_enumerator_eq_with_size_hint(vec![
+            Continue(Enum3::A),
+            Continue(Enum3::B),
+            Continue(Enum3::C),
+            Break(false),
+            Break(true),
+        ]);
+        assert_eq!(ControlFlow::<bool, Enum3>::ENUMERABLE_SIZE, 5);
+        assert_first_last_value::<ControlFlow<bool, Enum3>>();
+        assert_next_value_direct::<ControlFlow<bool, Enum3>>();
+        assert_previous_value_direct::<ControlFlow<bool, Enum3>>();
+
+        let all = collect_all::<ControlFlow<bool, Enum3>>();
+        for (index, value) in all.iter().enumerate() {
+            assert_eq!(
+                ControlFlow::<bool, Enum3>::enumerable_from_index(index),
+                Some(*value)
+            );
+            assert_eq!(
+                ControlFlow::<bool, Enum3>::enumerator_since(index).collect::<Vec<_>>(),
+                all[index..]
+            );
+        }
+        assert_eq!(ControlFlow::<bool, Enum3>::enumerable_from_index(5), None);
+
+        // an uninhabited side contributes no values
+        assert_enumerator_eq_with_size_hint::<ControlFlow<bool, Enum0>>(vec![
+            Break(false),
+            Break(true),
+        ]);
+        assert_enumerator_eq_with_size_hint::<ControlFlow<Enum0, bool>>(vec![
+            Continue(false),
+            Continue(true),
+        ]);
+        assert_first_last_value::<ControlFlow<Enum0, bool>>();
+        assert_first_last_value::<ControlFlow<bool, Enum0>>();
+        assert!(collect_all::<ControlFlow<Enum0, Enum0>>().is_empty());
+        const { assert!(!ControlFlow::<Enum0, Enum0>::IS_INHABITED) };
+
+        assert_eq!(
+            ControlFlow::<u64, u64>::enumerable_from_index_u128(1 << 64),
+            Some(Break(0))
+        );
+        assert_eq!(
+            ControlFlow::<u64, u64>::ENUMERABLE_SIZE_OPTION_U128,
+            Some(1 << 65)
+        );
+        assert_eq!(
+            <ControlFlow<bool, u8> as crate::SumEnumerable>::VARIANT_SIZES,
+            [256, 2]
+        );
+    }
+
+    #[test]
+    fn test_poll() {
+        use core::convert::Infallible;
+        use core::task::Poll;
+
+        assert_enumerator_eq_with_size_hint(vec![
+            Poll::Pending,
+            Poll::Ready(false),
+            Poll::Ready(true),
+        ]);
+        assert_exact_size::<Poll<bool>>();
+        assert_first_last_value::<Poll<bool>>();
+        assert_next_value_direct::<Poll<bool>>();
+        assert_previous_value_direct::<Poll<bool>>();
+        assert_eq!(
+            Poll::<bool>::enumerable_from_index(2),
+            Some(Poll::Ready(true))
+        );
+        assert_eq!(Poll::<bool>::enumerable_from_index(3), None);
+        assert_eq!(
+            Poll::<u64>::enumerable_from_index(1 << 40),
+            Some(Poll::Ready((1 << 40) - 1))
+        );
+        assert_eq!(
+            Poll::<bool>::enumerator_since(1).collect::<Vec<_>>(),
+            vec![Poll::Ready(false), Poll::Ready(true)]
+        );
+
+        assert_enumerator_eq_with_size_hint(vec![Poll::<Infallible>::Pending]);
+        assert_eq!(Poll::<Infallible>::ENUMERABLE_SIZE, 1);
+        assert_first_last_value::<Poll<Infallible>>();
+        assert_exact_size::<Poll<Infallible>>();
+        assert_eq!(Poll::<Infallible>::Pending.next_value(), None);
+
+        assert_eq!(Poll::<u128>::ENUMERABLE_SIZE_OPTION_U128, None);
+    }
+
+    #[test]
+    fn test_bound() {
+        use core::ops::Bound::{self, Excluded, Included, Unbounded};
+
+        assert_enumerator_eq_with_size_hint(vec![
+            Included(false),
+            Included(true),
+            Excluded(false),
+            Excluded(true),
+            Unbounded,
+        ]);
+        assert_eq!(Bound::<bool>::ENUMERABLE_SIZE, 5);
+        assert_eq!(Bound::<u8>::enumerator().count(), 2 * 256 + 1);
+        assert_first_last_value::<Bound<Enum3>>();
+        assert_next_value_direct::<Bound<Enum3>>();
+        assert_previous_value_direct::<Bound<Enum3>>();
+
+        let all = collect_all::<Bound<bool>>();
+        for index in 0..=all.len() + 1 {
+            assert_eq!(
+                Bound::<bool>::enumerable_from_index(index),
+                all.get(index).cloned()
+            );
+            assert_eq!(
+                Bound::<bool>::enumerator_since(index).collect::<Vec<_>>(),
+                all[index.min(all.len())..]
+            );
+        }
+
+        assert_enumerator_eq_with_size_hint(vec![Bound::<Enum0>::Unbounded]);
+        assert_first_last_value::<Bound<Enum0>>();
+        assert_eq!(Bound::<Enum0>::enumerable_from_index(0), Some(Unbounded));
+        assert_eq!(Bound::<Enum0>::enumerator_since(1).next(), None);
+        assert_eq!(
+            Bound::<NoSizeStruct>::enumerable_from_index(6),
+            Some(Excluded(NoSizeStruct::first_value().unwrap()))
+        );
+        assert_eq!(
+            Bound::<u64>::ENUMERABLE_SIZE_OPTION_U128,
+            Some((1 << 65) + 1)
+        );
+        assert_eq!(Bound::<u128>::ENUMERABLE_SIZE_OPTION_U128, None);
+    }
+
+    #[test]
+    fn test_primitive_numeric() {
+        assert_enumerator_eq_with_size_hint(u8::MIN..=u8::MAX);
+        assert_enumerator_eq_with_size_hint(i8::MIN..=i8::MAX);
+        assert_enumerator_eq_with_size_hint(i16::MIN..=i16::MAX);
+        assert_enumerator_eq_with_size_hint(u16::MIN..=u16::MAX);
+        /*
+           // Very slow, tested locally.
+           assert_enumerator_eq(i32::MIN..=i32::MAX);
+           assert_enumerator_eq(u32::MIN..=u32::MAX);
+        */
+    }
+
+    #[test]
+    fn test_primitive_since_from_index() {
+        // signed types start from their negative minimum
+        assert_eq!(i8::enumerable_from_index(0), Some(-128));
+        assert_eq!(i8::enumerable_from_index(100), Some(-28));
+        assert_eq!(i8::enumerable_from_index(255), Some(127));
+        assert_eq!(
+            i8::enumerator_since(126).take(4).collect::<Vec<_>>(),
+            vec![-2, -1, 0, 1]
+        );
+
+        // indices past the end
+        assert_eq!(i8::enumerable_from_index(256), None);
+        assert_eq!(u16::enumerable_from_index(65536), None);
+        assert_eq!(i8::enumerator_since(256).next(), None);
+        assert_eq!(u16::enumerator_since(usize::MAX).next(), None);
+
+        // positioned without stepping
+        assert_eq!(
+            u32::enumerator_since(3_000_000_000).next(),
+            Some(3_000_000_000)
+        );
+        assert_eq!(
+            i32::enumerable_from_index(3_000_000_000),
+            Some(3_000_000_000u32.wrapping_sub(1 << 31) as i32)
+        );
+        assert_eq!(
+            u64::enumerator_since(usize::MAX).next(),
+            Some(usize::MAX as u64)
+        );
+        assert_eq!(
+            i128::enumerable_from_index(usize::MAX),
+            Some(i128::MIN + usize::MAX as i128)
+        );
+
+        // `usize` indices cover all values of the pointer-sized types, whatever the host width
+        assert_eq!(usize::enumerable_from_index(usize::MAX), Some(usize::MAX));
+        assert_eq!(usize::enumerator_since(usize::MAX).count(), 1);
+        assert_eq!(isize::enumerable_from_index(0), Some(isize::MIN));
+        assert_eq!(isize::enumerable_from_index(usize::MAX), Some(isize::MAX));
+        assert_eq!(
+            isize::enumerator_since(isize::MAX as usize + 1).next(),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_primitive_first_last_value() {
+        assert_first_last_value::<u8>();
+        assert_first_last_value::<i16>();
+        assert_first_last_value::<bool>();
+        assert_first_last_value::<Option<bool>>();
+        assert_first_last_value::<Result<bool, i8>>();
+        assert_first_last_value::<Result<Enum0, bool>>();
+        assert_first_last_value::<Result<u8, Enum0>>();
+        assert_first_last_value::<Result<Enum0, Enum0>>();
+        assert_first_last_value::<Option<Enum0>>();
+
+        // computed directly
+        assert_eq!(u64::last_value(), Some(u64::MAX));
+        assert_eq!(i128::first_value(), Some(i128::MIN));
+        assert_eq!(char::last_value(), Some('\u{10FFFF}'));
+        assert_eq!(<Result<u8, u32>>::last_value(), Some(Err(u32::MAX)));
+        assert_eq!(<Option<u64>>::last_value(), Some(Some(u64::MAX)));
+    }
+
+    #[test]
+    fn test_primitive_next_value() {
+        assert_next_value_direct::<u8>();
+        assert_next_value_direct::<i8>();
+        assert_next_value_direct::<bool>();
+        assert_next_value_direct::<Option<bool>>();
+        assert_next_value_direct::<Option<Enum0>>();
+        assert_next_value_direct::<Result<bool, Enum3>>();
+        assert_next_value_direct::<Result<Enum0, bool>>();
+        assert_next_value_direct::<Result<bool, Enum0>>();
+        assert_next_value::<Result<Option<bool>, i8>>();
+
+        assert_eq!(u64::MAX.next_value(), None);
+        assert_eq!(i32::MAX.next_value(), None);
+        assert_eq!((-1i64).next_value(), Some(0));
+        assert_eq!('a'.next_value(), Some('b'));
+        assert_eq!('\u{D7FF}'.next_value(), Some('\u{E000}'));
+        assert_eq!('\u{10FFFF}'.next_value(), None);
+        assert_eq!(Some(u32::MAX).next_value(), None);
+        assert_eq!(
+            <Result<u16, i16>>::Ok(u16::MAX).next_value(),
+            Some(Err(i16::MIN))
+        );
+        assert_eq!(<Result<u16, Enum0>>::Ok(u16::MAX).next_value(), None);
+    }
+
+    #[test]
+    fn test_primitive_previous_value() {
+        assert_previous_value_direct::<u8>();
+        assert_previous_value_direct::<i8>();
+        assert_previous_value_direct::<bool>();
+        assert_previous_value_direct::<Option<bool>>();
+        assert_previous_value_direct::<Option<Enum0>>();
+        assert_previous_value_direct::<Result<bool, Enum3>>();
+        assert_previous_value_direct::<Result<Enum0, bool>>();
+        assert_previous_value_direct::<Result<bool, Enum0>>();
+        assert_previous_value::<Result<Option<bool>, i8>>();
+
+        assert_eq!(u64::MIN.previous_value(), None);
+        assert_eq!(i32::MIN.previous_value(), None);
+        assert_eq!(0i64.previous_value(), Some(-1));
+        assert_eq!('\0'.previous_value(), None);
+        assert_eq!('b'.previous_value(), Some('a'));
+        assert_eq!('\u{E000}'.previous_value(), Some('\u{D7FF}'));
+        assert_eq!('\u{10FFFF}'.previous_value(), Some('\u{10FFFE}'));
+        assert_eq!(Some(0u32).previous_value(), Some(None));
+        assert_eq!(
+            <Result<u16, i16>>::Err(i16::MIN).previous_value(),
+            Some(Ok(u16::MAX))
+        );
+        assert_eq!(<Result<Enum0, u16>>::Err(0).previous_value(), None);
+    }
+
+    #[test]
+    fn test_primitive_size_u128() {
+        assert_eq!(u8::ENUMERABLE_SIZE_OPTION_U128, Some(256));
+        assert_eq!(i64::ENUMERABLE_SIZE_OPTION_U128, Some(1 << 64));
+        assert_eq!(u128::ENUMERABLE_SIZE_OPTION_U128, None);
+        assert_eq!(i128::ENUMERABLE_SIZE_OPTION_U128, None);
+        assert_eq!(char::ENUMERABLE_SIZE_OPTION_U128, Some(0x10F800));
+        assert_eq!(
+            <Option<u64>>::ENUMERABLE_SIZE_OPTION_U128,
+            Some((1 << 64) + 1)
+        );
+        assert_eq!(
+            <Result<u64, i64>>::ENUMERABLE_SIZE_OPTION_U128,
+            Some(1 << 65)
+        );
+        assert_eq!(<Option<u128>>::ENUMERABLE_SIZE_OPTION_U128, None);
+        assert_eq!(
+            <(u16, u16, u16, u16)>::ENUMERABLE_SIZE_OPTION_U128,
+            Some(1 << 64)
+        );
+        assert_eq!(<(u64, u64)>::ENUMERABLE_SIZE_OPTION_U128, None);
+        assert_eq!(<(u128, ())>::ENUMERABLE_SIZE_OPTION_U128, None);
+        assert_eq!(<(u128, Enum0)>::ENUMERABLE_SIZE_OPTION_U128, Some(0));
+        assert_eq!(<(u32,)>::ENUMERABLE_SIZE_OPTION_U128, Some(1 << 32));
+
+        // the usize constant is consistent with the u128 one
+        assert_eq!(u16::ENUMERABLE_SIZE_OPTION, Some(65536));
+        assert_eq!(
+            <(u16, u16, u16)>::ENUMERABLE_SIZE_OPTION,
+            usize::try_from(1u128 << 48).ok()
+        );
+        assert_eq!(
+            <(u16, u16, u16, u16)>::ENUMERABLE_SIZE_OPTION,
+            usize::try_from(1u128 << 64).ok()
+        );
+    }
+
+    #[test]
+    fn test_non_zero_unsigned() {
+        use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
+
+        let all = collect_all::<NonZeroU8>();
+        assert_eq!(all.len(), 255);
+        assert_eq!(
+            all.iter().map(|x| x.get()).collect::<Vec<_>>(),
+            (1..=u8::MAX).collect::<Vec<_>>()
+        );
+        assert_exact_size::<NonZeroU8>();
+        assert_exact_size::<NonZeroU16>();
+        assert!(NonZeroU16::enumerator().all(|x| x.get() != 0));
+
+        // exactly one less than the underlying type
+        assert_eq!(
+            NonZeroU16::ENUMERABLE_SIZE_OPTION,
+            Some(u16::ENUMERABLE_SIZE - 1)
+        );
+        assert_eq!(
+            NonZeroU64::ENUMERABLE_SIZE_OPTION_U128,
+            Some(u64::ENUMERABLE_SIZE_OPTION_U128.unwrap() - 1)
+        );
+        assert_eq!(NonZeroU128::ENUMERABLE_SIZE_OPTION_U128, Some(u128::MAX));
+
+        assert_first_last_value::<NonZeroU8>();
+        assert_next_value_direct::<NonZeroU8>();
+        assert_previous_value_direct::<NonZeroU8>();
+        assert_eq!(NonZeroU32::MIN.previous_value(), None);
+        assert_eq!(NonZeroU32::MAX.next_value(), None);
+
+        assert_eq!(NonZeroU8::enumerable_from_index(0), NonZeroU8::new(1));
+        assert_eq!(NonZeroU8::enumerable_from_index(254), NonZeroU8::new(255));
+        assert_eq!(NonZeroU8::enumerable_from_index(255), None);
+        assert_eq!(
+            NonZeroU128::enumerable_from_index_u128(u128::MAX - 1),
+            Some(NonZeroU128::MAX)
+        );
+        assert_eq!(NonZeroU8::enumerable_to_index(&NonZeroU8::MAX), Some(254));
+        assert_eq!(
+            NonZeroU8::enumerator_since(254).collect::<Vec<_>>(),
+            vec![NonZeroU8::MAX]
+        );
+        assert_eq!(NonZeroU8::enumerator_since(255).next(), None);
+        assert_eq!(
+            NonZeroU8::enumerator_reversed().next(),
+            Some(NonZeroU8::MAX)
+        );
+        assert_eq!(
+            NonZeroU64::enumerator_from(NonZeroU64::MAX).collect::<Vec<_>>(),
+            vec![NonZeroU64::MAX]
+        );
+    }
+
+    #[test]
+    fn test_non_zero_signed() {
+        use core::num::{NonZeroI128, NonZeroI16, NonZeroI64, NonZeroI8};
+
+        let all = collect_all::<NonZeroI8>();
+        assert_eq!(all.len(), 255);
+        assert_eq!(
+            all.iter().map(|x| x.get()).collect::<Vec<_>>(),
+            (i8::MIN..=i8::MAX).filter(|&x| x != 0).collect::<Vec<_>>()
+        );
+        assert_exact_size::<NonZeroI8>();
+        assert_exact_size::<NonZeroI16>();
+        assert_eq!(
+            NonZeroI16::ENUMERABLE_SIZE_OPTION,
+            Some(i16::ENUMERABLE_SIZE - 1)
+        );
+        assert_eq!(NonZeroI128::ENUMERABLE_SIZE_OPTION_U128, Some(u128::MAX));
+
+        assert_first_last_value::<NonZeroI8>();
+        assert_next_value_direct::<NonZeroI8>();
+        assert_previous_value_direct::<NonZeroI8>();
+
+        // the boundary around zero
+        let minus_one = NonZeroI8::new(-1).unwrap();
+        let one = NonZeroI8::new(1).unwrap();
+        assert_eq!(minus_one.next_value(), Some(one));
+        assert_eq!(one.previous_value(), Some(minus_one));
+        assert_eq!(NonZeroI8::enumerable_from_index(127), Some(minus_one));
+        assert_eq!(NonZeroI8::enumerable_from_index(128), Some(one));
+        assert_eq!(NonZeroI8::enumerable_from_index(254), Some(NonZeroI8::MAX));
+        assert_eq!(NonZeroI8::enumerable_from_index(255), None);
+        assert_eq!(NonZeroI8::enumerable_to_index(&minus_one), Some(127));
+        assert_eq!(NonZeroI8::enumerable_to_index(&one), Some(128));
+        assert_eq!(
+            NonZeroI8::enumerator_since(126).take(3).collect::<Vec<_>>(),
+            all[126..129]
+        );
+        assert_eq!(
+            NonZeroI8::enumerator_from(minus_one).collect::<Vec<_>>(),
+            all[127..]
+        );
+        assert_eq!(NonZeroI8::enumerator_since(255).next(), None);
+        assert_eq!(NonZeroI8::enumerator().nth(128), Some(one));
+        assert_eq!(NonZeroI8::enumerator().nth(255), None);
+
+        let mut iter = NonZeroI8::enumerator();
+        assert_eq!(iter.nth(126), NonZeroI8::new(-2));
+        assert_eq!(iter.len(), 128);
+        assert_eq!(iter.nth(1), Some(one));
+        assert_eq!(iter.len(), 126);
+        assert_eq!(iter.next_back(), Some(NonZeroI8::MAX));
+
+        let reversed = NonZeroI8::enumerator_reversed().collect::<Vec<_>>();
+        assert_eq!(reversed, all.iter().rev().copied().collect::<Vec<_>>());
+        assert_eq!(
+            NonZeroI64::enumerable_from_index_u128(1 << 63),
+            NonZeroI64::new(1)
+        );
+    }
+
+    #[test]
+    fn test_ipv4_addr() {
+        use core::net::Ipv4Addr;
+
+        let mut iter = Ipv4Addr::enumerator();
+        assert_eq!(iter.next(), Some(Ipv4Addr::new(0, 0, 0, 0)));
+        assert_eq!(iter.next(), Some(Ipv4Addr::new(0, 0, 0, 1)));
+        assert_eq!(iter.nth(253), Some(Ipv4Addr::new(0, 0, 0, 255)));
+        assert_eq!(iter.next(), Some(Ipv4Addr::new(0, 0, 1, 0)));
+        assert_eq!(iter.size_hint(), (0xFFFF_FEFF, Some(0xFFFF_FEFF)));
+        assert_eq!(iter.next_back(), Some(Ipv4Addr::BROADCAST));
+        assert_eq!(iter.nth(usize::MAX), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+
+        assert_eq!(Ipv4Addr::ENUMERABLE_SIZE_OPTION_U128, Some(1 << 32));
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(Ipv4Addr::ENUMERABLE_SIZE_OPTION, Some(1 << 32));
+            assert_eq!(Ipv4Addr::enumerator().count(), 1 << 32);
+        }
+        #[cfg(not(target_pointer_width = "64"))]
+        assert_eq!(Ipv4Addr::ENUMERABLE_SIZE_OPTION, None);
+
+        assert_eq!(Ipv4Addr::first_value(), Some(Ipv4Addr::UNSPECIFIED));
+        assert_eq!(Ipv4Addr::last_value(), Some(Ipv4Addr::BROADCAST));
+        assert_eq!(
+            Ipv4Addr::new(10, 0, 0, 255).next_value(),
+            Some(Ipv4Addr::new(10, 0, 1, 0))
+        );
+        assert_eq!(Ipv4Addr::BROADCAST.next_value(), None);
+        assert_eq!(Ipv4Addr::UNSPECIFIED.previous_value(), None);
+
+        let localhost = Ipv4Addr::LOCALHOST;
+        let index = Ipv4Addr::enumerable_to_index(&localhost).unwrap();
+        assert_eq!(index, 0x7F00_0001);
+        assert_eq!(Ipv4Addr::enumerable_from_index(index), Some(localhost));
+        assert_eq!(Ipv4Addr::enumerable_from_index_u128(1 << 32), None);
+        assert_eq!(Ipv4Addr::enumerator_since(index).next(), Some(localhost));
+        assert_eq!(
+            Ipv4Addr::enumerator_since(u32::MAX as usize).collect::<Vec<_>>(),
+            vec![Ipv4Addr::BROADCAST]
+        );
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(Ipv4Addr::enumerator_since(1 << 32).next(), None);
+        assert_eq!(
+            <Ipv4Addr as crate::OrderedEnumerable>::values_between(
+                Ipv4Addr::new(192, 168, 0, 254),
+                Ipv4Addr::new(192, 168, 1, 1)
+            )
+            .count(),
+            4
+        );
+        assert_eq!(
+            Ipv4Addr::enumerator_reversed().next(),
+            Some(Ipv4Addr::BROADCAST)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::iter_skip_next)] // `skip` is what's being tested here
+    fn test_char() {
+        assert_eq!(char::enumerator().skip(0x61).next(), Some('\u{61}'));
+        assert_ne!(char::enumerator().skip(0xF987).next(), Some('\u{F987}'));
+        assert_eq!(
+            char::enumerator().skip(0xF987 - 0x800).next(),
+            Some('\u{F987}')
+        );
+    }
+
+    #[test]
+    fn test_char_from_index() {
+        assert_eq!(char::enumerable_from_index(0x61), Some('\u{61}'));
+        assert_eq!(
+            char::enumerable_from_index(0xF987 - 0x800),
+            Some('\u{F987}')
+        );
+
+        // both sides of the surrogate gap
+        assert_eq!(char::enumerable_from_index(0xD7FF), Some('\u{D7FF}'));
+        assert_eq!(char::enumerable_from_index(0xD800), Some('\u{E000}'));
+        assert_eq!(char::enumerator_since(0xD7FF).nth(1), Some('\u{E000}'));
+        assert_eq!(char::enumerator_since(0xD800).next(), Some('\u{E000}'));
+
+        // the last value and past it
+        let last = char::ENUMERABLE_SIZE - 1;
+        assert_eq!(last, 0x10F7FF);
+        assert_eq!(char::enumerable_from_index(last), Some('\u{10FFFF}'));
+        assert_eq!(
+            char::enumerable_from_index_u128(last as u128),
+            Some('\u{10FFFF}')
+        );
+        assert_eq!(char::enumerable_from_index(last + 1), None);
+        assert_eq!(char::enumerable_from_index_u128(1 << 40), None);
+        assert_eq!(
+            char::enumerator_since(last).collect::<Vec<_>>(),
+            vec!['\u{10FFFF}']
+        );
+        assert_eq!(char::enumerator_since(last + 1).next(), None);
+        assert_eq!(char::enumerable_from_index(0x10F000), Some('\u{10F800}'));
+    }
+
+    // the dedicated enumerator seeks and steps back in O(1) time
+    #[test]
+    fn test_char_enumerator() {
+        let last = char::ENUMERABLE_SIZE - 1;
+        assert_exact_size::<char>();
+        let mut iter = char::enumerator();
+        assert_eq!(iter.nth(0xD7FE), Some('\u{D7FE}'));
+        assert_eq!(iter.nth(1), Some('\u{E000}'));
+        assert_eq!(iter.len(), last - 0xD800);
+        assert_eq!(iter.nth(0x10), Some('\u{E011}'));
+        let mut iter = char::enumerator_since(0xD000);
+        assert_eq!(iter.nth(0x1000), Some('\u{E800}'));
+
+        let mut iter = char::enumerator();
+        assert_eq!(iter.next_back(), Some('\u{10FFFF}'));
+        assert_eq!(iter.next_back(), Some('\u{10FFFE}'));
+        assert_eq!(iter.len(), last - 1);
+        assert_eq!(iter.nth_back(0x10F7FD - 0xD800), Some('\u{E000}'));
+        assert_eq!(iter.next_back(), Some('\u{D7FF}'));
+        assert_eq!(iter.next(), Some('\u{0}'));
+        assert_eq!(iter.len(), 0xD7FE);
+        assert_eq!(char::enumerator().last(), Some('\u{10FFFF}'));
+        assert_eq!(char::enumerator().rev().nth(0x10F7FF), Some('\u{0}'));
+        assert_eq!(char::enumerator_from('\u{E000}').next(), Some('\u{E000}'));
+    }
+
+    #[test]
+    fn test_char_enumerator_exhausted() {
+        // the enumerator doesn't yield anything once exhausted from either end
+        let last = char::ENUMERABLE_SIZE - 1;
+        let mut iter = char::enumerator_since(last);
+        assert_eq!(iter.next_back(), Some('\u{10FFFF}'));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.len(), 0);
+    }
+}
+
+mod enum_and_struct {
+    use super::*;
+    use crate::{
+        const_char_from_index, const_from_index, const_i128_from_index, const_i32_from_index,
+        const_i8_from_index, const_isize_from_index, const_u128_from_index, const_u32_from_index,
+        const_u64_from_index, const_values, ConstEnumerable,
+    };
+    use std::fmt::Debug;
+
+    #[test]
+    fn test_enum_derive() {
+        // plain enums return a slice iter, so it must have a exact size hint
+        assert_enumerator_eq_with_size_hint(vec![Enum3::A, Enum3::B, Enum3::C]);
+        assert_enumerator_eq_with_size_hint(vec![Enum4::W, Enum4::X, Enum4::Y, Enum4::Z])
+    }
+
+    #[test]
+    fn test_enum_derive_complex() {
+        let mut expected = vec![ComplexEnum::NoField];
+        expected.extend(Enum3::enumerator().map(ComplexEnum::UnnamedField));
+        expected.extend(Enum3::enumerator().map(|e3| ComplexEnum::NamedField { e3 }));
+        expected.extend(
+            <(Enum3, Enum4) as Enumerable>::enumerator()
+                .map(|(e3, e4)| ComplexEnum::MultipleUnnamedFields(e3, e4)),
+        );
+        expected.extend(
+            <(Enum3, Enum4) as Enumerable>::enumerator()
+                .map(|(e3, e4)| ComplexEnum::MultipleNamedFields { e3, e4 }),
+        );
+        expected.extend(Enum3::enumerator().map(|e3| ComplexEnum::UnnamedFieldAfterEmpty { e3 }));
+        assert_enumerator_eq(expected);
+
+        // Checks whether the custom enumerator name is used.
+        let e: ComplexEnumerator = ComplexEnum::enumerator();
+        assert_eq!(ComplexEnum::ENUMERABLE_SIZE, e.count());
+    }
+
+    #[test]
+    fn test_unit_struct() {
+        assert_enumerator_eq(vec![StructUnit {}]);
+        assert_enumerator_eq(vec![StructUnitFieldsNamed {}]);
+        assert_enumerator_eq(vec![StructUnitFieldsUnnamed()]);
+    }
+
+    #[test]
+    fn test_structs() {
+        let expected = Enum3::enumerator()
+            .flat_map(|e3| Enum4::enumerator().map(move |e4| (e3, e4)))
+            .collect::<Vec<_>>();
+
+        assert_enumerator_eq(expected.iter().map(|(e3, e4)| Struct2 { e3: *e3, e4: *e4 }));
+        assert_enumerator_eq(expected.iter().map(|(e3, e4)| StructTuple2(*e3, *e4)));
+    }
+
+    fn assert_const_values<T: ConstEnumerable + PartialEq + Debug>() {
+        assert_eq!(T::VALUES, collect_all::<T>().as_slice());
+    }
+
+    #[test]
+    fn test_const_values() {
+        assert_const_values::<u8>();
+        assert_const_values::<i8>();
+        assert_const_values::<u16>();
+        assert_const_values::<i16>();
+        assert_const_values::<bool>();
+        assert_const_values::<()>();
+        assert_const_values::<Enum0>();
+        assert_const_values::<Enum3>();
+        assert_const_values::<StructUnit>();
+        assert_const_values::<ProtocolOrder>();
+        assert_const_values::<MixedDiscriminants>();
+
+        const ENUM3: [Enum3; Enum3::ENUMERABLE_SIZE] = const_values();
+        const ENUM0: [Enum0; 0] = const_values();
+        const MIN_I16: Option<i16> = const_from_index(0);
+        const SECOND: Option<ProtocolOrder> = const_from_index(1);
+        const OUT_OF_RANGE: Option<Enum4> = const_from_index(4);
+        assert_eq!(ENUM3, [Enum3::A, Enum3::B, Enum3::C]);
+        assert_eq!(ENUM0, []);
+        assert_eq!(MIN_I16, Some(i16::MIN));
+        assert_eq!(SECOND, Some(ProtocolOrder::Data));
+        assert_eq!(OUT_OF_RANGE, None);
+
+        // wide integers and `char` are looked up arithmetically
+        const MAX_U64: Option<u64> = const_u64_from_index(u64::MAX as u128);
+        const ZERO_I128: Option<i128> = const_i128_from_index(1 << 127);
+        const FIRST_NON_SURROGATE: Option<char> = const_char_from_index(0xD800);
+        assert_eq!(MAX_U64, Some(u64::MAX));
+        assert_eq!(ZERO_I128, Some(0));
+        assert_eq!(FIRST_NON_SURROGATE, Some('\u{E000}'));
+        assert_eq!(const_u64_from_index(1 << 64), None);
+        assert_eq!(const_u128_from_index(u128::MAX), Some(u128::MAX));
+        assert_eq!(const_i8_from_index(200), i8::enumerable_from_index(200));
+        assert_eq!(const_i8_from_index(256), None);
+        for index in [0, 1 << 20, (1 << 31) - 1, 1 << 31, u32::MAX as u128] {
+            assert_eq!(
+                const_i32_from_index(index),
+                i32::enumerable_from_index_u128(index)
+            );
+            assert_eq!(
+                const_u32_from_index(index),
+                u32::enumerable_from_index_u128(index)
+            );
+        }
+        assert_eq!(const_u32_from_index(1 << 32), None);
+        assert_eq!(const_isize_from_index(0), isize::enumerable_from_index(0));
+        for index in [0, 0xD7FF, 0xD800, 0x10F7FF, 0x10F800, u128::MAX] {
+            assert_eq!(
+                const_char_from_index(index),
+                char::enumerable_from_index_u128(index)
+            );
+        }
+    }
+
+    #[test]
+    fn test_exact_size() {
+        assert_exact_size::<Enum0>();
+        assert_exact_size::<Enum3>();
+        assert_exact_size::<StructUnit>();
+        assert_exact_size::<Struct2>();
+        assert_exact_size::<StructTuple2>();
+        assert_exact_size::<ComplexEnum>();
+        assert_exact_size::<MaxSizeGeneric<i8>>();
+        assert_exact_size::<Option<Struct2>>();
+
+        // size hints are exact for all derived types, even if not `ExactSizeEnumerable`
+        assert_enumerator_eq_with_size_hint(collect_all::<TrailingEmptyBranch>());
+        assert_enumerator_eq_with_size_hint(collect_all::<StructWithEmptyField>());
+        assert_enumerator_eq_with_size_hint(collect_all::<GenericEnum3<bool, Enum3>>());
+        assert_enumerator_eq_with_size_hint(collect_all::<NoSizeGeneric<Enum3>>());
+        assert_eq!(ThreeU32::enumerator().size_hint(), (usize::MAX, None));
+        assert_enumerator_eq_with_size_hint(collect_all::<NoSizeStruct>());
+    }
+
+    #[test]
+    fn test_clone_only() {
+        assert_enumerator_eq(vec![Token::Read, Token::Write]);
+        assert_enumerator_eq(
+            Token::enumerator()
+                .flat_map(|token| {
+                    <Option<Enum3>>::enumerator().map(move |scope| Grant {
+                        token: token.clone(),
+                        scope,
+                    })
+                })
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(<Permission<Grant>>::ENUMERABLE_SIZE, 1 + 8 * 2);
+        assert_eq!(
+            <Permission<Grant>>::enumerator().nth(3),
+            Some(Permission::Granted(
+                Grant {
+                    token: Token::Read,
+                    scope: Some(Enum3::A),
+                },
+                false,
+            ))
+        );
+
+        assert_first_last_value::<Permission<Grant>>();
+        assert_next_value_direct::<Permission<Grant>>();
+        assert_previous_value_direct::<Permission<Grant>>();
+        assert_next_value_direct::<(Token, Grant)>();
+        assert_previous_value_direct::<Option<Token>>();
+
+        let grant = Grant {
+            token: Token::Write,
+            scope: Some(Enum3::C),
+        };
+        assert_eq!(Grant::enumerable_from_index(7), Some(grant.clone()));
+        assert_eq!(
+            Grant::enumerator_from(grant.clone()).collect::<Vec<_>>(),
+            vec![grant.clone()]
+        );
+        assert_eq!(
+            grant.clone().cycle_next(),
+            Grant {
+                token: Token::Read,
+                scope: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_next_value() {
+        assert_next_value_direct::<Enum0>();
+        assert_next_value_direct::<Enum3>();
+        assert_next_value_direct::<StructUnit>();
+        assert_next_value_direct::<StructUnitFieldsUnnamed>();
+        assert_next_value_direct::<Struct2>();
+        assert_next_value_direct::<StructTuple2>();
+        assert_next_value_direct::<ComplexEnum>();
+        assert_next_value_direct::<TrailingEmptyBranch>();
+        assert_next_value_direct::<StructWithEmptyField>();
+        assert_next_value_direct::<GenericEnum3<bool, Enum3>>();
+        assert_next_value_direct::<ProtocolOrder>();
+        assert_next_value_direct::<NoSizeGeneric<Option<bool>>>();
+        assert_next_value::<ComplexEnum>();
+
+        // carried over from the last field
+        assert_eq!(
+            ThreeU32 {
+                a: 0,
+                b: u32::MAX,
+                c: u32::MAX
+            }
+            .next_value(),
+            Some(ThreeU32 { a: 1, b: 0, c: 0 })
+        );
+        assert_eq!(ThreeU32::last_value().unwrap().next_value(), None);
+        // skips the uninhabited variant
+        assert_eq!(WideEnum::Wide(u64::MAX, true).next_value(), None);
+        assert_eq!(
+            WideEnum::Small(Enum3::C).next_value(),
+            Some(WideEnum::Wide(0, false))
+        );
+    }
+
+    #[test]
+    fn test_previous_value() {
+        assert_previous_value_direct::<Enum0>();
+        assert_previous_value_direct::<Enum3>();
+        assert_previous_value_direct::<StructUnit>();
+        assert_previous_value_direct::<StructUnitFieldsUnnamed>();
+        assert_previous_value_direct::<Struct2>();
+        assert_previous_value_direct::<StructTuple2>();
+        assert_previous_value_direct::<ComplexEnum>();
+        assert_previous_value_direct::<TrailingEmptyBranch>();
+        assert_previous_value_direct::<StructWithEmptyField>();
+        assert_previous_value_direct::<GenericEnum3<bool, Enum3>>();
+        assert_previous_value_direct::<ProtocolOrder>();
+        assert_previous_value_direct::<NoSizeGeneric<Option<bool>>>();
+        assert_previous_value::<ComplexEnum>();
+
+        // borrowed from the previous field
+        assert_eq!(
+            ThreeU32 { a: 1, b: 0, c: 0 }.previous_value(),
+            Some(ThreeU32 {
+                a: 0,
+                b: u32::MAX,
+                c: u32::MAX
+            })
+        );
+        assert_eq!(ThreeU32::first_value().unwrap().previous_value(), None);
+        assert_eq!(
+            WideEnum::Wide(0, false).previous_value(),
+            Some(WideEnum::Small(Enum3::C))
+        );
+    }
+
+    #[test]
+    fn test_cycle() {
+        assert_eq!(Enum3::A.cycle_next(), Enum3::B);
+        assert_eq!(Enum3::C.cycle_next(), Enum3::A);
+        assert_eq!(Enum3::A.cycle_prev(), Enum3::C);
+        assert_eq!(Enum3::C.cycle_prev(), Enum3::B);
+
+        let first = Struct2::first_value().unwrap();
+        let last = Struct2::last_value().unwrap();
+        assert_eq!(last.cycle_next(), first);
+        assert_eq!(first.cycle_prev(), last);
+        assert_eq!(first.cycle_next().cycle_prev(), first);
+
+        assert!(!true.cycle_next());
+        assert!(false.cycle_next());
+        assert!(false.cycle_prev());
+        assert_eq!(Some(Enum3::C).cycle_next(), None);
+        assert_eq!(None.cycle_next(), Some(Enum3::A));
+        assert_eq!(<Option<Enum3>>::None.cycle_prev(), Some(Enum3::C));
+
+        // a single value cycles to itself
+        assert_eq!(StructUnit.cycle_next(), StructUnit);
+        assert_eq!(StructUnit.cycle_prev(), StructUnit);
+
+        // cycles through all values
+        let mut value = ComplexEnum::first_value().unwrap();
+        for expected in ComplexEnum::enumerator()
+            .skip(1)
+            .chain(ComplexEnum::first_value())
+        {
+            value = value.cycle_next();
+            assert_eq!(value, expected);
+        }
+    }
+
+    #[test]
+    fn test_first_last_value() {
+        assert_first_last_value::<Enum0>();
+        assert_first_last_value::<Enum3>();
+        assert_first_last_value::<StructUnit>();
+        assert_first_last_value::<StructUnitFieldsNamed>();
+        assert_first_last_value::<StructUnitFieldsUnnamed>();
+        assert_first_last_value::<Struct2>();
+        assert_first_last_value::<StructTuple2>();
+        assert_first_last_value::<ComplexEnum>();
+        assert_first_last_value::<TrailingEmptyBranch>();
+        assert_first_last_value::<StructWithEmptyField>();
+        assert_first_last_value::<GenericEnum3<bool, Enum3>>();
+        assert_first_last_value::<ProtocolOrder>();
+
+        assert_eq!(
+            TrailingEmptyBranch::last_value(),
+            Some(TrailingEmptyBranch::Flag(true))
+        );
+        assert_eq!(StructWithEmptyField::last_value(), None);
+        assert_eq!(ProtocolOrder::last_value(), Some(ProtocolOrder::Close));
+
+        // computed directly
+        assert_eq!(
+            ThreeU32::last_value(),
+            Some(ThreeU32 {
+                a: u32::MAX,
+                b: u32::MAX,
+                c: u32::MAX
+            })
+        );
+        assert_eq!(WideEnum::last_value(), Some(WideEnum::Wide(u64::MAX, true)));
+    }
+
+    #[test]
+    fn test_max_size() {
+        assert_eq!(MaxSizeAtLimit::ENUMERABLE_SIZE, 12);
+        assert_eq!(MaxSizeAtLimit::enumerator().count(), 12);
+        assert_eq!(MaxSizeUnderLimit::ENUMERABLE_SIZE, 256 + 3);
+        assert_eq!(MaxSizeGeneric::<u8>::enumerator().count(), 256);
+        assert_eq!(MaxSizeGeneric::<Enum3>::ENUMERABLE_SIZE, 3);
+    }
+
+    #[test]
+    fn test_size_u128() {
+        assert_eq!(ThreeU32::ENUMERABLE_SIZE_OPTION_U128, Some(1 << 96));
+        assert_eq!(ThreeU32::ENUMERABLE_SIZE_OPTION, None);
+        assert_eq!(WideEnum::ENUMERABLE_SIZE_OPTION_U128, Some(3 + (1 << 65)));
+        assert_eq!(
+            ComplexEnum::ENUMERABLE_SIZE_OPTION_U128,
+            Some(ComplexEnum::ENUMERABLE_SIZE as u128)
+        );
+        assert_eq!(NoSizeStruct::ENUMERABLE_SIZE_OPTION_U128, None);
+    }
+
+    #[test]
+    fn test_no_size() {
+        assert_eq!(NoSizeStruct::ENUMERABLE_SIZE_OPTION, None);
+        assert_eq!(
+            collect_all::<NoSizeStruct>(),
+            <(bool, Enum3)>::enumerator()
+                .map(|(flag, choice)| NoSizeStruct { flag, choice })
+                .collect::<Vec<_>>()
+        );
+
+        assert_eq!(NoSizeGeneric::<bool>::ENUMERABLE_SIZE_OPTION, None);
+        assert_eq!(
+            collect_all::<NoSizeGeneric<bool>>(),
+            vec![
+                NoSizeGeneric::Empty,
+                NoSizeGeneric::Value(false),
+                NoSizeGeneric::Value(true),
+            ]
+        );
+
+        // types containing a type with unreported size report no size either
+        assert_eq!(<Option<NoSizeStruct>>::ENUMERABLE_SIZE_OPTION, None);
+        assert_eq!(<Option<NoSizeStruct>>::enumerator().count(), 7);
+    }
+
+    fn is_inhabited<T: Enumerable>() -> bool {
+        T::IS_INHABITED
+    }
+
+    #[test]
+    fn test_is_inhabited() {
+        assert!(is_inhabited::<u8>());
+        assert!(is_inhabited::<()>());
+        assert!(is_inhabited::<Option<Enum0>>());
+        assert!(is_inhabited::<Result<Enum0, bool>>());
+        assert!(is_inhabited::<(u128, u128)>());
+        assert!(is_inhabited::<StructUnit>());
+        assert!(is_inhabited::<ComplexEnum>());
+        assert!(is_inhabited::<TrailingEmptyBranch>());
+        assert!(is_inhabited::<NoSizeStruct>());
+        assert!(is_inhabited::<NoSizeGeneric<Enum0>>());
+
+        assert!(!is_inhabited::<Enum0>());
+        assert!(!is_inhabited::<StructWithEmptyField>());
+        assert!(!is_inhabited::<Result<Enum0, Enum0>>());
+        assert!(!is_inhabited::<(Enum0,)>());
+        assert!(!is_inhabited::<(bool, Enum0, u8)>());
+        assert!(!is_inhabited::<GenericStruct1<Enum0>>());
+
+        // exact even if the size is unreported
+        assert_eq!(NoSizeEmpty::ENUMERABLE_SIZE_OPTION_U128, None);
+        assert!(!is_inhabited::<NoSizeEmpty>());
+        assert!(!is_inhabited::<Result<NoSizeEmpty, Enum0>>());
+        assert_eq!(NoSizeEmpty::first_value(), None);
+        assert_eq!(NoSizeEmpty::last_value(), None);
+        assert_eq!(NoSizeEmpty::enumerable_from_index(0), None);
+        assert_eq!(NoSizeEmpty::enumerator_since(5).next(), None);
+        assert_eq!(NoSizeEmpty::enumerator_reversed().size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_sort_by_discriminant() {
+        assert_enumerator_eq_with_size_hint(vec![
+            ProtocolOrder::Open,
+            ProtocolOrder::Data,
+            ProtocolOrder::Ping,
+            ProtocolOrder::Close,
+        ]);
+        assert_enumerator_eq_with_size_hint(vec![
+            MixedDiscriminants::MinusTwo,
+            MixedDiscriminants::MinusOne,
+            MixedDiscriminants::Zero,
+            MixedDiscriminants::Five,
+            MixedDiscriminants::Ten,
+            MixedDiscriminants::Eleven,
+        ]);
+
+        let discriminants = MixedDiscriminants::enumerator()
+            .map(|v| v as i8)
+            .collect::<Vec<_>>();
+        assert_eq!(discriminants, vec![-2, -1, 0, 5, 10, 11]);
+        assert!(ProtocolOrder::enumerator()
+            .zip(ProtocolOrder::enumerator().skip(1))
+            .all(|(a, b)| (a as u16) < (b as u16)));
+    }
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    enum Command {
+        Move(Enum3, bool),
+        Stop,
+        // `String` isn't `Enumerable`, which is fine for a skipped variant
+        #[enumerable(skip)]
+        Debug(String),
+        Wait(Option<bool>),
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    enum Light {
+        Red,
+        #[enumerable(skip)]
+        Internal,
+        Green,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    enum OnlySkipped {
+        #[enumerable(skip)]
+        Internal,
+        #[enumerable(skip)]
+        Raw(String),
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    #[enumerable(sort_by_discriminant)]
+    #[repr(u8)]
+    enum Priority {
+        High = 2,
+        #[enumerable(skip)]
+        Reserved,
+        Low = 0,
+    }
+
+    // skipped variants may use the generic parameters, leaving no fields to enumerate
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    enum Toggle<'a, T> {
+        Off,
+        On,
+        #[enumerable(skip)]
+        Custom(core::marker::PhantomData<T>, &'a str),
+    }
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    enum Opaque<T> {
+        #[enumerable(skip)]
+        Inner(T),
+    }
+
+    #[test]
+    fn test_skip_variants() {
+        let expected = Enum3::enumerator()
+            .flat_map(|a| [false, true].map(|b| Command::Move(a, b)))
+            .chain([Command::Stop])
+            .chain(Option::<bool>::enumerator().map(Command::Wait))
+            .collect::<Vec<_>>();
+        assert_eq!(Command::ENUMERABLE_SIZE, 6 + 1 + 3);
+        assert_enumerator_eq_with_size_hint(expected);
+        assert!(!Command::enumerator().any(|c| matches!(c, Command::Debug(_))));
+        assert_eq!(<Command as crate::SumEnumerable>::VARIANT_SIZES, [6, 1, 3]);
+
+        // values of skipped variants are not in the enumeration
+        let debug = Command::Debug("dump".into());
+        assert_eq!(debug.clone().next_value(), None);
+        assert_eq!(debug.clone().previous_value(), None);
+        assert_eq!(Command::enumerator_from(debug).next(), None);
+        assert_eq!(Command::Stop.next_value(), Some(Command::Wait(None)));
+
+        assert_enumerator_eq_with_size_hint(vec![Light::Red, Light::Green]);
+        assert_eq!(Light::ENUMERABLE_SIZE, 2);
+        assert_eq!(Light::VALUES, &[Light::Red, Light::Green]);
+        assert_eq!(Light::Red.next_value(), Some(Light::Green));
+        assert_eq!(Light::Internal.next_value(), None);
+        assert_eq!(Light::Internal.previous_value(), None);
+        assert_eq!(Light::enumerator_from(Light::Internal).next(), None);
+        assert_eq!(
+            Light::enumerator_reversed().collect::<Vec<_>>(),
+            vec![Light::Green, Light::Red]
+        );
+
+        assert_eq!(OnlySkipped::ENUMERABLE_SIZE, 0);
+        assert_eq!(OnlySkipped::enumerator().next(), None);
+        assert_eq!(OnlySkipped::Internal.next_value(), None);
+        assert_eq!(OnlySkipped::Raw("raw".into()).previous_value(), None);
+        assert!(!is_inhabited::<OnlySkipped>());
+        assert_eq!(OnlySkipped::enumerator_reversed().next(), None);
+
+        assert_enumerator_eq_with_size_hint(vec![Toggle::<String>::Off, Toggle::On]);
+        assert_exact_size::<Toggle<String>>();
+        assert_first_last_value::<Toggle<String>>();
+        assert_next_value_direct::<Toggle<String>>();
+        assert_previous_value_direct::<Toggle<String>>();
+        assert_eq!(Toggle::<String>::enumerable_from_index(1), Some(Toggle::On));
+        assert_eq!(
+            Toggle::<String>::enumerable_to_index(&Toggle::Custom(core::marker::PhantomData, "x")),
+            None
+        );
+        assert_eq!(
+            Toggle::<String>::enumerator_reversed().collect::<Vec<_>>(),
+            vec![Toggle::On, Toggle::Off]
+        );
+        assert_eq!(
+            Toggle::<String>::enumerator_from(Toggle::On).collect::<Vec<_>>(),
+            vec![Toggle::On]
+        );
+
+        assert_eq!(Opaque::<String>::ENUMERABLE_SIZE, 0);
+        assert_eq!(Opaque::<String>::enumerator().next(), None);
+        assert_eq!(Opaque::Inner(String::new()).next_value(), None);
+
+        // `Reserved` is 3, following `High`
+        assert_enumerator_eq_with_size_hint(vec![Priority::Low, Priority::High]);
+        assert_eq!(Priority::Reserved as u8, 3);
+        assert_eq!(
+            Priority::enumerator_reversed().collect::<Vec<_>>(),
+            vec![Priority::High, Priority::Low]
+        );
+    }
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    struct Event {
+        kind: Enum3,
+        #[enumerable(skip, default = 42)]
+        id: u64,
+        urgent: bool,
+        // `String` isn't `Enumerable`, which is fine for a skipped field
+        #[enumerable(skip, default = String::from("now"))]
+        timestamp: String,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    struct Sample(bool, #[enumerable(skip, default = 7)] u32, Option<bool>);
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    struct Pinned {
+        #[enumerable(skip, default = 3)]
+        value: u8,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    struct Named {
+        #[enumerable(skip, default = String::from("x"))]
+        name: String,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    struct Tagged<T>(
+        #[enumerable(skip, default = core::marker::PhantomData)] core::marker::PhantomData<T>,
+    );
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    struct Annotated<T> {
+        value: bool,
+        #[enumerable(skip, default = None)]
+        note: Option<T>,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    enum Message {
+        Ping {
+            #[enumerable(skip, default = 0)]
+            seq: u32,
+            ack: bool,
+        },
+        Data(#[enumerable(skip, default = vec![1, 2])] Vec<u8>, Enum3),
+        Close(#[enumerable(skip, default = "bye")] &'static str),
+    }
+
+    #[test]
+    fn test_skip_fields() {
+        let event = |kind, urgent| Event {
+            kind,
+            id: 42,
+            urgent,
+            timestamp: "now".into(),
+        };
+        let expected = Enum3::enumerator()
+            .flat_map(|kind| [event(kind, false), event(kind, true)])
+            .collect::<Vec<_>>();
+        assert_eq!(Event::ENUMERABLE_SIZE, 6);
+        assert_enumerator_eq_with_size_hint(expected);
+        assert!(Event::enumerator().all(|e| e.id == 42 && e.timestamp == "now"));
+        assert_eq!(Event::enumerable_from_index(5), Some(event(Enum3::C, true)));
+
+        // skipped fields of the given values are ignored, and set to the defaults
+        let other = Event {
+            id: 1,
+            timestamp: "later".into(),
+            ..event(Enum3::A, true)
+        };
+        assert_eq!(other.clone().next_value(), Some(event(Enum3::B, false)));
+        assert_eq!(other.clone().previous_value(), Some(event(Enum3::A, false)));
+        assert_eq!(Event::enumerable_to_index(&other), Some(1));
+        assert_eq!(
+            Event::enumerable_to_index(&other),
+            Event::enumerable_to_index(&event(Enum3::A, true))
+        );
+        assert_eq!(
+            Event::enumerator_from(other).collect::<Vec<_>>(),
+            Event::enumerator().skip(1).collect::<Vec<_>>(),
+        );
+
+        assert_eq!(Sample::ENUMERABLE_SIZE, 6);
+        assert_enumerator_eq_with_size_hint(
+            [false, true]
+                .into_iter()
+                .flat_map(|a| Option::<bool>::enumerator().map(move |b| Sample(a, 7, b)))
+                .collect::<Vec<_>>(),
+        );
+
+        assert_eq!(collect_all::<Pinned>(), vec![Pinned { value: 3 }]);
+        // values of structs with all fields skipped are treated as the only enumerated one
+        assert_eq!(
+            Pinned::enumerator_from(Pinned { value: 9 }).collect::<Vec<_>>(),
+            vec![Pinned { value: 3 }]
+        );
+        assert_eq!(Pinned::enumerable_to_index(&Pinned { value: 9 }), Some(0));
+        assert_eq!(Pinned { value: 9 }.next_value(), None);
+
+        // non-constant defaults and generic parameters are fine with all fields skipped
+        assert_enumerator_eq_with_size_hint(vec![Named { name: "x".into() }]);
+        assert_eq!(
+            Named::enumerator_from(Named { name: "y".into() }).collect::<Vec<_>>(),
+            vec![Named { name: "x".into() }]
+        );
+        assert_enumerator_eq_with_size_hint(vec![Tagged::<String>(core::marker::PhantomData)]);
+
+        // `String` is only used in a skipped field, so it needn't be `Enumerable`
+        assert_enumerator_eq_with_size_hint(vec![
+            Annotated::<String> {
+                value: false,
+                note: None,
+            },
+            Annotated {
+                value: true,
+                note: None,
+            },
+        ]);
+
+        let mut expected = vec![
+            Message::Ping { seq: 0, ack: false },
+            Message::Ping { seq: 0, ack: true },
+        ];
+        expected.extend(Enum3::enumerator().map(|e| Message::Data(vec![1, 2], e)));
+        expected.push(Message::Close("bye"));
+        assert_eq!(Message::ENUMERABLE_SIZE, 6);
+        assert_enumerator_eq_with_size_hint(expected);
+        assert_eq!(
+            Message::Ping { seq: 9, ack: true }.next_value(),
+            Some(Message::Data(vec![1, 2], Enum3::A)),
+        );
+        assert_eq!(
+            Message::Close("hi").previous_value(),
+            Some(Message::Data(vec![1, 2], Enum3::C)),
+        );
+    }
+
+    #[derive(Copy, Clone, Debug, Eq, PartialEq, Enumerable)]
+    struct UnitStruct;
+
+    #[test]
+    fn test_derive_unit_struct() {
+        assert_eq!(collect_all::<UnitStruct>(), vec![UnitStruct]);
+    }
+}
+
+mod tuple {
+    use super::*;
+
+    #[test]
+    fn test_tuple0() {
+        assert_eq!(vec![()], collect_all::<()>());
+    }
+
+    #[test]
+    fn test_tuple1() {
+        assert_eq!(vec![(false,), (true,)], collect_all::<(bool,)>());
+    }
+
+    #[test]
+    fn test_tuple_first_last_value() {
+        assert_first_last_value::<()>();
+        assert_first_last_value::<(Enum3,)>();
+        assert_first_last_value::<(Enum0,)>();
+        assert_first_last_value::<(bool, Enum3)>();
+        assert_first_last_value::<(bool, Enum0, Enum3)>();
+        assert_first_last_value::<(Enum3, Option<bool>, Result<bool, Enum0>, u8)>();
+
+        assert_eq!(
+            <(u32, i64, bool)>::last_value(),
+            Some((u32::MAX, i64::MAX, true))
+        );
+    }
+
+    #[test]
+    fn test_tuple_next_value() {
+        assert_next_value_direct::<()>();
+        assert_next_value_direct::<(Enum3,)>();
+        assert_next_value_direct::<(bool, Enum3)>();
+        assert_next_value_direct::<(bool, Enum0, Enum3)>();
+        assert_next_value_direct::<(Enum3, Option<bool>, Result<bool, Enum0>, u8)>();
+
+        assert_eq!((0u8, u32::MAX).next_value(), Some((1, 0)));
+        assert_eq!((u8::MAX, u32::MAX).next_value(), None);
+    }
+
+    #[test]
+    fn test_tuple_previous_value() {
+        assert_previous_value_direct::<()>();
+        assert_previous_value_direct::<(Enum3,)>();
+        assert_previous_value_direct::<(bool, Enum3)>();
+        assert_previous_value_direct::<(bool, Enum0, Enum3)>();
+        assert_previous_value_direct::<(Enum3, Option<bool>, Result<bool, Enum0>, u8)>();
+
+        assert_eq!((1u8, 0u32).previous_value(), Some((0, u32::MAX)));
+        assert_eq!((0u8, 0u32).previous_value(), None);
+    }
+
+    #[test]
+    fn test_tuple_exact_size() {
+        assert_exact_size::<()>();
+        assert_exact_size::<(Enum3,)>();
+        assert_exact_size::<(bool, Enum3)>();
+        assert_exact_size::<(bool, Enum0, Enum3)>();
+        assert_exact_size::<(Enum3, Option<bool>, i8, Struct2)>();
+        assert_eq!(<(u8, u16)>::enumerator().len(), 256 * 65536);
+
+        let mut iter = <(Enum4, u8, bool)>::enumerator();
+        iter.nth(1000);
+        assert_eq!(iter.len(), 4 * 256 * 2 - 1001);
+        assert_eq!(<(u64, bool)>::enumerator().size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn test_tuple_enumerator_clone_and_debug() {
+        let mut iter = <(bool, Enum3)>::enumerator();
+        iter.nth(2);
+        let cloned = iter.clone();
+        assert_eq!(cloned.collect::<Vec<_>>(), iter.collect::<Vec<_>>());
+
+        let mut iter = <(bool, Enum3)>::enumerator();
+        iter.nth(2);
+        assert_eq!(iter.clone().next(), Some((true, Enum3::A)));
+        assert_eq!(iter.clone().count(), 3);
+        assert!(format!("{iter:?}").starts_with("Tuple2Enumerator {"));
+        assert!(format!("{iter:?}").contains("next: Some((true, A))"));
+
+        let mut iter = <(Enum4,)>::enumerator();
+        iter.next();
+        assert_eq!(iter.clone().collect::<Vec<_>>(), iter.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_tuple_from_index() {
+        fn assert_from_index_eq_nth<T: Enumerable + PartialEq + std::fmt::Debug>() {
+            let values = collect_all::<T>();
+            for (index, value) in values.iter().enumerate() {
+                assert_eq!(T::enumerable_from_index(index).as_ref(), Some(value));
+            }
+            assert_eq!(T::enumerable_from_index(values.len()), None);
+            assert_eq!(T::enumerable_from_index(usize::MAX), None);
+        }
+
+        assert_from_index_eq_nth::<(bool,)>();
+        assert_from_index_eq_nth::<(Enum4,)>();
+        assert_from_index_eq_nth::<(bool, Enum3)>();
+        assert_from_index_eq_nth::<(Enum3, bool, Enum4)>();
+        assert_from_index_eq_nth::<(Enum4, (), Option<bool>, Enum3)>();
+
+        // 1_000_000 = 15 * 256 * 256 + 66 * 256 + 64
+        assert_eq!(
+            <(u8, u8, u8)>::enumerable_from_index(1_000_000),
+            Some((15, 66, 64))
+        );
+        assert_eq!(
+            <(u32, bool)>::enumerable_from_index((1 << 33) - 1),
+            Some((u32::MAX, true))
+        );
+        assert_eq!(<(u32, bool)>::enumerable_from_index(1 << 33), None);
+
+        // `(u64, u64)` has more values than `u128` can count, so it falls back to stepping.
+        assert_eq!(<(u64, u64)>::ENUMERABLE_SIZE_OPTION_U128, None);
+        assert_eq!(<(u64, u64)>::enumerable_from_index(5), Some((0, 5)));
+    }
+
+    #[test]
+    fn test_tuple_enumerator_since() {
+        assert_since_eq_skip_all::<(bool,)>();
+        assert_since_eq_skip_all::<(Enum3, bool, Enum4)>();
+        assert_since_eq_skip_all::<(Enum4, (), Option<bool>, Enum3)>();
+        assert_since_eq_skip_all::<(bool, Enum0)>();
+        assert_since_eq_skip_all::<(NoSizeStruct, bool)>();
+
+        // positioned without replaying the values before `start`
+        let mut iter = <(u16, u16)>::enumerator_since(100_000);
+        assert_eq!(iter.next(), Some((1, 34_464)));
+        assert_eq!(iter.len(), (1 << 32) - 100_001);
+        assert_eq!(
+            <(u32, u32, bool)>::enumerator_since(usize::MAX).next(),
+            Some(((1 << 31) - 1, u32::MAX, true))
+        );
+    }
+
+    #[test]
+    fn test_tuple_nth() {
+        fn assert_nth_eq_skip<T: Enumerable + PartialEq + std::fmt::Debug>() {
+            let size = T::enumerator().count();
+            for start in 0..=size {
+                for n in 0..=size - start + 1 {
+                    let mut iter = T::enumerator();
+                    let mut skipped = T::enumerator().skip(start);
+                    if start > 0 {
+                        iter.nth(start - 1);
+                    }
+                    assert_eq!(iter.nth(n), skipped.nth(n), "start = {start}, n = {n}");
+                    assert!(iter.eq(skipped), "start = {start}, n = {n}");
+                }
+            }
+        }
+
+        assert_nth_eq_skip::<(Enum4,)>();
+        assert_nth_eq_skip::<(Enum3, bool, Enum4)>();
+        assert_nth_eq_skip::<(bool, Enum0)>();
+        assert_nth_eq_skip::<(NoSizeStruct, bool)>();
+
+        // it jumps by index, so it's fast even without optimizations
+        let mut iter = <(u16, u16)>::enumerator();
+        assert_eq!(iter.nth(100_000), Some((1, 34_464)));
+        assert_eq!(iter.nth(usize::MAX), None);
+        assert_eq!(iter.next(), None);
+
+        let mut iter = <(u32, u16, bool)>::enumerator();
+        assert_eq!(iter.nth(1 << 40), Some((1 << 23, 0, false)));
+        assert_eq!(iter.next(), Some((1 << 23, 0, true)));
+        assert_eq!(
+            iter.by_ref().skip(1 << 45).take(2).collect::<Vec<_>>(),
+            vec![(1 << 23 | 1 << 28, 1, false), (1 << 23 | 1 << 28, 1, true)]
+        );
+    }
+
+    #[test]
+    fn test_tuple2() {
+        // Illustrate the return order of the enumerator.
+        assert_eq!(
+            vec![(0, false), (0, true), (1, false), (1, true), (2, false)],
+            <(u8, bool)>::enumerator().take(5).collect::<Vec<_>>()
+        );
+
+        // Verify that the enumerator returns all possible values, with exact size hints.
+        assert_enumerator_eq_with_size_hint(
+            (0u8..=0xff).flat_map(|a| [false, true].into_iter().map(move |b| (a, b))),
+        );
+        assert_enumerator_eq_with_size_hint(
+            Enum3::enumerator().flat_map(|a| Enum4::enumerator().map(move |b| (a, b))),
+        );
+    }
+
+    #[test]
+    fn test_tuple16() {
+        type Tuple16 = (
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+        );
+        let tuple16_to_u16 = |t: Tuple16| {
+            let mut result = 0u16;
+            if t.0 {
+                result |= 1 << 15;
+            }
+            if t.1 {
+                result |= 1 << 14;
+            }
+            if t.2 {
+                result |= 1 << 13;
+            }
+            if t.3 {
+                result |= 1 << 12;
+            }
+            if t.4 {
+                result |= 1 << 11;
+            }
+            if t.5 {
+                result |= 1 << 10;
+            }
+            if t.6 {
+                result |= 1 << 9;
+            }
+            if t.7 {
+                result |= 1 << 8;
+            }
+            if t.8 {
+                result |= 1 << 7;
+            }
+            if t.9 {
+                result |= 1 << 6;
+            }
+            if t.10 {
+                result |= 1 << 5;
+            }
+            if t.11 {
+                result |= 1 << 4;
+            }
+            if t.12 {
+                result |= 1 << 3;
+            }
+            if t.13 {
+                result |= 1 << 2;
+            }
+            if t.14 {
+                result |= 1 << 1;
+            }
+            if t.15 {
+                result |= 1 << 0;
+            }
+            result
+        };
+
+        let collected_as_u16 = <Tuple16 as Enumerable>::enumerator()
+            .map(tuple16_to_u16)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            collected_as_u16,
+            <u16 as Enumerable>::enumerator().collect::<Vec<_>>()
+        );
+
+        // `Bits<16>` enumerates the same patterns, with the first element as the highest bit
+        assert!(crate::Bits::<16>::enumerator()
+            .map(|bits| bits.to_u64() as u16)
+            .eq(collected_as_u16));
+    }
+
+    // the generator itself has no limit on the number of elements, with type parameters named
+    // `A`, ..., `Z`, `AA`, `AB`, ...
+    type Tuple27 = (
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+        bool,
+    );
+
+    enumerable_derive::__impl_enumerable_for_tuples!(27, 27);
+
+    #[test]
+    fn test_tuple27() {
+        assert_eq!(Tuple27::ENUMERABLE_SIZE, 1 << 27);
+        assert_eq!(Tuple27::enumerator().len(), 1 << 27);
+
+        let value = Tuple27::enumerable_from_index(1).unwrap();
+        assert!(!value.0 && !value.25 && value.26);
+        let value = Tuple27::enumerator().nth(2).unwrap();
+        assert!(value.25 && !value.26);
+        let next = value.next_value_direct().unwrap().unwrap();
+        assert!(next.25 && next.26);
+        assert!(Tuple27::last_value().is_some_and(|last| last.0 && last.26));
+    }
+
+    #[cfg(feature = "large-tuples")]
+    #[test]
+    fn test_tuple20() {
+        type Tuple20 = (
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+            bool,
+        );
+
+        assert_eq!(Tuple20::ENUMERABLE_SIZE, 1 << 20);
+        assert_eq!(Tuple20::enumerator().count(), 1 << 20);
+        assert_eq!(Tuple20::enumerator().len(), 1 << 20);
+
+        // tuples with more than 12 elements don't implement `PartialEq`, so fields are compared
+        let value = Tuple20::enumerable_from_index(1).unwrap();
+        assert!(!value.0 && !value.18 && value.19);
+        let value = Tuple20::enumerator().nth((1 << 19) + 1).unwrap();
+        assert!(value.0 && !value.1 && !value.18 && value.19);
+        assert!(Tuple20::enumerator()
+            .last()
+            .is_some_and(|last| last.0 && last.19));
+    }
+}
+
+mod array {
+    use super::*;
+
+    #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    struct Board {
+        cells: [Option<bool>; 2],
+        turn: bool,
+    }
+
+    #[test]
+    fn test_array() {
+        assert_eq!(collect_all::<[bool; 0]>(), vec![[false; 0]]);
+        assert_eq!(<[bool; 0]>::ENUMERABLE_SIZE_OPTION, Some(1));
+        assert_eq!(
+            collect_all::<[bool; 3]>(),
+            collect_all::<(bool, bool, bool)>()
+                .into_iter()
+                .map(|(a, b, c)| [a, b, c])
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            collect_all::<[Enum3; 2]>()[..4],
+            [
+                [Enum3::A, Enum3::A],
+                [Enum3::A, Enum3::B],
+                [Enum3::A, Enum3::C],
+                [Enum3::B, Enum3::A]
+            ]
+        );
+
+        assert!(collect_all::<[Enum0; 2]>().is_empty());
+        assert_eq!(<[Enum0; 2]>::ENUMERABLE_SIZE_OPTION, Some(0));
+        const { assert!(!<[Enum0; 2]>::IS_INHABITED) };
+        const { assert!(<[Enum0; 0]>::IS_INHABITED) };
+
+        assert_eq!(<[u8; 4]>::ENUMERABLE_SIZE_OPTION_U128, Some(1 << 32));
+        assert_eq!(<[u64; 2]>::ENUMERABLE_SIZE_OPTION_U128, None);
+        assert_eq!(<[u64; 2]>::ENUMERABLE_SIZE_OPTION, None);
+        assert_eq!(<[(); 1000]>::ENUMERABLE_SIZE_OPTION, Some(1));
+    }
+
+    #[test]
+    fn test_array_first_last_value() {
+        assert_first_last_value::<[bool; 0]>();
+        assert_first_last_value::<[Enum3; 2]>();
+        assert_first_last_value::<[Enum0; 2]>();
+        assert_first_last_value::<[Option<bool>; 3]>();
+
+        assert_eq!(<[u32; 3]>::last_value(), Some([u32::MAX; 3]));
+    }
+
+    #[test]
+    fn test_array_next_previous_value() {
+        assert_next_value_direct::<[bool; 0]>();
+        assert_next_value_direct::<[Enum3; 2]>();
+        assert_next_value_direct::<[Option<bool>; 3]>();
+        assert_previous_value_direct::<[bool; 0]>();
+        assert_previous_value_direct::<[Enum3; 2]>();
+        assert_previous_value_direct::<[Option<bool>; 3]>();
+
+        assert_eq!([0u8, u8::MAX].next_value(), Some([1, 0]));
+        assert_eq!([u8::MAX; 2].next_value(), None);
+        assert_eq!([1u8, 0].previous_value(), Some([0, u8::MAX]));
+    }
+
+    #[test]
+    fn test_array_exact_size() {
+        assert_exact_size::<[bool; 0]>();
+        assert_exact_size::<[Enum3; 3]>();
+        assert_exact_size::<[Enum0; 2]>();
+
+        let mut iter = <[u8; 2]>::enumerator();
+        iter.nth(1000);
+        assert_eq!(iter.len(), 65536 - 1001);
+        assert_eq!(<[u64; 2]>::enumerator().size_hint(), (usize::MAX, None));
+    }
+
+    #[test]
+    fn test_array_from_index() {
+        let all = collect_all::<[Option<bool>; 3]>();
+        for (index, value) in all.iter().enumerate() {
+            assert_eq!(
+                <[Option<bool>; 3]>::enumerable_from_index(index),
+                Some(*value)
+            );
+            assert_eq!(
+                <[Option<bool>; 3]>::enumerator_from(*value).collect::<Vec<_>>(),
+                all[index..]
+            );
+        }
+        assert_eq!(<[Option<bool>; 3]>::enumerable_from_index(all.len()), None);
+        assert_eq!(<[bool; 0]>::enumerable_from_index(0), Some([false; 0]));
+        assert_eq!(<[bool; 0]>::enumerable_from_index(1), None);
+        assert_eq!(<[Enum0; 2]>::enumerable_from_index(0), None);
+        assert_eq!(
+            <[u64; 3]>::enumerable_from_index_u128((5 << 64) + 6),
+            Some([0, 5, 6])
+        );
+    }
+
+    #[test]
+    fn test_derive_with_array_field() {
+        assert_eq!(Board::ENUMERABLE_SIZE_OPTION, Some(3 * 3 * 2));
+        assert_eq!(
+            Board::enumerator().nth(5),
+            Some(Board {
+                cells: [None, Some(true)],
+                turn: true
+            })
+        );
+        assert_eq!(Board::enumerator().count(), 18);
+    }
+}
+
+mod wrapper {
+    use super::*;
+    use crate::ReversedEnumerator;
+    use core::cmp::Reverse;
+    use core::mem::ManuallyDrop;
+    use core::num::{Saturating, Wrapping};
+
+    #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    struct Checksum {
+        sum: Wrapping<u8>,
+        valid: bool,
+    }
+
+    #[test]
+    fn test_wrapping() {
+        assert_eq!(
+            collect_all::<Wrapping<u8>>(),
+            collect_all::<u8>()
+                .into_iter()
+                .map(Wrapping)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            collect_all::<Wrapping<Enum3>>(),
+            vec![Wrapping(Enum3::A), Wrapping(Enum3::B), Wrapping(Enum3::C)]
+        );
+        assert!(collect_all::<Wrapping<Enum0>>().is_empty());
+        assert_exact_size::<Wrapping<u8>>();
+        assert_first_last_value::<Wrapping<Enum3>>();
+        assert_next_value_direct::<Wrapping<Option<bool>>>();
+        assert_previous_value_direct::<Wrapping<Option<bool>>>();
+
+        assert_eq!(Wrapping::<u16>::ENUMERABLE_SIZE_OPTION, Some(65536));
+        assert_eq!(
+            Wrapping::<u64>::ENUMERABLE_SIZE_OPTION_U128,
+            u64::ENUMERABLE_SIZE_OPTION_U128
+        );
+        assert_eq!(
+            Wrapping::<i32>::enumerable_from_index(0),
+            Some(Wrapping(i32::MIN))
+        );
+        assert_eq!(
+            Wrapping::<u128>::enumerable_from_index_u128(u128::MAX),
+            Some(Wrapping(u128::MAX))
+        );
+        assert_eq!(
+            Wrapping::<u32>::enumerator_since(u32::MAX as usize).collect::<Vec<_>>(),
+            vec![Wrapping(u32::MAX)]
+        );
+        assert_eq!(
+            Wrapping::<u8>::enumerator_from(Wrapping(254)).collect::<Vec<_>>(),
+            vec![Wrapping(254), Wrapping(255)]
+        );
+        assert_eq!(
+            Wrapping::<u8>::enumerator_reversed().next(),
+            Some(Wrapping(255))
+        );
+    }
+
+    #[test]
+    fn test_saturating() {
+        assert_eq!(
+            collect_all::<Saturating<i8>>(),
+            collect_all::<i8>()
+                .into_iter()
+                .map(Saturating)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            collect_all::<Saturating<Option<bool>>>(),
+            collect_all::<Option<bool>>()
+                .into_iter()
+                .map(Saturating)
+                .collect::<Vec<_>>()
+        );
+        assert_exact_size::<Saturating<Enum4>>();
+        assert_first_last_value::<Saturating<Enum3>>();
+        assert_next_value_direct::<Saturating<Enum3>>();
+        assert_previous_value_direct::<Saturating<Enum3>>();
+
+        assert_eq!(
+            Saturating::<ComplexEnum>::ENUMERABLE_SIZE_OPTION,
+            ComplexEnum::ENUMERABLE_SIZE_OPTION
+        );
+        for (index, value) in collect_all::<Saturating<ComplexEnum>>()
+            .into_iter()
+            .enumerate()
+        {
+            assert_eq!(
+                Saturating::<ComplexEnum>::enumerable_from_index(index),
+                Some(value)
+            );
+        }
+        assert_eq!(
+            Saturating::<u16>::enumerator_since(65535).collect::<Vec<_>>(),
+            vec![Saturating(u16::MAX)]
+        );
+        assert_ordered::<Saturating<u8>>();
+    }
+
+    #[test]
+    fn test_manually_drop() {
+        #[derive(Clone, Debug, PartialEq, Enumerable)]
+        struct Handle {
+            id: ManuallyDrop<u8>,
+            owned: bool,
+        }
+
+        assert_eq!(
+            collect_all::<ManuallyDrop<ComplexEnum>>(),
+            collect_all::<ComplexEnum>()
+                .into_iter()
+                .map(ManuallyDrop::new)
+                .collect::<Vec<_>>()
+        );
+        assert_exact_size::<ManuallyDrop<u8>>();
+        assert_first_last_value::<ManuallyDrop<Option<bool>>>();
+        assert_next_value_direct::<ManuallyDrop<ComplexEnum>>();
+        assert_previous_value_direct::<ManuallyDrop<ComplexEnum>>();
+        assert_eq!(
+            ManuallyDrop::<u32>::ENUMERABLE_SIZE_OPTION,
+            u32::ENUMERABLE_SIZE_OPTION
+        );
+        assert_eq!(
+            ManuallyDrop::<u32>::enumerable_from_index(1000),
+            Some(ManuallyDrop::new(1000))
+        );
+        assert_eq!(
+            ManuallyDrop::<u32>::enumerator_since(u32::MAX as usize).count(),
+            1
+        );
+
+        assert_eq!(Handle::ENUMERABLE_SIZE_OPTION, Some(512));
+        assert_eq!(
+            Handle::enumerator().nth(3),
+            Some(Handle {
+                id: ManuallyDrop::new(1),
+                owned: true
+            })
+        );
+    }
+
+    #[test]
+    fn test_wrapper_forwarding() {
+        for (index, value) in collect_all::<Saturating<Option<Enum3>>>()
+            .iter()
+            .enumerate()
+        {
+            assert_eq!(
+                Saturating::<Option<Enum3>>::enumerable_to_index(value),
+                Some(index)
+            );
+        }
+        assert_eq!(
+            ManuallyDrop::<i16>::enumerable_to_index(&ManuallyDrop::new(0)),
+            Some(1 << 15)
+        );
+        assert_eq!(
+            Wrapping::<u128>::enumerable_to_index(&Wrapping(1 << 100)),
+            None
+        );
+        assert_eq!(
+            Wrapping::<u128>::enumerable_to_index_direct(&Wrapping(1 << 100)),
+            Some(1 << 100)
+        );
+        // values without direct indices are found by stepping
+        assert_eq!(
+            Wrapping::<NoSizeStruct>::enumerable_to_index(&Wrapping(
+                NoSizeStruct::last_value().unwrap()
+            )),
+            Some(5)
+        );
+
+        assert!(Wrapping::<u8>::enumerator_reversed().eq(u8::enumerator_reversed().map(Wrapping)));
+        assert!(Saturating::<ComplexEnum>::enumerator_reversed()
+            .eq(ComplexEnum::enumerator_reversed().map(Saturating)));
+        assert!(ManuallyDrop::<NoSizeStruct>::enumerator_reversed()
+            .eq(NoSizeStruct::enumerator_reversed().map(ManuallyDrop::new)));
+        let mut iter = Wrapping::<Option<bool>>::enumerator_reversed();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.nth(2), Some(Wrapping(None)));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        // `u128` has `u128::MAX + 1` values, which are neither counted nor stepped through
+        let mut iter = Wrapping::<u128>::enumerator_reversed();
+        assert_eq!(iter.next(), Some(Wrapping(u128::MAX)));
+        assert_eq!(iter.nth(1 << 40), Some(Wrapping(u128::MAX - 1 - (1 << 40))));
+        assert_eq!(
+            ReversedEnumerator::<Saturating<i128>>::new().next(),
+            Some(Saturating(i128::MAX))
+        );
+        assert_eq!(Wrapping::<Enum0>::enumerator_reversed().next(), None);
+    }
+
+    #[test]
+    fn test_reverse() {
+        assert_eq!(
+            collect_all::<Reverse<bool>>(),
+            vec![Reverse(true), Reverse(false)]
+        );
+        let all = collect_all::<Reverse<u8>>();
+        assert_eq!(all, (0..=u8::MAX).rev().map(Reverse).collect::<Vec<_>>());
+        // the enumeration order is still ascending
+        assert_ordered::<Reverse<u8>>();
+
+        assert_exact_size::<Reverse<u8>>();
+        assert_exact_size::<Reverse<Enum3>>();
+        assert_first_last_value::<Reverse<u8>>();
+        assert_first_last_value::<Reverse<ComplexEnum>>();
+        assert_next_value_direct::<Reverse<u8>>();
+        assert_previous_value_direct::<Reverse<Option<bool>>>();
+        assert_eq!(Reverse(0u8).next_value(), None);
+        assert_eq!(Reverse(true).next_value(), Some(Reverse(false)));
+
+        for (index, value) in all.iter().enumerate() {
+            assert_eq!(Reverse::<u8>::enumerable_from_index(index), Some(*value));
+            assert_eq!(Reverse::<u8>::enumerable_to_index(value), Some(index));
+        }
+        assert_eq!(Reverse::<u8>::enumerable_from_index(256), None);
+        assert_eq!(
+            Reverse::<u128>::enumerable_from_index_u128(0),
+            Some(Reverse(u128::MAX))
+        );
+        assert_eq!(
+            Reverse::<NoSizeStruct>::enumerable_from_index(0),
+            NoSizeStruct::last_value().map(Reverse)
+        );
+        assert_eq!(
+            Reverse::<u8>::enumerator_from(Reverse(1)).collect::<Vec<_>>(),
+            vec![Reverse(1), Reverse(0)]
+        );
+        assert_eq!(
+            Reverse::<bool>::enumerator_reversed().collect::<Vec<_>>(),
+            vec![Reverse(false), Reverse(true)]
+        );
+
+        // positioned by the `u128` indices of `T` instead of stepping
+        assert_eq!(
+            Reverse::<u64>::enumerator_since(1 << 40).next(),
+            Some(Reverse(u64::MAX - (1 << 40)))
+        );
+        assert_eq!(
+            Reverse::<u64>::enumerator().nth(1 << 40),
+            Some(Reverse(u64::MAX - (1 << 40)))
+        );
+        assert_eq!(Reverse::<bool>::enumerator_since(2).next(), None);
+        assert_eq!(
+            Reverse::<u64>::enumerator_from(Reverse(5)).collect::<Vec<_>>(),
+            (0..=5).rev().map(Reverse).collect::<Vec<_>>()
+        );
+        let mut iter = Reverse::<u64>::enumerator_from(Reverse(0));
+        assert_eq!(iter.next(), Some(Reverse(0)));
+        assert_eq!(iter.next(), None);
+        let mut iter = Reverse::<(u64, bool)>::enumerator();
+        assert_eq!(iter.next(), Some(Reverse((u64::MAX, true))));
+        assert_eq!(iter.nth(2), Some(Reverse((u64::MAX - 1, false))));
+        for start in 0..=6 {
+            assert!(Reverse::<Option<Enum3>>::enumerator_since(start)
+                .eq(Reverse::<Option<Enum3>>::enumerator().skip(start)));
+        }
+        for value in collect_all::<Reverse<ComplexEnum>>() {
+            assert!(Reverse::enumerator_from(value)
+                .eq(Reverse::<ComplexEnum>::enumerator().skip_while(|v| *v != value)));
+        }
+    }
+
+    #[test]
+    fn test_derive_with_wrapping_field() {
+        assert_eq!(Checksum::ENUMERABLE_SIZE_OPTION, Some(512));
+        assert_eq!(
+            Checksum::enumerator().nth(3),
+            Some(Checksum {
+                sum: Wrapping(1),
+                valid: true
+            })
+        );
+        assert_eq!(Checksum::enumerator().count(), 512);
+    }
+}
+
+mod ascii {
+    use super::*;
+    use crate::{AsciiChar, ConstEnumerable};
+
+    #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    struct Token {
+        quoted: bool,
+        c: AsciiChar,
+    }
+
+    #[test]
+    fn test_ascii_char() {
+        let expected = (0..0x80)
+            .map(|code| AsciiChar::new(code).unwrap())
+            .collect::<Vec<_>>();
+        assert_enumerator_eq_with_size_hint(expected.clone());
+        assert_eq!(AsciiChar::VALUES, expected.as_slice());
+        assert_exact_size::<AsciiChar>();
+        assert_first_last_value::<AsciiChar>();
+        assert_next_value_direct::<AsciiChar>();
+        assert_previous_value_direct::<AsciiChar>();
+        for (index, c) in expected.iter().enumerate() {
+            assert_eq!(AsciiChar::enumerable_to_index(c), Some(index));
+            assert_eq!(AsciiChar::enumerable_from_index(index), Some(*c));
+        }
+        assert!(AsciiChar::enumerator_reversed().eq(expected.iter().rev().copied()));
+    }
+
+    #[test]
+    fn test_ascii_char_boundary() {
+        let del = AsciiChar::new(0x7F).unwrap();
+        assert_eq!(del, AsciiChar::MAX);
+        assert_eq!(del.to_char(), '\u{7F}');
+        assert_eq!(del.next_value(), None);
+        assert_eq!(AsciiChar::enumerable_from_index(0x7F), Some(del));
+        assert_eq!(AsciiChar::enumerable_from_index(0x80), None);
+        assert_eq!(AsciiChar::enumerable_from_index_u128(1 << 100), None);
+        assert_eq!(
+            AsciiChar::enumerator_since(0x7F).collect::<Vec<_>>(),
+            vec![del]
+        );
+        assert_eq!(AsciiChar::enumerator_since(0x80).next(), None);
+        assert_eq!(AsciiChar::enumerator_since(usize::MAX).next(), None);
+
+        // non-ASCII values are rejected
+        assert_eq!(AsciiChar::new(0x80), None);
+        assert_eq!(AsciiChar::new(u8::MAX), None);
+        assert_eq!(AsciiChar::from_char('\u{80}'), None);
+        assert_eq!(AsciiChar::from_char('é'), None);
+        assert_eq!(AsciiChar::from_char('~').map(u8::from), Some(b'~'));
+    }
+
+    #[test]
+    fn test_ascii_char_display() {
+        let a = AsciiChar::from_char('a').unwrap();
+        assert_eq!(a.to_string(), "a");
+        assert_eq!(format!("{a:>3}"), "  a");
+        assert_eq!(char::from(a), 'a');
+    }
+
+    #[test]
+    fn test_derive_with_ascii_char_field() {
+        assert_eq!(Token::ENUMERABLE_SIZE_OPTION, Some(256));
+        assert_eq!(
+            Token::enumerator().nth(0x80 + 0x41),
+            Some(Token {
+                quoted: true,
+                c: AsciiChar::from_char('A').unwrap(),
+            })
+        );
+        assert_eq!(Token::enumerator().count(), 256);
+    }
+}
+
+mod ranged {
+    use super::*;
+    use crate::{RangedChar, RangedI16, RangedI8, RangedU32, RangedU64, RangedU8};
+
+    #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    struct Dice {
+        a: RangedU8<1, 6>,
+        b: RangedU8<1, 6>,
+    }
+
+    #[test]
+    fn test_ranged_u8() {
+        type Digit = RangedU8<0, 9>;
+
+        assert_enumerator_eq_with_size_hint(
+            (0..=9).map(|v| Digit::new(v).unwrap()).collect::<Vec<_>>(),
+        );
+        assert_exact_size::<Digit>();
+        assert_first_last_value::<Digit>();
+        assert_next_value_direct::<Digit>();
+        assert_previous_value_direct::<Digit>();
+        assert_eq!(Digit::new(10), None);
+        assert_eq!(Digit::new(9).map(u8::from), Some(9));
+        assert_eq!(Digit::enumerable_from_index(9).map(Digit::get), Some(9));
+        assert_eq!(Digit::enumerable_from_index(10), None);
+        assert_eq!(Digit::enumerator_since(10).next(), None);
+        assert_eq!(Digit::new(7).unwrap().to_string(), "7");
+
+        assert_eq!(<RangedU8<255, 255>>::ENUMERABLE_SIZE, 1);
+        assert_eq!(<RangedU8<0, 255>>::ENUMERABLE_SIZE, 256);
+        assert_eq!(<RangedU8<0, 255>>::enumerator().count(), 256);
+    }
+
+    #[test]
+    fn test_ranged_signed() {
+        type Offset = RangedI8<-2, 2>;
+
+        assert_enumerator_eq_with_size_hint(
+            (-2..=2)
+                .map(|v| Offset::new(v).unwrap())
+                .collect::<Vec<_>>(),
+        );
+        assert_first_last_value::<Offset>();
+        assert_next_value_direct::<Offset>();
+        assert_previous_value_direct::<Offset>();
+        assert_eq!(
+            Offset::enumerable_to_index(&Offset::new(-2).unwrap()),
+            Some(0)
+        );
+        assert_eq!(
+            Offset::enumerable_to_index(&Offset::new(2).unwrap()),
+            Some(4)
+        );
+        assert_eq!(Offset::enumerable_from_index(2).map(Offset::get), Some(0));
+        assert_eq!(Offset::new(-3), None);
+
+        assert_eq!(
+            <RangedI16<{ i16::MIN }, { i16::MAX }>>::ENUMERABLE_SIZE,
+            65536
+        );
+        assert_eq!(
+            <RangedI16<{ i16::MIN }, { i16::MAX }>>::enumerable_from_index(65535)
+                .map(RangedI16::get),
+            Some(i16::MAX)
+        );
+    }
+
+    #[test]
+    fn test_ranged_wide() {
+        type Large = RangedU32<1_000_000, 3_000_000_000>;
+
+        assert_eq!(Large::ENUMERABLE_SIZE_OPTION_U128, Some(2_999_000_001));
+        assert_eq!(
+            Large::enumerable_from_index(2_999_000_000).map(Large::get),
+            Some(3_000_000_000)
+        );
+        assert_eq!(
+            Large::enumerator_since(2_999_000_000)
+                .map(Large::get)
+                .collect::<Vec<_>>(),
+            vec![3_000_000_000]
+        );
+        assert_eq!(Large::enumerator().nth(5).map(Large::get), Some(1_000_005));
+
+        type Full = RangedU64<0, { u64::MAX }>;
+        assert_eq!(Full::ENUMERABLE_SIZE_OPTION_U128, Some(1 << 64));
+        assert_eq!(
+            Full::enumerable_from_index_u128(u64::MAX as u128).map(Full::get),
+            Some(u64::MAX)
+        );
+        assert_eq!(Full::enumerable_from_index_u128(1 << 64), None);
+    }
+
+    #[test]
+    fn test_ranged_char() {
+        type Lowercase = RangedChar<'a', 'z'>;
+
+        assert_enumerator_eq_with_size_hint(
+            ('a'..='z')
+                .map(|c| Lowercase::new(c).unwrap())
+                .collect::<Vec<_>>(),
+        );
+        assert_exact_size::<Lowercase>();
+        assert_first_last_value::<Lowercase>();
+        assert_next_value_direct::<Lowercase>();
+        assert_previous_value_direct::<Lowercase>();
+        assert_eq!(Lowercase::new('A'), None);
+        assert_eq!(
+            Lowercase::enumerable_from_index(25).map(char::from),
+            Some('z')
+        );
+        assert_eq!(Lowercase::enumerable_from_index(26), None);
+        assert_eq!(Lowercase::enumerator_since(26).next(), None);
+        assert_eq!(Lowercase::new('q').unwrap().to_string(), "q");
+    }
+
+    #[test]
+    fn test_ranged_char_surrogate_gap() {
+        type AroundGap = RangedChar<'\u{D7FE}', '\u{E001}'>;
+
+        let expected = ['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}'];
+        assert_enumerator_eq_with_size_hint(expected.map(|c| AroundGap::new(c).unwrap()).to_vec());
+        assert_eq!(AroundGap::ENUMERABLE_SIZE, 4);
+        assert_first_last_value::<AroundGap>();
+        assert_next_value_direct::<AroundGap>();
+        assert_previous_value_direct::<AroundGap>();
+        for (index, c) in expected.into_iter().enumerate() {
+            let value = AroundGap::new(c).unwrap();
+            assert_eq!(AroundGap::enumerable_from_index(index), Some(value));
+            assert_eq!(AroundGap::enumerable_to_index(&value), Some(index));
+            assert_eq!(
+                AroundGap::enumerator_from(value).map(char::from).next(),
+                Some(c)
+            );
+        }
+        assert_eq!(AroundGap::enumerable_from_index(4), None);
+        assert_eq!(
+            AroundGap::enumerator_since(1).nth(1).map(char::from),
+            Some('\u{E000}')
+        );
+        assert_eq!(
+            AroundGap::enumerator()
+                .rev()
+                .map(char::from)
+                .collect::<Vec<_>>(),
+            vec!['\u{E001}', '\u{E000}', '\u{D7FF}', '\u{D7FE}']
+        );
+
+        type Full = RangedChar<'\0', '\u{10FFFF}'>;
+        assert_eq!(Full::ENUMERABLE_SIZE, char::ENUMERABLE_SIZE);
+        assert_eq!(
+            Full::enumerable_from_index(0xD800).map(char::from),
+            Some('\u{E000}')
+        );
+    }
+
+    #[test]
+    fn test_derive_with_ranged_fields() {
+        assert_eq!(Dice::ENUMERABLE_SIZE, 36);
+        assert_eq!(Dice::enumerator().count(), 36);
+        assert_eq!(
+            Dice::enumerator().nth(7),
+            Some(Dice {
+                a: RangedU8::new(2).unwrap(),
+                b: RangedU8::new(2).unwrap(),
+            })
+        );
+        assert_eq!(
+            Dice::enumerator()
+                .map(|dice| dice.a.get() + dice.b.get())
+                .filter(|&sum| sum == 7)
+                .count(),
+            6
+        );
+    }
+}
+
+mod bits {
+    use super::*;
+    use crate::Bits;
+
+    #[test]
+    fn test_bits() {
+        assert_enumerator_eq_with_size_hint(
+            (0..8)
+                .map(|v| Bits::<3>::new(v).unwrap())
+                .collect::<Vec<_>>(),
+        );
+        assert_first_last_value::<Bits<3>>();
+        assert_next_value_direct::<Bits<3>>();
+        assert_previous_value_direct::<Bits<3>>();
+        assert_eq!(Bits::<3>::new(8), None);
+        assert_eq!(Bits::<3>::truncate(0b1101).to_u64(), 0b101);
+        assert_eq!(Bits::<3>::enumerable_from_index(7), Some(Bits::ALL));
+        assert_eq!(Bits::<3>::enumerable_from_index(8), None);
+        assert_eq!(Bits::<3>::enumerator_since(8).next(), None);
+        assert_eq!(
+            format!("{:?}", Bits::<4>::new(0b101).unwrap()),
+            "Bits<4>(0b0101)"
+        );
+    }
+
+    #[test]
+    fn test_bits_access() {
+        let bits = Bits::<4>::from_array([true, false, true, true]);
+        assert_eq!(bits.to_u64(), 0b1101);
+        assert!(bits[0] && !bits[1] && bits[2] && bits[3]);
+        assert!(bits.get(3));
+        assert_eq!(bits.with(3, false).with(1, true).to_u64(), 0b0111);
+        assert_eq!(<[bool; 4]>::from(bits), [true, false, true, true]);
+        assert_eq!(Bits::from([false, true]), Bits::<2>::new(0b10).unwrap());
+        assert_eq!(Bits::<4>::default(), Bits::<4>::ZERO);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bits_index_out_of_range() {
+        let _ = Bits::<4>::ALL[4];
+    }
+
+    #[test]
+    fn test_bits_zero_width() {
+        assert_eq!(collect_all::<Bits<0>>(), vec![Bits::ZERO]);
+        assert_eq!(Bits::<0>::ENUMERABLE_SIZE, 1);
+        assert_eq!(Bits::<0>::ZERO, Bits::<0>::ALL);
+        assert_eq!(Bits::<0>::new(1), None);
+        assert_eq!(Bits::<0>::ZERO.next_value(), None);
+        assert_eq!(Bits::<0>::from_array([]), Bits::ZERO);
+    }
+
+    #[test]
+    fn test_bits_wide() {
+        assert_eq!(Bits::<32>::ENUMERABLE_SIZE_OPTION_U128, Some(1 << 32));
+        assert_eq!(Bits::<64>::ENUMERABLE_SIZE_OPTION_U128, Some(1 << 64));
+        assert_eq!(Bits::<64>::ENUMERABLE_SIZE_OPTION, None);
+        assert_eq!(Bits::<64>::ALL.to_u64(), u64::MAX);
+        assert_eq!(Bits::<64>::ALL.next_value(), None);
+        assert_eq!(
+            Bits::<64>::enumerable_from_index_u128(u64::MAX as u128),
+            Some(Bits::ALL)
+        );
+        assert_eq!(Bits::<64>::enumerable_from_index_u128(1 << 64), None);
+        assert_eq!(Bits::<64>::enumerator().size_hint(), (usize::MAX, None));
+        assert_eq!(Bits::<64>::enumerator().next_back(), Some(Bits::ALL));
+        assert_eq!(
+            Bits::<40>::enumerator().nth(1 << 39).map(Bits::to_u64),
+            Some(1 << 39)
+        );
+    }
+}
+
+mod total_float {
+    use super::*;
+    use crate::{TotalF32, TotalF64};
+
+    #[test]
+    fn test_total_f32_zeros() {
+        let negative_zero = TotalF32::new(-0.0);
+        let positive_zero = TotalF32::new(0.0);
+        assert_ne!(negative_zero, positive_zero);
+        assert!(negative_zero < positive_zero);
+
+        assert_eq!(
+            TotalF32::enumerable_to_index(&negative_zero),
+            Some(0x7FFF_FFFF)
+        );
+        assert_eq!(
+            TotalF32::enumerable_to_index(&positive_zero),
+            Some(0x8000_0000)
+        );
+        assert_eq!(negative_zero.next_value(), Some(positive_zero));
+        assert_eq!(positive_zero.previous_value(), Some(negative_zero));
+        assert_eq!(
+            negative_zero.previous_value(),
+            Some(TotalF32::from_bits(0x8000_0001))
+        );
+        assert_eq!(
+            TotalF32::enumerator_since(0x7FFF_FFFF)
+                .take(3)
+                .map(|value| value.to_bits())
+                .collect::<Vec<_>>(),
+            vec![0x8000_0000, 0, 1]
+        );
+    }
+
+    #[test]
+    fn test_total_f32_nan_boundaries() {
+        // the negative `NaN` with the greatest payload comes first, and its positive counterpart
+        // comes last
+        assert_eq!(TotalF32::first_value().unwrap().to_bits(), 0xFFFF_FFFF);
+        assert_eq!(TotalF32::last_value().unwrap().to_bits(), 0x7FFF_FFFF);
+        assert!(TotalF32::first_value().unwrap().get().is_nan());
+
+        let infinity = TotalF32::new(f32::INFINITY);
+        let next = infinity.next_value().unwrap();
+        assert!(next.get().is_nan());
+        assert_eq!(next.to_bits(), 0x7F80_0001);
+
+        let negative_infinity = TotalF32::new(f32::NEG_INFINITY);
+        let previous = negative_infinity.previous_value().unwrap();
+        assert!(previous.get().is_nan());
+        assert_eq!(previous.to_bits(), 0xFF80_0001);
+        assert!(previous < negative_infinity);
+
+        assert_eq!(TotalF32::from_bits(0x7FFF_FFFF).next_value(), None);
+        assert_eq!(TotalF32::from_bits(0xFFFF_FFFF).previous_value(), None);
+    }
+
+    #[test]
+    fn test_total_f32_order() {
+        assert_eq!(TotalF32::ENUMERABLE_SIZE_OPTION_U128, Some(1 << 32));
+        assert_eq!(
+            <TotalF32 as crate::OrderedEnumerable>::values_between(
+                TotalF32::new(-0.0),
+                TotalF32::new(0.0)
+            )
+            .count(),
+            2
+        );
+
+        // spot-check windows of the enumeration order across sign, exponent and `NaN` boundaries
+        for start in [
+            0u32,
+            0x007F_FFF0,
+            0x7F7F_FFF0,
+            0x7FFF_FFF0,
+            0x807F_FFF0,
+            0xFF7F_FFF0,
+        ] {
+            let values = TotalF32::enumerator_since(start as usize)
+                .take(32)
+                .collect::<Vec<_>>();
+            assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+            for (offset, value) in values.iter().enumerate() {
+                let index = start as usize + offset;
+                assert_eq!(TotalF32::enumerable_to_index(value), Some(index));
+                assert_eq!(TotalF32::enumerable_from_index(index), Some(*value));
+            }
+        }
+
+        let mut iter = TotalF32::enumerator();
+        assert_eq!(iter.nth(0x8000_0000).map(TotalF32::get), Some(0.0));
+        assert_eq!(iter.next().map(TotalF32::get), Some(f32::from_bits(1)));
+        assert_eq!(iter.next_back(), TotalF32::last_value());
+        assert_eq!(iter.size_hint(), (0x7FFF_FFFD, Some(0x7FFF_FFFD)));
+        assert_eq!(TotalF32::enumerable_from_index_u128(1 << 32), None);
+    }
+
+    #[test]
+    fn test_total_f64() {
+        assert_eq!(TotalF64::ENUMERABLE_SIZE_OPTION_U128, Some(1 << 64));
+        assert_eq!(TotalF64::ENUMERABLE_SIZE_OPTION, None);
+        assert_eq!(TotalF64::new(-0.0).next_value(), Some(TotalF64::new(0.0)));
+        assert_eq!(
+            TotalF64::enumerable_from_index_u128(1 << 63),
+            Some(TotalF64::new(0.0))
+        );
+        assert_eq!(
+            TotalF64::enumerable_from_index_u128(u64::MAX as u128).map(TotalF64::to_bits),
+            Some(0x7FFF_FFFF_FFFF_FFFF)
+        );
+        assert_eq!(
+            TotalF64::enumerator_reversed()
+                .next()
+                .map(TotalF64::to_bits),
+            Some(0x7FFF_FFFF_FFFF_FFFF)
+        );
+        assert_eq!(f64::from(TotalF64::from(1.5)), 1.5);
+    }
+}
+
+mod manual_impls {
+    use super::*;
+    use crate::{OptionEnumerator, Tuple2Enumerator};
+
+    /// A type implementing `Enumerable` manually, solely out of public enumerator constructors.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    struct Slot {
+        enabled: bool,
+        content: Option<Enum3>,
+    }
+
+    impl Enumerable for Slot {
+        type Enumerator = core::iter::Map<
+            Tuple2Enumerator<bool, Option<Enum3>>,
+            fn((bool, Option<Enum3>)) -> Slot,
+        >;
+
+        fn enumerator() -> Self::Enumerator {
+            Tuple2Enumerator::new().map(|(enabled, content)| Slot { enabled, content })
+        }
+
+        const ENUMERABLE_SIZE_OPTION: Option<usize> =
+            <(bool, Option<Enum3>)>::ENUMERABLE_SIZE_OPTION;
+    }
+
+    /// A type implementing `Enumerable` manually, with fast lookups by index and positioning by
+    /// value but no `enumerator_since`.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    struct Ticket(u32);
+
+    impl Enumerable for Ticket {
+        type Enumerator = core::iter::Map<core::ops::RangeInclusive<u32>, fn(u32) -> Ticket>;
+
+        fn enumerator() -> Self::Enumerator {
+            (0..=u32::MAX).map(Ticket)
+        }
+
+        fn last_value() -> Option<Self> {
+            Some(Ticket(u32::MAX))
+        }
+
+        fn enumerable_from_index(index: usize) -> Option<Self> {
+            u32::try_from(index).ok().map(Ticket)
+        }
+
+        fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+            Some((value.0..=u32::MAX).map(Ticket))
+        }
+
+        const ENUMERABLE_SIZE_OPTION: Option<usize> = u32::ENUMERABLE_SIZE_OPTION;
+    }
+
+    #[test]
+    fn test_default_enumerator_since() {
+        // positioned by `enumerable_from_index` instead of stepping billions of times
+        let mut iter = Ticket::enumerator_since(u32::MAX as usize - 1);
+        assert_eq!(iter.next(), Some(Ticket(u32::MAX - 1)));
+        assert_eq!(iter.next(), Some(Ticket(u32::MAX)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(Ticket::enumerator_since(u32::MAX as usize + 1).next(), None);
+        assert_eq!(Ticket::enumerator_since(usize::MAX).next(), None);
+        assert!(Ticket::enumerator_since(0).take(3).eq((0..3).map(Ticket)));
+        assert_eq!(
+            Ticket::enumerator_range(3_000_000_000, 3_000_000_002).collect::<Vec<_>>(),
+            vec![Ticket(3_000_000_000), Ticket(3_000_000_001)]
+        );
+
+        // `Slot` has no `enumerator_from_direct`, so the enumerator is stepped through
+        for start in 0..10 {
+            assert!(Slot::enumerator_since(start).eq(Slot::enumerator().skip(start)));
+        }
+    }
+
+    #[test]
+    fn test_default_size_u128() {
+        assert_eq!(Slot::ENUMERABLE_SIZE_OPTION_U128, Some(8));
+    }
+
+    #[test]
+    fn test_default_first_last_value() {
+        assert_first_last_value::<Slot>();
+    }
+
+    #[test]
+    fn test_default_previous_value() {
+        assert_previous_value::<Slot>();
+        assert_previous_value::<Option<Slot>>();
+        assert_previous_value::<(bool, Slot)>();
+
+        // found by stepping through the enumerator
+        let slot = Slot {
+            enabled: false,
+            content: None,
+        };
+        assert_eq!(slot.previous_value_direct(), None);
+        assert_eq!(Some(slot).previous_value_direct(), None);
+        assert_eq!(<Option<Slot>>::None.previous_value_direct(), Some(None));
+    }
+
+    #[test]
+    fn test_default_next_value() {
+        assert_next_value::<Slot>();
+        assert_next_value::<Option<Slot>>();
+        assert_next_value::<(bool, Slot)>();
+
+        // found by stepping through the enumerator
+        let slot = Slot {
+            enabled: false,
+            content: None,
+        };
+        assert_eq!(slot.next_value_direct(), None);
+        assert_eq!(Some(slot).next_value_direct(), None);
+        assert_eq!(None.next_value_direct(), Some(Some(Some(slot))));
+    }
+
+    #[test]
+    fn test_default_enumerator_from() {
+        // found by stepping through the enumerator
+        for (index, slot) in Slot::enumerator().enumerate() {
+            assert!(Slot::enumerator_from(slot).eq(Slot::enumerator().skip(index)));
+            assert!(Slot::enumerator_from_direct(slot).is_none());
+            assert!(<Option<Slot>>::enumerator_from_direct(Some(slot)).is_none());
+            assert!(<(bool, Slot)>::enumerator_from_direct((true, slot)).is_none());
+        }
+
+        let slot = Slot {
+            enabled: true,
+            content: Some(Enum3::C),
+        };
+        assert_eq!(
+            <(bool, Slot)>::enumerator_from((false, slot)).nth(1),
+            Some((true, Slot::first_value().unwrap()))
+        );
+        assert!(<Option<Slot>>::enumerator_from_direct(None).is_some());
+    }
+
+    #[test]
+    fn test_public_constructors() {
+        assert_enumerator_eq(
+            <(bool, Option<Enum3>)>::enumerator()
+                .map(|(enabled, content)| Slot { enabled, content }),
+        );
+        assert!(OptionEnumerator::<Enum3>::new().eq(Option::<Enum3>::enumerator()));
+        assert!(OptionEnumerator::<Enum3>::default().eq(Option::<Enum3>::enumerator()));
+        assert!(crate::Tuple1Enumerator::<Enum4>::new().eq(<(Enum4,)>::enumerator()));
+    }
+}
+
+mod generic_types {
+    use super::*;
+    use core::marker::PhantomData;
+
+    #[test]
+    fn test_generic_struct1() {
+        assert_enumerator_eq(<u8 as Enumerable>::enumerator().map(|x| GenericStruct1 { field: x }));
+    }
+
+    #[test]
+    fn test_generic_struct_with_default() {
+        assert_enumerator_eq(
+            <(u8, u8) as Enumerable>::enumerator()
+                .map(|(field1, field2)| GenericStruct2 { field1, field2 }),
+        );
+        assert_enumerator_eq(
+            <(u8, bool) as Enumerable>::enumerator()
+                .map(|(field1, field2)| GenericStruct2 { field1, field2 }),
+        );
+
+        assert_eq!(
+            u8::ENUMERABLE_SIZE * u8::ENUMERABLE_SIZE,
+            GenericStruct2::<u8>::ENUMERABLE_SIZE
+        );
+        assert_eq!(
+            u8::ENUMERABLE_SIZE * 2,
+            GenericStruct2::<u8, bool>::ENUMERABLE_SIZE
+        );
+    }
+
+    #[test]
+    fn test_generic_enum() {
+        let expected1 =
+            <GenericStruct2<u8, bool> as Enumerable>::enumerator().map(GenericEnum3::Variant1);
+        let expected2 = std::iter::once(GenericEnum3::Variant2);
+        let expected3 = <Result<bool, bool>>::enumerator().map(GenericEnum3::Variant3);
+        let expected = expected1
+            .chain(expected2)
+            .chain(expected3)
+            .collect::<Vec<_>>();
+
+        assert_enumerator_eq::<GenericEnum3<u8, bool>>(expected);
+    }
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    struct Tagged<T> {
+        value: u8,
+        _tag: PhantomData<T>,
+    }
+
+    /// A marker type which doesn't implement `Enumerable`.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Meters;
+
+    #[test]
+    fn test_phantom_data() {
+        assert_enumerator_eq_with_size_hint(vec![PhantomData::<str>]);
+        assert_exact_size::<PhantomData<[u8]>>();
+        assert_first_last_value::<PhantomData<Meters>>();
+        assert_eq!(
+            PhantomData::<str>::enumerable_from_index(0),
+            Some(PhantomData)
+        );
+        assert_eq!(PhantomData::<str>::enumerable_from_index(1), None);
+        assert_eq!(PhantomData::<str>::enumerator_since(1).next(), None);
+        assert_eq!(PhantomData::<Meters>::ENUMERABLE_SIZE, 1);
+
+        assert_eq!(Tagged::<Meters>::ENUMERABLE_SIZE, 256);
+        assert_enumerator_eq(u8::enumerator().map(|value| Tagged::<Meters> {
+            value,
+            _tag: PhantomData,
+        }));
+    }
+
+    #[derive(Debug, PartialEq, Enumerable)]
+    struct Key<T: ?Sized> {
+        id: u8,
+        _tag: PhantomData<T>,
+    }
+
+    // `#[derive(Clone)]` would require `T: Clone`, which unsized types can't implement
+    impl<T: ?Sized> Clone for Key<T> {
+        fn clone(&self) -> Self {
+            Key {
+                id: self.id,
+                _tag: PhantomData,
+            }
+        }
+    }
+
+    #[derive(Debug, PartialEq, Enumerable)]
+    enum Handle<T: ?Sized, U> {
+        Closed,
+        Open(U, PhantomData<fn(&T) -> Box<T>>),
+    }
+
+    impl<T: ?Sized, U: Clone> Clone for Handle<T, U> {
+        fn clone(&self) -> Self {
+            match self {
+                Handle::Closed => Handle::Closed,
+                Handle::Open(value, _) => Handle::Open(value.clone(), PhantomData),
+            }
+        }
+    }
+
+    /// A marker type which implements neither `Clone` nor `Enumerable`.
+    #[derive(Debug, PartialEq)]
+    struct Opaque;
+
+    #[test]
+    fn test_phantom_only_params() {
+        assert_eq!(Key::<str>::ENUMERABLE_SIZE, 256);
+        assert_eq!(Key::<[u8]>::enumerator().count(), 256);
+        assert_eq!(
+            Key::<dyn core::fmt::Debug>::enumerable_from_index(7).map(|key| key.id),
+            Some(7)
+        );
+        assert_eq!(Key::<Opaque>::last_value().map(|key| key.id), Some(255));
+
+        assert_enumerator_eq_with_size_hint(vec![
+            Handle::<str, bool>::Closed,
+            Handle::Open(false, PhantomData),
+            Handle::Open(true, PhantomData),
+        ]);
+        assert_eq!(Handle::<Opaque, Enum3>::ENUMERABLE_SIZE, 4);
+
+        // parameters used by fields are still bounded by `Clone`, and the derived `Clone` works
+        assert_eq!(Tagged::<Meters>::enumerator().count(), 256);
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Enumerable)]
+    struct Borrowed<'a, T> {
+        value: u8,
+        _marker: PhantomData<&'a T>,
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Enumerable)]
+    enum Slot<'a, 'b: 'a> {
+        Empty,
+        Full(bool, PhantomData<&'a mut &'b str>),
+    }
+
+    #[test]
+    fn test_lifetime_params() {
+        fn count_borrowed<'a>(_: &'a Meters) -> usize {
+            Borrowed::<'a, Meters>::enumerator().count()
+        }
+
+        assert_eq!(count_borrowed(&Meters), 256);
+        assert_eq!(Borrowed::<'static, Meters>::ENUMERABLE_SIZE, 256);
+        assert_enumerator_eq_with_size_hint(u8::enumerator().map(|value| Borrowed::<Meters> {
+            value,
+            _marker: PhantomData,
+        }));
+
+        assert_enumerator_eq_with_size_hint(vec![
+            Slot::Empty,
+            Slot::Full(false, PhantomData),
+            Slot::Full(true, PhantomData),
+        ]);
+        assert_eq!(
+            Slot::enumerable_from_index(2),
+            Some(Slot::Full(true, PhantomData))
+        );
+    }
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    struct Grid<const W: usize> {
+        cells: [bool; W],
+    }
+
+    #[derive(Clone, Debug, PartialEq, Enumerable)]
+    struct Labeled<T, const N: usize> {
+        label: T,
+        cells: [Enum3; N],
+    }
+
+    /// `LIMIT` only tells levels of different devices apart.
+    #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    enum Level<const LIMIT: u8> {
+        Off,
+        On(bool),
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    enum Channel<const ID: usize> {
+        Left,
+        Right,
+    }
+
+    #[test]
+    fn test_const_generic_params() {
+        assert_eq!(Grid::<3>::ENUMERABLE_SIZE, 8);
+        assert_enumerator_eq_with_size_hint(
+            <[bool; 3]>::enumerator().map(|cells| Grid::<3> { cells }),
+        );
+        assert_eq!(Grid::<0>::enumerator().count(), 1);
+        assert_eq!(
+            Grid::<2>::enumerable_from_index(2),
+            Some(Grid {
+                cells: [true, false]
+            })
+        );
+
+        assert_eq!(Labeled::<bool, 2>::ENUMERABLE_SIZE, 2 * 9);
+        assert_enumerator_eq(
+            <(bool, [Enum3; 2])>::enumerator().map(|(label, cells)| Labeled { label, cells }),
+        );
+
+        assert_enumerator_eq_with_size_hint(vec![
+            Level::<10>::Off,
+            Level::On(false),
+            Level::On(true),
+        ]);
+        assert_eq!(Level::<10>::ENUMERABLE_SIZE, Level::<20>::ENUMERABLE_SIZE);
+
+        assert_enumerator_eq_with_size_hint(vec![Channel::<0>::Left, Channel::Right]);
+        assert_eq!(
+            Channel::<1>::enumerator_since(1).next(),
+            Some(Channel::Right)
+        );
+    }
+}
+
+#[cfg(feature = "either")]
+mod either {
+    use super::*;
+    use ::either::Either::{self, Left, Right};
+
+    #[test]
+    fn test_either_enumerator_clone_and_debug() {
+        let mut iter = <Either<Enum3, bool>>::enumerator();
+        iter.nth(1);
+        assert_eq!(iter.clone().collect::<Vec<_>>(), iter.collect::<Vec<_>>());
+        assert!(format!("{:?}", <Either<bool, u8>>::enumerator()).starts_with("EitherEnumerator {"));
+    }
+
+    #[test]
+    fn test_either_bool_bool() {
+        assert_enumerator_eq_with_size_hint(vec![
+            Left(false),
+            Left(true),
+            Right(false),
+            Right(true),
+        ]);
+        assert_exact_size::<Either<bool, bool>>();
+        assert_exact_size::<Either<Enum3, Option<bool>>>();
+    }
+
+    #[test]
+    fn test_either_uninhabited_side() {
+        assert_eq!(
+            collect_all::<Either<Enum0, bool>>(),
+            vec![Right(false), Right(true)]
+        );
+        assert_eq!(
+            collect_all::<Either<bool, Enum0>>(),
+            vec![Left(false), Left(true)]
+        );
+        assert_eq!(collect_all::<Either<Enum0, Enum0>>(), vec![]);
+        const { assert!(<Either<Enum0, bool>>::IS_INHABITED) };
+        const { assert!(!<Either<Enum0, Enum0>>::IS_INHABITED) };
+        assert_exact_size::<Either<Enum0, Enum3>>();
+        assert_first_last_value::<Either<Enum0, bool>>();
+        assert_first_last_value::<Either<u8, Enum0>>();
+        assert_first_last_value::<Either<Enum0, Enum0>>();
+        assert_eq!(
+            <Either<Enum0, bool>>::enumerable_from_index(0),
+            Some(Right(false))
+        );
+    }
+
+    #[test]
+    fn test_either_next_previous_value() {
+        assert_first_last_value::<Either<bool, i8>>();
+        assert_next_value_direct::<Either<bool, Enum3>>();
+        assert_next_value_direct::<Either<Enum0, bool>>();
+        assert_previous_value_direct::<Either<bool, Enum3>>();
+        assert_previous_value_direct::<Either<bool, Enum0>>();
+        assert_eq!(
+            <Either<u16, i16>>::Left(u16::MAX).next_value(),
+            Some(Right(i16::MIN))
+        );
+        assert_eq!(
+            <Either<u16, i16>>::Right(i16::MIN).previous_value(),
+            Some(Left(u16::MAX))
+        );
+    }
+
+    #[test]
+    fn test_either_from_index() {
+        assert_eq!(
+            <Either<u8, bool>>::enumerable_from_index(255),
+            Some(Left(255))
+        );
+        assert_eq!(
+            <Either<u8, bool>>::enumerable_from_index(256),
+            Some(Right(false))
+        );
+        assert_eq!(<Either<u8, bool>>::enumerable_from_index(258), None);
+        assert_eq!(
+            <Either<u64, u64>>::enumerable_from_index_u128((1 << 64) + 5),
+            Some(Right(5))
+        );
+        assert_eq!(
+            <Either<u8, bool>>::enumerator_since(256).collect::<Vec<_>>(),
+            vec![Right(false), Right(true)]
+        );
+        assert_eq!(
+            <Either<u8, bool>>::enumerator_from(Left(254)).collect::<Vec<_>>(),
+            vec![Left(254), Left(255), Right(false), Right(true)]
+        );
+        assert_eq!(
+            <Either<u8, bool>>::enumerator_from(Right(true)).collect::<Vec<_>>(),
+            vec![Right(true)]
+        );
+        assert_eq!(
+            <Either<u8, bool>>::enumerable_to_index(&Right(true)),
+            Some(257)
+        );
+    }
+
+    #[test]
+    fn test_either_sizes() {
+        assert_eq!(<Either<u8, bool>>::ENUMERABLE_SIZE, 258);
+        assert_eq!(
+            <Either<u64, i64>>::ENUMERABLE_SIZE_OPTION_U128,
+            Some(1 << 65)
+        );
+        assert_eq!(<Either<u128, bool>>::ENUMERABLE_SIZE_OPTION_U128, None);
+        assert_eq!(
+            <Either<u8, Enum3> as crate::SumEnumerable>::VARIANT_SIZES,
+            [256, 3]
+        );
+
+        let mut iter = <Either<u32, u8>>::enumerator();
+        assert_eq!(iter.nth(u32::MAX as usize + 2), Some(Right(1)));
+        assert_eq!(iter.size_hint(), (254, Some(254)));
+        assert_eq!(
+            <Either<bool, Enum3>>::enumerator().next_back(),
+            Some(Right(Enum3::C))
+        );
+    }
+}
+
+#[cfg(feature = "schemars")]
+mod schema {
+    use super::*;
+    use crate::{json_schema_for, json_schema_for_with_limit};
+    use schemars::{json_schema, Schema, SchemaGenerator};
+    use serde::Serialize;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumerable, Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Mode {
+        ReadOnly,
+        ReadWrite,
+        Custom { exclusive: bool },
+    }
+
+    #[test]
+    fn test_schema_lists_all_values() {
+        let schema = json_schema_for::<Mode>(&mut SchemaGenerator::default());
+        assert_eq!(
+            schema,
+            json_schema!({
+                "enum": [
+                    "read_only",
+                    "read_write",
+                    { "custom": { "exclusive": false } },
+                    { "custom": { "exclusive": true } },
+                ]
+            })
+        );
+
+        let schema = json_schema_for::<Option<bool>>(&mut SchemaGenerator::default());
+        assert_eq!(schema, json_schema!({ "enum": [null, false, true] }));
+    }
+
+    #[test]
+    fn test_schema_limit_fallback() {
+        let mut generator = SchemaGenerator::default();
+
+        assert_eq!(json_schema_for::<u32>(&mut generator), Schema::default());
+        assert_eq!(
+            json_schema_for::<(u64, u64)>(&mut generator),
+            Schema::default()
+        );
+
+        let schema = json_schema_for_with_limit::<u8>(&mut generator, 256);
+        assert_eq!(schema.get("enum").unwrap().as_array().unwrap().len(), 256);
+        assert_eq!(
+            json_schema_for_with_limit::<u8>(&mut generator, 255),
+            Schema::default()
+        );
+        assert_eq!(
+            json_schema_for_with_limit::<Mode>(&mut generator, 3),
+            Schema::default()
+        );
+    }
+}
+
+mod verify {
+    use super::*;
+    use crate::verify::*;
+    use core::num::Wrapping;
+
+    /// Rotates the values of `Enum3`, a bijection.
+    fn next_enum3(x: Enum3) -> Enum3 {
+        x.cycle_next()
+    }
+
+    #[test]
+    fn test_is_surjective() {
+        assert_eq!(is_surjective(|x: Wrapping<u8>| -x), Ok(()));
+        assert_eq!(is_surjective(next_enum3), Ok(()));
+        assert_eq!(is_surjective(|x: u8| (x < 128, x % 3)), Err((false, 3)));
+        assert_eq!(is_surjective(|x: Enum3| x == Enum3::A), Ok(()));
+        assert_eq!(is_surjective(|x: bool| x as u8), Err(2));
+    }
+
+    #[test]
+    fn test_is_injective() {
+        assert_eq!(is_injective(|x: Wrapping<u8>| x + Wrapping(1)), Ok(()));
+        assert_eq!(is_injective(next_enum3), Ok(()));
+        assert_eq!(
+            is_injective(|x: Enum3| x == Enum3::A),
+            Err((Enum3::B, Enum3::C))
+        );
+        assert_eq!(is_injective(|x: bool| x as u8), Ok(()));
+        assert_eq!(is_injective(|x: u8| x / 4), Err((0, 1)));
+        assert_eq!(is_injective(|x: u8| x.count_ones()), Err((1, 2)));
+        assert_eq!(
+            is_injective(|(a, b): (bool, bool)| a || b),
+            Err(((false, true), (true, false)))
+        );
+        // collisions are found early without reserving space for the whole domain
+        assert_eq!(is_injective(|x: u64| x / 2), Err((0, 1)));
+    }
+
+    #[test]
+    fn test_is_bijective() {
+        assert_eq!(is_bijective(|x: Wrapping<u8>| x * Wrapping(3)), Ok(()));
+        assert_eq!(is_bijective(|x: Wrapping<u8>| !x), Ok(()));
+        assert_eq!(is_bijective(next_enum3), Ok(()));
+        assert_eq!(
+            is_bijective(|(a, b): (bool, bool)| (b, a)),
+            Ok::<(), NonBijective<_, (bool, bool)>>(())
+        );
+        assert_eq!(
+            is_bijective(|x: Wrapping<u8>| x * Wrapping(2)),
+            Err(NonBijective::Collision(Wrapping(0), Wrapping(128)))
+        );
+        assert_eq!(
+            is_bijective(|x: bool| x as u8),
+            Err(NonBijective::Missed(2))
+        );
+    }
+}
+
+mod dyn_enumerable {
+    use super::*;
+    use crate::{DynEnumerable, EnumerableProxy};
+    use std::{boxed::Box, vec::Vec};
+
+    #[test]
+    fn test_dyn_enumerable() {
+        let registry: Vec<Box<dyn DynEnumerable>> = vec![
+            Box::new(EnumerableProxy::<Enum0>::new()),
+            Box::new(EnumerableProxy::<Enum3>::new()),
+            Box::new(EnumerableProxy::<(bool, bool)>::default()),
+            Box::new(EnumerableProxy::<u128>::new()),
+        ];
+
+        assert_eq!(
+            registry.iter().map(|e| e.dyn_size()).collect::<Vec<_>>(),
+            vec![Some(0), Some(3), Some(4), None]
+        );
+        assert_eq!(registry[2].dyn_type_name(), "(bool, bool)");
+        assert_eq!(registry[0].dyn_values().count(), 0);
+        assert_eq!(
+            registry[1]
+                .dyn_values()
+                .map(|value| *value.downcast::<Enum3>().unwrap())
+                .collect::<Vec<_>>(),
+            collect_all::<Enum3>()
+        );
+        assert!(registry[2]
+            .dyn_values()
+            .all(|value| value.downcast::<Enum3>().is_err()));
+        assert_eq!(
+            *registry[3]
+                .dyn_values()
+                .nth(5)
+                .unwrap()
+                .downcast::<u128>()
+                .unwrap(),
+            5
+        );
+        assert_eq!(
+            std::format!("{:?}", EnumerableProxy::<bool>::new()),
+            "EnumerableProxy<bool>"
+        );
+    }
+}
+
+mod size {
+    use super::*;
+    use crate::size::{product, product_u128, sum, sum_u128};
+
+    #[test]
+    fn test_same_as_derived() {
+        assert_eq!(
+            product_u128(&[
+                Enum3::ENUMERABLE_SIZE_OPTION_U128,
+                Enum4::ENUMERABLE_SIZE_OPTION_U128
+            ]),
+            Struct2::ENUMERABLE_SIZE_OPTION_U128
+        );
+        assert_eq!(
+            product(&[u64::ENUMERABLE_SIZE_OPTION, Enum0::ENUMERABLE_SIZE_OPTION]),
+            <(u64, Enum0)>::ENUMERABLE_SIZE_OPTION
+        );
+        assert_eq!(
+            sum(&[
+                Some(1),
+                Enum3::ENUMERABLE_SIZE_OPTION,
+                Enum3::ENUMERABLE_SIZE_OPTION,
+                product(&[Enum3::ENUMERABLE_SIZE_OPTION, Enum4::ENUMERABLE_SIZE_OPTION]),
+                Struct2::ENUMERABLE_SIZE_OPTION,
+                Enum0::ENUMERABLE_SIZE_OPTION,
+                Enum3::ENUMERABLE_SIZE_OPTION,
+            ]),
+            ComplexEnum::ENUMERABLE_SIZE_OPTION
+        );
+        assert_eq!(
+            sum_u128(&[
+                u64::ENUMERABLE_SIZE_OPTION_U128,
+                bool::ENUMERABLE_SIZE_OPTION_U128
+            ]),
+            Result::<u64, bool>::ENUMERABLE_SIZE_OPTION_U128
+        );
+    }
+
+    #[test]
+    fn test_const() {
+        const SIZE: Option<usize> = product(&[sum(&[Some(2), Some(3)]), Some(4)]);
+        assert_eq!(SIZE, Some(20));
+    }
+}
+
+mod all {
+    use super::*;
+    use crate::All;
+
+    #[test]
+    fn test_all() {
+        let mut values = vec![];
+        for value in All::<ComplexEnum>::new() {
+            values.push(value);
+        }
+        assert_eq!(values, collect_all::<ComplexEnum>());
+        assert_eq!(
+            All::<Option<bool>>::default()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![None, Some(false), Some(true)]
+        );
+
+        assert_eq!(All::<ComplexEnum>::new().len(), 34);
+        assert_eq!(All::<u16>::new().len_option(), Some(65536));
+        assert_eq!(All::<NoSizeStruct>::new().len_option(), None);
+        assert!(All::<Enum0>::new().is_empty());
+        assert!(!All::<()>::new().is_empty());
+        assert_eq!(All::<Enum0>::new().into_iter().next(), None);
+    }
+}
+
+mod quantifiers {
+    use super::*;
+    use crate::{count_where, count_where_u128, exists, forall};
+
+    #[test]
+    fn test_uninhabited() {
+        assert!(forall(|_: Enum0| false));
+        assert!(!exists(|_: Enum0| true));
+        assert_eq!(count_where(|_: Enum0| true), 0);
+        assert_eq!(count_where_u128(|_: Enum0| true), Some(0));
+    }
+
+    #[test]
+    fn test_bool() {
+        assert!(forall(|x: bool| x as u8 <= 1));
+        assert!(!forall(|x: bool| x));
+        assert!(exists(|x: bool| x));
+        assert!(!exists(|x: bool| x as u8 > 1));
+        assert_eq!(count_where(|x: bool| x), 1);
+        assert_eq!(count_where_u128(|_: bool| true), Some(2));
+    }
+
+    #[test]
+    fn test_derived_struct() {
+        assert!(forall(|s: Struct2| s.e3 <= Enum3::C));
+        assert!(!forall(|s: Struct2| s.e4 != Enum4::Z));
+        assert!(exists(|s: Struct2| s.e3 == Enum3::C && s.e4 == Enum4::W));
+        assert_eq!(count_where(|s: Struct2| s.e3 == Enum3::B), 4);
+        assert_eq!(count_where(|_: Struct2| true), Struct2::ENUMERABLE_SIZE);
+        assert_eq!(count_where_u128(|s: Struct2| s.e4 == Enum4::X), Some(3));
+    }
+
+    #[test]
+    fn test_short_circuit() {
+        let mut calls = 0;
+        assert!(!forall(|x: u64| {
+            calls += 1;
+            x < 10
+        }));
+        assert_eq!(calls, 11);
+
+        calls = 0;
+        assert!(exists(|x: u64| {
+            calls += 1;
+            x == 10
+        }));
+        assert_eq!(calls, 11);
+    }
+}
+
+mod adjacent {
+    use super::*;
+    use crate::adjacent_pairs;
+    use std::fmt::Debug;
+
+    fn assert_adjacent_pairs<T: Enumerable + Debug + PartialEq>() {
+        let all = collect_all::<T>();
+        let expected = all
+            .windows(2)
+            .map(|w| (w[0].clone(), w[1].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(adjacent_pairs::<T>().collect::<Vec<_>>(), expected);
+
+        let mut iter = adjacent_pairs::<T>();
+        for remaining in (0..=expected.len()).rev() {
+            assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+            iter.next();
+        }
+    }
+
+    #[test]
+    fn test_adjacent_pairs() {
+        assert_adjacent_pairs::<bool>();
+        assert_adjacent_pairs::<u8>();
+        assert_adjacent_pairs::<ComplexEnum>();
+        assert_adjacent_pairs::<Option<Enum3>>();
+        assert_eq!(
+            adjacent_pairs::<bool>().collect::<Vec<_>>(),
+            vec![(false, true)]
+        );
+        assert_eq!(adjacent_pairs::<u8>().nth(200), Some((200, 201)));
+        assert_eq!(
+            adjacent_pairs::<ComplexEnum>().next(),
+            Some((ComplexEnum::NoField, ComplexEnum::UnnamedField(Enum3::A)))
+        );
+
+        // fewer than two values
+        assert_eq!(adjacent_pairs::<Enum0>().next(), None);
+        assert_eq!(adjacent_pairs::<Enum0>().size_hint(), (0, Some(0)));
+        assert_eq!(adjacent_pairs::<StructUnit>().next(), None);
+        assert_eq!(adjacent_pairs::<StructUnit>().size_hint(), (0, Some(0)));
+
+        // consecutive values of `u8` differ by one
+        assert!(adjacent_pairs::<u8>().all(|(a, b)| b - a == 1));
+    }
+}
+
+mod optimize {
+    use super::*;
+    use crate::{argmax_by_key, argmin_by_key};
+
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Enumerable)]
+    enum Food {
+        Apple,
+        Banana,
+        Coffee { with_milk: bool },
+    }
+
+    impl Food {
+        fn health_score(&self) -> f64 {
+            match self {
+                Food::Apple => 5.0,
+                Food::Banana => 4.0,
+                Food::Coffee { with_milk: true } => 3.0,
+                Food::Coffee { with_milk: false } => 2.0,
+            }
+        }
+
+        fn price(&self) -> f64 {
+            match self {
+                Food::Apple => 2.0,
+                Food::Banana => 1.5,
+                Food::Coffee { with_milk: true } => 1.5,
+                Food::Coffee { with_milk: false } => 1.0,
+            }
+        }
+    }
+
+    #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+    struct Meal {
+        alice_eats: Food,
+        bob_eats: Option<Food>,
+        at_home: bool,
+    }
+
+    impl Meal {
+        fn health_score(&self) -> f64 {
+            let bob_score = self.bob_eats.map_or(0.0, |food| food.health_score());
+            let bonus = if self.at_home { 1.05 } else { 1.0 };
+            (self.alice_eats.health_score() + bob_score) / 2.0 * bonus
+        }
+
+        fn price(&self) -> f64 {
+            let bob_price = self.bob_eats.map_or(0.0, |food| food.price());
+            let discount = if self.at_home { 0.8 } else { 1.0 };
+            (self.alice_eats.price() + bob_price) * discount
+        }
+    }
+
+    #[test]
+    fn test_meal() {
+        assert_eq!(
+            argmax_by_key(Meal::health_score),
+            Some(Meal {
+                alice_eats: Food::Apple,
+                bob_eats: Some(Food::Apple),
+                at_home: true,
+            })
+        );
+        assert_eq!(
+            argmin_by_key(Meal::price),
+            Some(Meal {
+                alice_eats: Food::Coffee { with_milk: false },
+                bob_eats: None,
+                at_home: true,
+            })
+        );
+
+        // the first of the values attaining the extreme, unlike `Iterator::max_by`
+        let all = collect_all::<Meal>();
+        let best_value = |m: &Meal| m.health_score() / m.price();
+        let max = all.iter().map(best_value).fold(f64::MIN, f64::max);
+        assert_eq!(
+            argmax_by_key(best_value),
+            all.iter().copied().find(|m| best_value(m) == max)
+        );
+    }
+
+    #[test]
+    fn test_ties_and_incomparable_keys() {
+        assert_eq!(argmax_by_key(|_: &Enum3| 0), Some(Enum3::A));
+        assert_eq!(argmin_by_key(|_: &Enum3| 0), Some(Enum3::A));
+        assert_eq!(argmax_by_key(|x: &Enum3| *x != Enum3::A), Some(Enum3::B));
+        assert_eq!(
+            argmax_by_key(|x: &Enum3| if *x == Enum3::C { f64::NAN } else { 1.0 }),
+            Some(Enum3::A)
+        );
+        assert_eq!(
+            argmin_by_key(|x: &Enum3| if *x == Enum3::A { f64::NAN } else { 1.0 }),
+            Some(Enum3::B)
+        );
+        assert_eq!(argmax_by_key(|_: &Enum3| f64::NAN), None);
+        assert_eq!(argmax_by_key(|_: &Enum0| 0), None);
+        assert_eq!(argmin_by_key(|_: &Enum0| 0), None);
+    }
+}
+
+mod from_index {
+    use super::*;
+    use crate::{Indexed, OrderedEnumerable, ProductEnumerable, SumEnumerable};
+    use core::cmp::Reverse;
+    use core::num::Wrapping;
+    use core::ops::{Bound, ControlFlow};
+    use core::task::Poll;
+    use std::fmt::Debug;
+
+    #[test]
+    fn test_enumerable_from_index() {
+        assert_eq!(u8::enumerable_from_index(0), Some(0));
+        assert_eq!(u8::enumerable_from_index(255), Some(255));
+        assert_eq!(u8::enumerable_from_index(256), None);
+        assert_eq!(Enum0::enumerable_from_index(0), None);
+        assert_eq!(
+            ComplexEnum::enumerator().collect::<Vec<_>>(),
+            (0..ComplexEnum::ENUMERABLE_SIZE)
+                .map(|i| ComplexEnum::enumerable_from_index(i).unwrap())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    fn assert_from_index_eq_nth<T: Enumerable + Debug + PartialEq>() {
+        let all = collect_all::<T>();
+        for index in 0..=all.len() {
+            assert_eq!(T::enumerable_from_index(index), all.get(index).cloned());
+            assert_eq!(
+                T::enumerable_from_index_u128(index as u128),
+                all.get(index).cloned()
+            );
+        }
+    }
+
+    #[test]
+    fn test_enumerable_from_index_u128() {
+        assert_from_index_eq_nth::<(bool, Enum3)>();
+        assert_from_index_eq_nth::<(Enum3, Enum0, bool)>();
+        assert_from_index_eq_nth::<(Enum3,)>();
+        assert_from_index_eq_nth::<(Option<bool>, NoSizeGeneric<bool>)>();
+
+        let beyond_usize = usize::MAX as u128 + 1;
+        assert_eq!(
+            ThreeU32::enumerable_from_index_u128(beyond_usize + 5),
+            Some(ThreeU32 {
+                a: (beyond_usize >> 64) as u32,
+                b: (beyond_usize >> 32) as u32,
+                c: 5,
+            })
+        );
+        assert_eq!(ThreeU32::enumerable_from_index_u128(1 << 96), None);
+        assert_eq!(
+            ThreeU32::enumerable_from_index_u128((1 << 96) - 1),
+            ThreeU32::last_value()
+        );
+        assert_eq!(
+            WideEnum::enumerable_from_index_u128(3 + (u64::MAX as u128) * 2 + 1),
+            Some(WideEnum::Wide(u64::MAX, true))
+        );
+        assert_eq!(WideEnum::enumerable_from_index_u128(3 + (1 << 65)), None);
+        assert_eq!(
+            <(u32, i8, u64)>::enumerable_from_index_u128((7 << 72) + (1 << 64) + 9),
+            Some((7, -127, 9))
+        );
+        assert_eq!(
+            <(u128,)>::enumerable_from_index_u128(u128::MAX),
+            Some((u128::MAX,))
+        );
+        assert_eq!(i128::enumerable_from_index_u128(0), Some(i128::MIN));
+        assert_eq!(i128::enumerable_from_index_u128(u128::MAX), Some(i128::MAX));
+        assert_eq!(i64::enumerable_from_index_u128(1 << 63), Some(0));
+        assert_eq!(u64::enumerable_from_index_u128(1 << 64), None);
+
+        assert_eq!(
+            <Option<u128>>::enumerable_from_index_u128(beyond_usize),
+            Some(Some(beyond_usize - 1))
+        );
+        assert_eq!(<Option<u8>>::enumerable_from_index_u128(1), Some(Some(0)));
+        // the default implementation can't address values beyond `usize::MAX`
+        assert_eq!(
+            <NoSizeGeneric<bool>>::enumerable_from_index_u128(beyond_usize),
+            None
+        );
+    }
+
+    #[test]
+    fn test_try_enumerable_from_index() {
+        assert_eq!(u8::try_enumerable_from_index(255), Ok(255));
+        assert_eq!(
+            Struct2::try_enumerable_from_index(5),
+            Ok(Struct2 {
+                e3: Enum3::B,
+                e4: Enum4::X
+            })
+        );
+        assert_eq!(
+            Struct2::try_enumerable_from_index(12),
+            Err(FromIndexError {
+                index: 12,
+                size: Some(12)
+            })
+        );
+        assert_eq!(
+            Enum0::try_enumerable_from_index(0),
+            Err(FromIndexError {
+                index: 0,
+                size: Some(0)
+            })
+        );
+        assert_eq!(
+            <NoSizeGeneric<bool>>::try_enumerable_from_index(100),
+            Err(FromIndexError {
+                index: 100,
+                size: None
+            })
+        );
+
+        assert_eq!(
+            bool::try_enumerable_from_index(2).unwrap_err().to_string(),
+            "index 2 out of range for type with 2 values"
+        );
+        assert_eq!(
+            <NoSizeGeneric<bool>>::try_enumerable_from_index(100)
+                .unwrap_err()
+                .to_string(),
+            "index 100 out of range for type with an unknown number of values"
+        );
+    }
+
+    #[test]
+    fn test_derived_from_index() {
+        assert_from_index_eq_nth::<Struct2>();
+        assert_from_index_eq_nth::<StructTuple2>();
+        assert_from_index_eq_nth::<ComplexEnum>();
+        assert_from_index_eq_nth::<TrailingEmptyBranch>();
+        assert_from_index_eq_nth::<StructWithEmptyField>();
+        assert_from_index_eq_nth::<FieldsNamedLikeLocals>();
+        assert_from_index_eq_nth::<GenericStruct2<bool, Option<Enum3>>>();
+        assert_from_index_eq_nth::<GenericEnum3<bool, Enum3, Option<bool>>>();
+        assert_from_index_eq_nth::<NoSizeStruct>();
+        assert_from_index_eq_nth::<NoSizeGeneric<Enum4>>();
+
+        // looked up in O(1) time, even beyond `usize::MAX` values
+        assert_eq!(
+            ThreeU32::enumerable_from_index(usize::MAX),
+            Some(ThreeU32 {
+                a: (usize::MAX as u128 >> 64) as u32,
+                b: (usize::MAX >> 32) as u32,
+                c: usize::MAX as u32,
+            })
+        );
+        assert_eq!(
+            WideEnum::enumerable_from_index(3 + 5),
+            Some(WideEnum::Wide(2, true))
+        );
+        assert_eq!(
+            WideEnum::enumerable_from_index(usize::MAX),
+            Some(WideEnum::Wide(
+                ((usize::MAX - 3) / 2) as u64,
+                (usize::MAX - 3) % 2 == 1
+            ))
+        );
+    }
+
+    #[test]
+    fn test_enumerator_reversed() {
+        assert_reversed_eq_rev::<u8>();
+        assert_reversed_eq_rev::<i8>();
+        assert_reversed_eq_rev::<bool>();
+        assert_reversed_eq_rev::<()>();
+        assert_reversed_eq_rev::<Option<Enum3>>();
+        assert_reversed_eq_rev::<Result<bool, Enum4>>();
+        assert_reversed_eq_rev::<(bool, Enum3, Option<bool>)>();
+        assert_reversed_eq_rev::<Enum0>();
+        assert_reversed_eq_rev::<Enum4>();
+        assert_reversed_eq_rev::<StructUnit>();
+        assert_reversed_eq_rev::<Struct2>();
+        assert_reversed_eq_rev::<ComplexEnum>();
+        assert_reversed_eq_rev::<TrailingEmptyBranch>();
+        assert_reversed_eq_rev::<StructWithEmptyField>();
+        assert_reversed_eq_rev::<NoSizeStruct>();
+
+        let mut iter = char::enumerator_reversed();
+        assert_eq!(iter.next(), Some('\u{10FFFF}'));
+        assert_eq!(iter.nth(0x10FFFF - 0xE000), Some('\u{D7FF}'));
+        assert_eq!(iter.size_hint(), (0xD7FF, Some(0xD7FF)));
+
+        // exact size hints
+        let mut iter = ComplexEnum::enumerator_reversed();
+        assert_eq!(
+            iter.size_hint(),
+            (
+                ComplexEnum::ENUMERABLE_SIZE,
+                Some(ComplexEnum::ENUMERABLE_SIZE)
+            )
+        );
+        iter.next();
+        assert_eq!(
+            iter.size_hint(),
+            (
+                ComplexEnum::ENUMERABLE_SIZE - 1,
+                Some(ComplexEnum::ENUMERABLE_SIZE - 1)
+            )
+        );
+        assert_eq!(u64::enumerator_reversed().next(), Some(u64::MAX));
+
+        // more than `usize::MAX` values, looked up by `u128` indices
+        let mut iter = WideEnum::enumerator_reversed();
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+        assert_eq!(iter.next(), Some(WideEnum::Wide(u64::MAX, true)));
+        assert_eq!(iter.next(), Some(WideEnum::Wide(u64::MAX, false)));
+        let mut iter = <(u64, bool)>::enumerator_reversed();
+        assert_eq!(iter.next(), Some((u64::MAX, true)));
+        assert_eq!(iter.nth(1), Some((u64::MAX - 1, true)));
+    }
+
+    #[test]
+    fn test_enumerator_since() {
+        for start in [0, 1, 2, 3, 100, 255, 256, 257, 1000] {
+            assert_since_eq_skip::<u8>(start);
+            assert_since_eq_skip::<i8>(start);
+            assert_since_eq_skip::<bool>(start);
+            assert_since_eq_skip::<Option<u8>>(start);
+            assert_since_eq_skip::<Result<Enum3, i8>>(start);
+            assert_since_eq_skip::<Result<Option<bool>, Enum0>>(start);
+            assert_since_eq_skip::<ComplexEnum>(start);
+            assert_since_eq_skip::<(Enum3, bool)>(start);
+            assert_since_eq_skip::<Enum0>(start);
+            assert_since_eq_skip::<Enum4>(start);
+            assert_since_eq_skip::<StructUnit>(start);
+            assert_since_eq_skip::<Struct2>(start);
+            assert_since_eq_skip::<StructTuple2>(start);
+            assert_since_eq_skip::<TrailingEmptyBranch>(start);
+            assert_since_eq_skip::<StructWithEmptyField>(start);
+            assert_since_eq_skip::<NoSizeStruct>(start);
+            assert_since_eq_skip::<GenericEnum3<bool, Enum3>>(start);
+            assert_since_eq_skip::<MaxSizeUnderLimit>(start);
+            assert_since_eq_skip::<ProtocolOrder>(start);
+            assert_since_eq_skip::<Token>(start);
+            assert_since_eq_skip::<(Enum3, Option<bool>, i8)>(start);
+        }
+    }
+
+    // derived types are positioned by `enumerable_from_index`
+    #[test]
+    fn test_enumerator_since_derived() {
+        assert_eq!(
+            ThreeU32::enumerator_since(usize::MAX).next(),
+            ThreeU32::enumerable_from_index(usize::MAX)
+        );
+        #[derive(Copy, Clone, Debug, PartialEq, Enumerable)]
+        enum Wide {
+            Narrow(bool),
+            Wide { high: u32, low: u16 },
+        }
+        let mut iter = Wide::enumerator_since(Wide::ENUMERABLE_SIZE - 2);
+        assert_eq!(
+            iter.next(),
+            Some(Wide::Wide {
+                high: u32::MAX,
+                low: u16::MAX - 1
+            })
+        );
+        assert_eq!(iter.next(), Wide::last_value());
+        assert_eq!(iter.next(), None);
+        assert_eq!(Wide::enumerator_since(Wide::ENUMERABLE_SIZE).next(), None);
+        assert_eq!(Wide::enumerator_since(usize::MAX).next(), None);
+    }
+
+    #[test]
+    fn test_char_enumerator_since() {
+        for start in [
+            0, 0x61, 0xD7FF, 0xD800, 0xD801, 0x10F7FF, 0x10F800, 0x10F801,
+        ] {
+            assert_eq!(
+                char::enumerator_since(start).next(),
+                char::enumerator().nth(start)
+            );
+        }
+        assert_eq!(char::enumerator_since(0xD7FF).nth(1), Some('\u{E000}'));
+    }
+
+    // built-in types are positioned in O(1) time
+    #[test]
+    fn test_enumerator_since_direct() {
+        assert_eq!(
+            u32::enumerator_since(3_000_000_000).next(),
+            Some(3_000_000_000)
+        );
+        assert_eq!(i64::enumerator_since(1).next(), Some(i64::MIN + 1));
+        assert_eq!(
+            u128::enumerator_since(usize::MAX).next(),
+            Some(usize::MAX as u128)
+        );
+        assert_eq!(
+            <Option<u32>>::enumerator_since(u32::MAX as usize + 1).collect::<Vec<_>>(),
+            vec![Some(u32::MAX)]
+        );
+        assert_eq!(
+            <Result<u32, i16>>::enumerator_since((1 << 32) + 65535).collect::<Vec<_>>(),
+            vec![Err(i16::MAX)]
+        );
+    }
+
+    #[test]
+    fn test_enumerator_from() {
+        assert_from_eq_skip::<u8>(true);
+        assert_from_eq_skip::<i8>(true);
+        assert_from_eq_skip::<bool>(true);
+        assert_from_eq_skip::<()>(true);
+        assert_from_eq_skip::<Option<u8>>(true);
+        assert_from_eq_skip::<Result<Enum3, i8>>(true);
+        assert_from_eq_skip::<Result<Enum0, bool>>(true);
+        assert_from_eq_skip::<Enum3>(true);
+        assert_from_eq_skip::<StructUnit>(true);
+        assert_from_eq_skip::<Struct2>(true);
+        assert_from_eq_skip::<StructTuple2>(true);
+        assert_from_eq_skip::<ComplexEnum>(true);
+        assert_from_eq_skip::<GenericEnum3<bool, Enum3>>(true);
+        assert_from_eq_skip::<(Enum3,)>(true);
+        assert_from_eq_skip::<(Enum3, bool, Option<Enum4>)>(true);
+
+        // resumed from the middle
+        let mut iter = Struct2::enumerator_from(Struct2 {
+            e3: Enum3::B,
+            e4: Enum4::Z,
+        });
+        assert_eq!(
+            iter.next(),
+            Some(Struct2 {
+                e3: Enum3::B,
+                e4: Enum4::Z
+            })
+        );
+        assert_eq!(
+            iter.next(),
+            Some(Struct2 {
+                e3: Enum3::C,
+                e4: Enum4::W
+            })
+        );
+        assert_eq!(iter.count(), 3);
+        assert_eq!(
+            StructTuple2::enumerator_after(StructTuple2(Enum3::A, Enum4::Y)).next(),
+            Some(StructTuple2(Enum3::A, Enum4::Z))
+        );
+
+        // positioned in O(1) time
+        assert_eq!(
+            u64::enumerator_from(u64::MAX - 1).collect::<Vec<_>>(),
+            vec![u64::MAX - 1, u64::MAX]
+        );
+        assert_eq!(
+            char::enumerator_from('\u{D7FF}')
+                .take(2)
+                .collect::<Vec<_>>(),
+            vec!['\u{D7FF}', '\u{E000}']
+        );
+        assert_eq!(char::enumerator_after('\u{E000}').next(), Some('\u{E001}'));
+        assert_eq!(char::enumerator_after('\u{10FFFF}').next(), None);
+        assert_eq!(
+            <Result<u32, i16>>::enumerator_from(Err(i16::MAX - 1)).collect::<Vec<_>>(),
+            vec![Err(i16::MAX - 1), Err(i16::MAX)]
+        );
+        assert_eq!(
+            <Option<(u32, u64)>>::enumerator_after(Some((u32::MAX, u64::MAX))).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_enumerator_range() {
+        assert!(u8::enumerator_range(10, 20).eq(10..20));
+        assert!(u8::enumerator_range(250, 1000).eq(250..=255));
+        assert_eq!(u8::enumerator_range(20, 10).next(), None);
+        assert_eq!(u8::enumerator_range(300, 400).next(), None);
+        assert!(ComplexEnum::enumerator_range(2, 5).eq(ComplexEnum::enumerator().skip(2).take(3)));
+
+        let mut iter = u32::enumerator_range(4_000_000_000, 4_000_000_002);
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next(), Some(4_000_000_000));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.next(), Some(4_000_000_001));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+
+        // exact size hints even if the enumerator can't tell
+        assert_eq!(
+            ComplexEnum::enumerator_range(3, 1000).size_hint().0,
+            ComplexEnum::ENUMERABLE_SIZE - 3
+        );
+        assert_eq!(Enum3::enumerator_range(1, 2).size_hint(), (1, Some(1)));
+        assert_eq!(Enum3::enumerator_range(5, 10).size_hint(), (0, Some(0)));
+        assert_eq!(
+            u128::enumerator_range(0, usize::MAX).size_hint(),
+            (usize::MAX, Some(usize::MAX))
+        );
+        assert_eq!(
+            <(u64, u32)>::enumerator_range(10, 20).size_hint(),
+            (10, Some(10))
+        );
+
+        // types with unknown sizes get bounded size hints
+        assert_eq!(
+            <(u64, u64)>::enumerator_range(10, 20).size_hint().1,
+            Some(10)
+        );
+        assert_eq!(NoSizeStruct::enumerator_range(4, 100).count(), 2);
+        assert_eq!(
+            NoSizeStruct::enumerator_range(4, 100).size_hint().1,
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_enumerable_to_index() {
+        assert_to_index_inverts_from_index::<u8>();
+        assert_to_index_inverts_from_index::<i16>();
+        assert_to_index_inverts_from_index::<bool>();
+        assert_to_index_inverts_from_index::<Enum3>();
+        assert_to_index_inverts_from_index::<ComplexEnum>();
+        assert_to_index_inverts_from_index::<Option<Result<bool, Enum3>>>();
+
+        assert_eq!(char::enumerable_to_index(&'\u{0}'), Some(0));
+        assert_eq!(char::enumerable_to_index(&'\u{D7FF}'), Some(0xD7FF));
+        assert_eq!(char::enumerable_to_index(&'\u{E000}'), Some(0xD800));
+        assert_eq!(
+            char::enumerable_to_index(&'\u{10FFFF}'),
+            Some(char::ENUMERABLE_SIZE - 1)
+        );
+        for index in [0, 0xD7FF, 0xD800, 0xFFFF, char::ENUMERABLE_SIZE - 1] {
+            let value = char::enumerable_from_index(index).unwrap();
+            assert_eq!(char::enumerable_to_index(&value), Some(index));
+        }
+
+        assert_eq!(i64::enumerable_to_index(&i64::MIN), Some(0));
+        assert_eq!(u64::enumerable_to_index(&u64::MAX), Some(u64::MAX as usize));
+        assert_eq!(u128::enumerable_to_index(&(1 << 100)), None);
+        assert_eq!(i128::enumerable_to_index(&i128::MIN), Some(0));
+    }
+
+    fn assert_to_index_direct_inverts_from_index<T: Enumerable + Debug>() {
+        for (index, value) in T::enumerator().enumerate() {
+            assert_eq!(
+                T::enumerable_to_index_direct(&value),
+                Some(index as u128),
+                "{:?}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn test_enumerable_to_index_direct() {
+        assert_to_index_direct_inverts_from_index::<Option<Result<bool, Enum3>>>();
+        assert_to_index_direct_inverts_from_index::<ControlFlow<Enum3, Option<bool>>>();
+        assert_to_index_direct_inverts_from_index::<Bound<Enum3>>();
+        assert_to_index_direct_inverts_from_index::<Poll<bool>>();
+        assert_to_index_direct_inverts_from_index::<(bool, Enum3, Option<Enum4>)>();
+        assert_to_index_direct_inverts_from_index::<(Enum3,)>();
+        assert_to_index_direct_inverts_from_index::<()>();
+        assert_to_index_direct_inverts_from_index::<[Enum3; 3]>();
+        assert_to_index_direct_inverts_from_index::<[Enum3; 0]>();
+        assert_to_index_direct_inverts_from_index::<Reverse<Option<Enum3>>>();
+        assert_to_index_direct_inverts_from_index::<Wrapping<i8>>();
+        assert_to_index_direct_inverts_from_index::<ComplexEnum>();
+        assert_to_index_direct_inverts_from_index::<Struct2>();
+        assert_to_index_direct_inverts_from_index::<StructTuple2>();
+        assert_to_index_direct_inverts_from_index::<StructUnit>();
+        assert_to_index_direct_inverts_from_index::<Enum3>();
+        assert_to_index_direct_inverts_from_index::<ProtocolOrder>();
+        assert_to_index_direct_inverts_from_index::<GenericEnum3<bool, Enum3, ()>>();
+        assert_to_index_direct_inverts_from_index::<Permission<Token>>();
+
+        // values past `usize::MAX`
+        assert_eq!(
+            <(u64, bool)>::enumerable_to_index_direct(&(u64::MAX, true)),
+            Some(u64::MAX as u128 * 2 + 1)
+        );
+        assert_eq!(
+            WideEnum::enumerable_to_index_direct(&WideEnum::Wide(u64::MAX, false)),
+            Some(3 + u64::MAX as u128 * 2)
+        );
+        assert_eq!(
+            <[u32; 3]>::enumerable_to_index_direct(&[u32::MAX; 3]),
+            Some((1 << 96) - 1)
+        );
+        assert_eq!(
+            <Result<u128, bool>>::enumerable_to_index_direct(&Ok(u128::MAX)),
+            Some(u128::MAX)
+        );
+
+        // the offsets of `Err` values, or the sizes of the whole types, don't fit in `u128`
+        assert_eq!(
+            <Result<u128, bool>>::enumerable_to_index_direct(&Err(false)),
+            None
+        );
+        assert_eq!(
+            <(u128, bool)>::enumerable_to_index_direct(&(0, false)),
+            None
+        );
+        assert_eq!(<[u32; 4]>::enumerable_to_index_direct(&[0; 4]), None);
+        // the size is unreported
+        assert_eq!(
+            NoSizeStruct::enumerable_to_index_direct(&NoSizeStruct::first_value().unwrap()),
+            None
+        );
+        assert_eq!(
+            NoSizeStruct::enumerable_to_index(&NoSizeStruct::last_value().unwrap()),
+            Some(5)
+        );
+
+        // skipped variants and fields are left out
+        #[derive(Clone, Debug, PartialEq, Enumerable)]
+        enum Request {
+            Get(Enum3),
+            #[enumerable(skip)]
+            Internal(String),
+            Put {
+                #[enumerable(skip, default = 0)]
+                id: u32,
+                urgent: bool,
+            },
+        }
+        assert_to_index_direct_inverts_from_index::<Request>();
+        assert_eq!(
+            Request::enumerable_to_index_direct(&Request::Put {
+                id: 9,
+                urgent: true
+            }),
+            Some(4)
+        );
+        assert_eq!(
+            Request::enumerable_to_index_direct(&Request::Internal(String::new())),
+            None
+        );
+        assert_eq!(
+            Request::enumerable_to_index(&Request::Internal(String::new())),
+            None
+        );
+
+        #[derive(Clone, Debug, PartialEq, Enumerable)]
+        enum Mode {
+            Read,
+            #[enumerable(skip)]
+            Raw,
+            Write,
+        }
+        assert_to_index_direct_inverts_from_index::<Mode>();
+        assert_eq!(Mode::enumerable_to_index_direct(&Mode::Raw), None);
+    }
+
+    #[test]
+    fn test_indexed() {
+        assert!(Indexed(Enum3::A) < Indexed(Enum3::B));
+        assert!(Indexed(Enum3::C) > Indexed(Enum3::B));
+        assert!(Indexed('\u{D7FF}') < Indexed('\u{E000}'));
+        assert!(Indexed(-1i8) > Indexed(-2i8));
+
+        // the derived `Ord` compares discriminants, but `Indexed` compares positions
+        #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Enumerable)]
+        enum Priority {
+            High = 2,
+            Low = 0,
+        }
+        assert!(Priority::High > Priority::Low);
+        assert!(Indexed(Priority::High) < Indexed(Priority::Low));
+
+        let mut values = vec![Indexed(Enum3::C), Indexed(Enum3::A), Indexed(Enum3::B)];
+        values.sort();
+        assert_eq!(
+            values,
+            vec![Indexed(Enum3::A), Indexed(Enum3::B), Indexed(Enum3::C)]
+        );
+
+        assert_eq!(Indexed(Enum3::A).index(), 0);
+        assert_eq!(Indexed(Enum3::C).index(), 2);
+        assert_eq!(Indexed::<Enum3>::from_index(2), Some(Indexed(Enum3::C)));
+        assert_eq!(Indexed::<Enum3>::from_index(3), None);
+        assert_eq!(Indexed::from_index(0xD800), Some(Indexed('\u{E000}')));
+    }
+
+    #[test]
+    fn test_indexed_wide() {
+        assert!(Indexed((u32::MAX, true)) > Indexed((u32::MAX, false)));
+        assert!(Indexed(WideEnum::Small(Enum3::C)) < Indexed(WideEnum::Wide(0, false)));
+    }
+
+    #[test]
+    #[should_panic(expected = "index of the value exceeds `usize::MAX`")]
+    fn test_indexed_past_usize_max() {
+        // the index is computed directly, so the panic is reached instead of stepping forever
+        let _ = Indexed((u64::MAX, true)) < Indexed((0, false));
+    }
+
+    #[cfg(enumerable_step)]
+    #[test]
+    fn test_indexed_step() {
+        let all = (Indexed(Enum3::A)..=Indexed(Enum3::C))
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        assert_eq!(all, vec![Enum3::A, Enum3::B, Enum3::C]);
+        assert_eq!((Indexed(Enum3::C)..=Indexed(Enum3::A)).count(), 0);
+        assert_eq!((Indexed(Enum3::A)..Indexed(Enum3::A)).count(), 0);
+        assert_eq!((Indexed(Enum3::A)..).nth(1), Some(Indexed(Enum3::B)));
+
+        let gap = (Indexed('\u{D7FE}')..=Indexed('\u{E001}'))
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        assert_eq!(gap, vec!['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}']);
+        assert_eq!(
+            (Indexed('\u{0}')..=Indexed('\u{10FFFF}')).count(),
+            char::ENUMERABLE_SIZE
+        );
+
+        let first = ComplexEnum::first_value().unwrap();
+        let last = ComplexEnum::last_value().unwrap();
+        assert!((Indexed(first)..=Indexed(last))
+            .map(|v| v.0)
+            .eq(ComplexEnum::enumerator()));
+        assert_eq!(
+            (Indexed(first)..=Indexed(last)).next_back(),
+            Some(Indexed(last))
+        );
+    }
+
+    #[test]
+    fn test_values_between() {
+        assert!(u8::values_between(10, 20).eq(10..=20));
+        assert!(i16::values_between(-300, 300).eq(-300..=300));
+        assert!(char::values_between('a', 'z').eq('a'..='z'));
+        assert!(u8::values_between(255, 255).eq([255]));
+        assert_eq!(u8::values_between(20, 10).count(), 0);
+        assert_eq!(u8::values_between(20, 10).size_hint(), (0, Some(0)));
+        assert!(Enum3::values_between(Enum3::B, Enum3::C).eq([Enum3::B, Enum3::C]));
+        assert_eq!(Enum3::values_between(Enum3::C, Enum3::A).count(), 0);
+        assert!(<Option<bool>>::values_between(None, Some(false)).eq([None, Some(false)]));
+        assert!(
+            <Result<bool, Enum3>>::values_between(Ok(true), Err(Enum3::B)).eq([
+                Ok(true),
+                Err(Enum3::A),
+                Err(Enum3::B)
+            ])
+        );
+
+        let mut iter = u32::values_between(5, 6);
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.next(), Some(6));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_enumerator_step_by() {
+        for (start, step) in [
+            (0, 1),
+            (0, 2),
+            (1, 3),
+            (5, 7),
+            (100, 1),
+            (255, 1),
+            (256, 1),
+            (0, 300),
+        ] {
+            assert_step_by_eq_skip_step_by::<u8>(start, step);
+            assert_step_by_eq_skip_step_by::<i8>(start, step);
+            assert_step_by_eq_skip_step_by::<bool>(start, step);
+            assert_step_by_eq_skip_step_by::<Option<u8>>(start, step);
+            assert_step_by_eq_skip_step_by::<Result<Enum3, i8>>(start, step);
+            assert_step_by_eq_skip_step_by::<Enum0>(start, step);
+            assert_step_by_eq_skip_step_by::<Enum4>(start, step);
+            assert_step_by_eq_skip_step_by::<ComplexEnum>(start, step);
+            assert_step_by_eq_skip_step_by::<Struct2>(start, step);
+            assert_step_by_eq_skip_step_by::<TrailingEmptyBranch>(start, step);
+            assert_step_by_eq_skip_step_by::<(Enum3, Option<bool>, i8)>(start, step);
+            assert_step_by_eq_skip_step_by::<Token>(start, step);
+        }
+
+        // jumping over the values in between
+        assert_eq!(
+            char::enumerator_step_by(0xD7FE, 2)
+                .take(2)
+                .collect::<Vec<_>>(),
+            vec!['\u{D7FE}', '\u{E000}']
+        );
+        assert_eq!(
+            char::enumerator_step_by(0, 0x10000).count(),
+            char::ENUMERABLE_SIZE.div_ceil(0x10000)
+        );
+        assert_eq!(
+            <(u32, u32)>::enumerator_step_by(3, 1 << 32)
+                .take(3)
+                .collect::<Vec<_>>(),
+            vec![(0, 3), (1, 3), (2, 3)]
+        );
+        assert_eq!(u64::enumerator_step_by(0, usize::MAX).count(), 2);
+        assert_eq!(
+            u64::enumerator_step_by(usize::MAX, usize::MAX).collect::<Vec<_>>(),
+            vec![u64::MAX]
+        );
+        assert_eq!(
+            u64::enumerator_step_by(0, 1).size_hint(),
+            (usize::MAX, None)
+        );
+        assert_eq!(
+            ThreeU32::enumerator_step_by(0, usize::MAX).nth(2),
+            ThreeU32::enumerable_from_index_u128(2 * usize::MAX as u128)
+        );
+
+        // types with unknown sizes
+        assert!(NoSizeStruct::enumerator_step_by(1, 2)
+            .eq(NoSizeStruct::enumerator().skip(1).step_by(2)));
+        assert_eq!(
+            NoSizeStruct::enumerator_step_by(1, 2).size_hint(),
+            (0, None)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`step` must not be 0")]
+    fn test_enumerator_step_by_zero() {
+        u8::enumerator_step_by(0, 0);
+    }
+
+    #[test]
+    fn test_enumerator_shard() {
+        for num_shards in [1, 2, 3, 5, 7, 12, 13, 100] {
+            assert_shards_reassemble::<Struct2>(num_shards);
+            assert_shards_reassemble::<ComplexEnum>(num_shards);
+            assert_shards_reassemble::<(Enum3, Option<bool>, i8)>(num_shards);
+            assert_shards_reassemble::<Enum0>(num_shards);
+            assert_shards_reassemble::<StructUnit>(num_shards);
+            assert_shards_reassemble::<NoSizeStruct>(num_shards);
+        }
+
+        assert_eq!(u32::enumerator_shard(1, 2).next(), Some(1 << 31));
+        assert_eq!(u32::enumerator_shard(2, 3).size_hint().0, (1 << 32) / 3 + 1);
+        assert_eq!(
+            u32::enumerator_shard(0, usize::MAX).size_hint(),
+            (0, Some(0))
+        );
+        assert_eq!(
+            u32::enumerator_shard(usize::MAX - 1, usize::MAX).collect::<Vec<_>>(),
+            vec![u32::MAX]
+        );
+        assert_eq!(u8::enumerator_shard(2, 1000).count(), 0);
+        assert_eq!(u8::enumerator_shard(3, 1000).count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "`shard` (3) must be less than `num_shards` (3)")]
+    fn test_enumerator_shard_out_of_range() {
+        u8::enumerator_shard(3, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be sharded")]
+    fn test_enumerator_shard_too_many_values() {
+        ThreeU32::enumerator_shard(0, 2);
+    }
+
+    fn collect_progress<T: Enumerable>(every: usize) -> Vec<(usize, Option<usize>)> {
+        let mut reports = vec![];
+        let count =
+            T::enumerator_with_progress(every, |done, total| reports.push((done, total))).count();
+        assert_eq!(reports.last().map(|r| r.0), Some(count));
+        assert!(reports.windows(2).all(|w| w[0].0 < w[1].0), "{:?}", reports);
+        reports
     }
 
     #[test]
-    fn test_char() {
-        assert_eq!(char::enumerator().skip(0x61).next(), Some('\u{61}'));
-        assert_ne!(char::enumerator().skip(0xF987).next(), Some('\u{F987}'));
+    fn test_enumerator_with_progress() {
+        let size = Some(ComplexEnum::ENUMERABLE_SIZE);
         assert_eq!(
-            char::enumerator().skip(0xF987 - 0x800).next(),
-            Some('\u{F987}')
+            collect_progress::<ComplexEnum>(10),
+            vec![(10, size), (20, size), (30, size), (34, size)]
+        );
+        assert_eq!(
+            collect_progress::<ComplexEnum>(17),
+            vec![(17, size), (34, size)]
+        );
+        assert_eq!(collect_progress::<ComplexEnum>(100), vec![(34, size)]);
+        assert_eq!(collect_progress::<ComplexEnum>(1).len(), 34);
+        assert_eq!(collect_progress::<Enum0>(5), vec![(0, Some(0))]);
+        assert_eq!(
+            collect_progress::<NoSizeStruct>(4),
+            vec![(4, None), (6, None)]
         );
+
+        // the final progress is reported only once
+        let mut reports = 0;
+        let mut iter = Enum3::enumerator_with_progress(2, |_, _| reports += 1);
+        assert_eq!(iter.by_ref().count(), 3);
+        assert_eq!(iter.next(), None);
+        assert_eq!(reports, 2);
     }
-}
 
-mod enum_and_struct {
-    use super::*;
+    #[test]
+    #[should_panic(expected = "`every` must not be 0")]
+    fn test_enumerator_with_progress_zero() {
+        u8::enumerator_with_progress(0, |_, _| {});
+    }
 
     #[test]
-    fn test_enum_derive() {
-        // plain enums return a slice iter, so it must have a exact size hint
-        assert_enumerator_eq_with_size_hint(vec![Enum3::A, Enum3::B, Enum3::C]);
-        assert_enumerator_eq_with_size_hint(vec![Enum4::W, Enum4::X, Enum4::Y, Enum4::Z])
+    fn test_for_each_value() {
+        let mut visited = vec![];
+        ComplexEnum::for_each_value(|value| visited.push(value));
+        assert_eq!(visited, collect_all::<ComplexEnum>());
+
+        Enum0::for_each_value(|_| unreachable!());
     }
 
     #[test]
-    fn test_enum_derive_complex() {
-        let mut expected = vec![ComplexEnum::NoField];
-        expected.extend(Enum3::enumerator().map(ComplexEnum::UnnamedField));
-        expected.extend(Enum3::enumerator().map(|e3| ComplexEnum::NamedField { e3 }));
-        expected.extend(
-            <(Enum3, Enum4) as Enumerable>::enumerator()
-                .map(|(e3, e4)| ComplexEnum::MultipleUnnamedFields(e3, e4)),
+    fn test_try_for_each_value() {
+        use core::ops::ControlFlow;
+
+        // the break value is propagated, and no value is visited after it
+        let mut visited = 0;
+        let found = ComplexEnum::try_for_each_value(|value| {
+            visited += 1;
+            match value {
+                ComplexEnum::MultipleUnnamedFields(Enum3::B, e4) => ControlFlow::Break(e4),
+                _ => ControlFlow::Continue(()),
+            }
+        });
+        assert_eq!(found, Some(Enum4::W));
+        assert_eq!(
+            visited,
+            ComplexEnum::enumerable_to_index(&ComplexEnum::MultipleUnnamedFields(
+                Enum3::B,
+                Enum4::W
+            ))
+            .unwrap()
+                + 1
         );
-        expected.extend(
-            <(Enum3, Enum4) as Enumerable>::enumerator()
-                .map(|(e3, e4)| ComplexEnum::MultipleNamedFields { e3, e4 }),
+
+        let mut visited = 0;
+        let found = ComplexEnum::try_for_each_value(|_| {
+            visited += 1;
+            ControlFlow::<()>::Continue(())
+        });
+        assert_eq!(found, None);
+        assert_eq!(visited, ComplexEnum::ENUMERABLE_SIZE);
+
+        assert_eq!(Enum0::try_for_each_value(|_| ControlFlow::Break(())), None);
+    }
+
+    #[test]
+    fn test_enumerator_low_discrepancy() {
+        let all = u8::enumerator_low_discrepancy().collect::<Vec<_>>();
+        let mut sorted = all.clone();
+        sorted.sort();
+        assert_eq!(sorted, collect_all::<u8>());
+        assert_eq!(
+            all[..16],
+            [0, 128, 64, 192, 32, 160, 96, 224, 16, 144, 80, 208, 48, 176, 112, 240]
         );
-        expected.extend(Enum3::enumerator().map(|e3| ComplexEnum::UnnamedFieldAfterEmpty { e3 }));
-        assert_enumerator_eq(expected);
 
-        // Checks whether the custom enumerator name is used.
-        let e: ComplexEnumerator = ComplexEnum::enumerator();
-        assert_eq!(ComplexEnum::ENUMERABLE_SIZE, e.count());
+        // out-of-range reversed indices are skipped: 0, 2, 1, (3)
+        let mut iter = Enum3::enumerator_low_discrepancy();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some(Enum3::A));
+        assert_eq!(iter.next(), Some(Enum3::C));
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        assert_eq!(iter.next(), Some(Enum3::B));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        // 0, 4, 2, (6), 1, 5, 3, (7)
+        assert_eq!(
+            <(Enum3, bool)>::enumerator_low_discrepancy().collect::<Vec<_>>(),
+            vec![
+                (Enum3::A, false),
+                (Enum3::C, false),
+                (Enum3::B, false),
+                (Enum3::A, true),
+                (Enum3::C, true),
+                (Enum3::B, true),
+            ]
+        );
+
+        assert_eq!(Enum0::enumerator_low_discrepancy().next(), None);
+        assert_eq!(
+            <()>::enumerator_low_discrepancy().collect::<Vec<_>>(),
+            vec![()]
+        );
     }
 
     #[test]
-    fn test_unit_struct() {
-        assert_enumerator_eq(vec![StructUnit {}]);
-        assert_enumerator_eq(vec![StructUnitFieldsNamed {}]);
-        assert_enumerator_eq(vec![StructUnitFieldsUnnamed()]);
+    fn test_enumerator_spread() {
+        let all = u8::enumerator_spread().collect::<Vec<_>>();
+        assert_eq!(all, u8::enumerator_low_discrepancy().collect::<Vec<_>>());
+        let mut sorted = all.clone();
+        sorted.sort();
+        assert_eq!(sorted, collect_all::<u8>());
+
+        let all = Struct2::enumerator_spread().collect::<Vec<_>>();
+        let mut sorted = all.clone();
+        sorted.sort();
+        assert_eq!(sorted, collect_all::<Struct2>());
+        // the first values are spread over all 12 values: 0, 8, 4, (12), 2, ...
+        assert_eq!(
+            all[..4],
+            [
+                Struct2 {
+                    e3: Enum3::A,
+                    e4: Enum4::W
+                },
+                Struct2 {
+                    e3: Enum3::C,
+                    e4: Enum4::W
+                },
+                Struct2 {
+                    e3: Enum3::B,
+                    e4: Enum4::W
+                },
+                Struct2 {
+                    e3: Enum3::A,
+                    e4: Enum4::Y
+                },
+            ]
+        );
     }
 
     #[test]
-    fn test_structs() {
-        let expected = Enum3::enumerator()
-            .flat_map(|e3| Enum4::enumerator().map(move |e4| (e3, e4)))
-            .collect::<Vec<_>>();
+    fn test_enumerator_diagonal() {
+        fn assert_permutation<T: ProductEnumerable + Ord + Debug>() {
+            let iter = T::enumerator_diagonal();
+            assert_eq!(iter.size_hint(), T::enumerator().size_hint());
+            let mut diagonal = iter.collect::<Vec<_>>();
+            diagonal.sort();
+            assert_eq!(diagonal, collect_all::<T>());
+        }
 
-        assert_enumerator_eq(expected.iter().map(|(e3, e4)| Struct2 { e3: *e3, e4: *e4 }));
-        assert_enumerator_eq(expected.iter().map(|(e3, e4)| StructTuple2(*e3, *e4)));
+        assert_permutation::<(u8, bool)>();
+        assert_permutation::<(bool, u8)>();
+        assert_permutation::<(Enum3,)>();
+        assert_permutation::<(Enum3, Enum4, bool)>();
+        assert_permutation::<(Enum3, Enum0)>();
+        assert_permutation::<Struct2>();
+        assert_permutation::<StructTuple2>();
+
+        // shell 0, then shell 1 split by the first field reaching 1, then `bool` is out of values
+        assert_eq!(
+            <(u8, bool)>::enumerator_diagonal()
+                .take(6)
+                .collect::<Vec<_>>(),
+            vec![
+                (0, false),
+                (1, false),
+                (1, true),
+                (0, true),
+                (2, false),
+                (2, true)
+            ]
+        );
+        assert_eq!(
+            Struct2::enumerator_diagonal().take(4).collect::<Vec<_>>(),
+            vec![
+                Struct2 {
+                    e3: Enum3::A,
+                    e4: Enum4::W
+                },
+                Struct2 {
+                    e3: Enum3::B,
+                    e4: Enum4::W
+                },
+                Struct2 {
+                    e3: Enum3::B,
+                    e4: Enum4::X
+                },
+                Struct2 {
+                    e3: Enum3::A,
+                    e4: Enum4::X
+                },
+            ]
+        );
+
+        // after `n` values, both fields have advanced about `sqrt(n)` times
+        let (max_a, max_b) = <(u8, u8)>::enumerator_diagonal()
+            .take(100)
+            .fold((0, 0), |(a, b), (x, y)| (a.max(x), b.max(y)));
+        assert_eq!((max_a, max_b), (9, 9));
+
+        assert_eq!(<(Enum3, Enum0)>::FIELD_SIZES, [3, 0]);
+        assert_eq!(
+            StructTuple2::from_field_indices([2, 1]),
+            Some(StructTuple2(Enum3::C, Enum4::X))
+        );
+        assert_eq!(StructTuple2::from_field_indices([3, 1]), None);
     }
 
-    #[derive(Copy, Clone, Debug, Eq, PartialEq, Enumerable)]
-    struct UnitStruct;
+    #[test]
+    fn test_enumerator_interleaved() {
+        fn assert_permutation<T: SumEnumerable + Ord + Debug>() {
+            let iter = T::enumerator_interleaved();
+            assert_eq!(iter.size_hint(), T::enumerator().size_hint());
+            let mut interleaved = iter.collect::<Vec<_>>();
+            interleaved.sort();
+            assert_eq!(interleaved, collect_all::<T>());
+        }
+
+        assert_permutation::<Option<Enum3>>();
+        assert_permutation::<Result<u8, bool>>();
+        assert_permutation::<Result<Enum0, Enum3>>();
+        assert_permutation::<ComplexEnum>();
+        assert_permutation::<Enum0>();
+
+        assert_eq!(
+            Result::<u8, bool>::enumerator_interleaved()
+                .take(6)
+                .collect::<Vec<_>>(),
+            vec![Ok(0), Err(false), Ok(1), Err(true), Ok(2), Ok(3)]
+        );
+        assert_eq!(
+            Option::<Enum3>::enumerator_interleaved().collect::<Vec<_>>(),
+            vec![None, Some(Enum3::A), Some(Enum3::B), Some(Enum3::C)]
+        );
+        // fieldless enums are already interleaved
+        assert_eq!(
+            Enum3::enumerator_interleaved().collect::<Vec<_>>(),
+            collect_all::<Enum3>()
+        );
+
+        // the uninhabited variant is skipped, and the others keep their internal order
+        assert_eq!(ComplexEnum::VARIANT_SIZES, [1, 3, 3, 12, 12, 0, 3]);
+        assert_eq!(
+            ComplexEnum::enumerator_interleaved()
+                .take(7)
+                .collect::<Vec<_>>(),
+            vec![
+                ComplexEnum::NoField,
+                ComplexEnum::UnnamedField(Enum3::A),
+                ComplexEnum::NamedField { e3: Enum3::A },
+                ComplexEnum::MultipleUnnamedFields(Enum3::A, Enum4::W),
+                ComplexEnum::MultipleNamedFields {
+                    e3: Enum3::A,
+                    e4: Enum4::W
+                },
+                ComplexEnum::UnnamedFieldAfterEmpty { e3: Enum3::A },
+                ComplexEnum::UnnamedField(Enum3::B),
+            ]
+        );
+    }
 
     #[test]
-    fn test_derive_unit_struct() {
-        assert_eq!(collect_all::<UnitStruct>(), vec![UnitStruct]);
+    fn test_enumerator_shuffled() {
+        fn assert_permutation<T: Enumerable + Ord + Debug>(seed: u64) {
+            let iter = T::enumerator_shuffled(seed);
+            assert_eq!(
+                iter.size_hint(),
+                (T::ENUMERABLE_SIZE, Some(T::ENUMERABLE_SIZE))
+            );
+            let mut shuffled = iter.collect::<Vec<_>>();
+            shuffled.sort();
+            assert_eq!(shuffled, collect_all::<T>());
+        }
+
+        for seed in [0, 1, 42, u64::MAX] {
+            assert_permutation::<u8>(seed);
+            assert_permutation::<u16>(seed);
+            assert_permutation::<bool>(seed);
+            assert_permutation::<Option<bool>>(seed);
+            assert_permutation::<(Enum3, bool)>(seed);
+            assert_permutation::<Enum0>(seed);
+            assert_permutation::<()>(seed);
+        }
+
+        let shuffled = u8::enumerator_shuffled(7).collect::<Vec<_>>();
+        assert_eq!(shuffled, u8::enumerator_shuffled(7).collect::<Vec<_>>());
+        assert_ne!(shuffled, u8::enumerator_shuffled(8).collect::<Vec<_>>());
+        assert_ne!(shuffled, collect_all::<u8>());
+
+        let mut iter = Enum3::enumerator_shuffled(0);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
     }
 }
 
-mod tuple {
+mod partition {
     use super::*;
+    use crate::{partition_by_enumerable_key, partition_by_key};
 
     #[test]
-    fn test_tuple0() {
-        assert_eq!(vec![()], collect_all::<()>());
+    fn test_partition_by_key() {
+        let groups = partition_by_key(|v: &u8| v % 3);
+        assert_eq!(
+            groups
+                .iter()
+                .map(|(k, g)| (*k, g.len()))
+                .collect::<Vec<_>>(),
+            vec![(0, 86), (1, 85), (2, 85)]
+        );
+        for (k, group) in groups {
+            assert!(group.iter().all(|v| v % 3 == k));
+            assert!(group.windows(2).all(|w| w[0] < w[1]));
+        }
+
+        // groups are sorted by key, not by the first member
+        let groups = partition_by_key(|v: &Enum3| core::cmp::Reverse(*v));
+        assert_eq!(
+            groups.into_iter().map(|(_, g)| g).collect::<Vec<_>>(),
+            vec![vec![Enum3::C], vec![Enum3::B], vec![Enum3::A]]
+        );
+
+        assert_eq!(partition_by_key(|_: &Enum0| ()), vec![]);
     }
 
     #[test]
-    fn test_tuple1() {
-        assert_eq!(vec![(false,), (true,)], collect_all::<(bool,)>());
+    fn test_partition_by_enumerable_key() {
+        let groups = partition_by_enumerable_key(|v: &u8| *v >= 128);
+        assert_eq!(groups[0], (false, (0..128).collect::<Vec<_>>()));
+        assert_eq!(groups[1], (true, (128..=255).collect::<Vec<_>>()));
+
+        // every key has a group, even if empty
+        let groups = partition_by_enumerable_key(|_: &Enum3| Enum4::X);
+        assert_eq!(
+            groups,
+            vec![
+                (Enum4::W, vec![]),
+                (Enum4::X, vec![Enum3::A, Enum3::B, Enum3::C]),
+                (Enum4::Y, vec![]),
+                (Enum4::Z, vec![]),
+            ]
+        );
+        assert_eq!(
+            partition_by_enumerable_key(|_: &Enum0| false),
+            vec![(false, vec![]), (true, vec![])]
+        );
+    }
+}
+
+mod sorted {
+    use super::*;
+    use std::cmp::Ordering;
+
+    // `Ord` only compares `tag`, so values with the same tag are equal
+    #[derive(Copy, Clone, Debug, Eq, Enumerable)]
+    struct Tagged {
+        id: Enum3,
+        tag: bool,
+    }
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.tag == other.tag
+        }
+    }
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.tag.cmp(&other.tag)
+        }
     }
 
     #[test]
-    fn test_tuple2() {
-        // Illustrate the return order of the enumerator.
+    fn test_enumerator_sorted_stable() {
+        let sorted = Tagged::enumerator_sorted()
+            .map(|t| (t.id, t.tag))
+            .collect::<Vec<_>>();
         assert_eq!(
-            vec![(0, false), (0, true), (1, false), (1, true), (2, false)],
-            <(u8, bool)>::enumerator().take(5).collect::<Vec<_>>()
+            sorted,
+            vec![
+                (Enum3::A, false),
+                (Enum3::B, false),
+                (Enum3::C, false),
+                (Enum3::A, true),
+                (Enum3::B, true),
+                (Enum3::C, true),
+            ]
         );
+    }
 
-        // Verify that the enumerator returns all possible values.
-        assert_enumerator_eq(
-            (0u8..=0xff).flat_map(|a| [false, true].into_iter().map(move |b| (a, b))),
-        );
+    #[test]
+    fn test_enumerator_sorted_empty() {
+        assert_eq!(Enum0::enumerator_sorted().count(), 0);
+        assert_eq!(Enum0::enumerator_sorted().size_hint(), (0, Some(0)));
     }
 
     #[test]
-    fn test_tuple16() {
-        type Tuple16 = (
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-            bool,
-        );
-        let tuple16_to_u16 = |t: Tuple16| {
-            let mut result = 0u16;
-            if t.0 {
-                result |= 1 << 15;
-            }
-            if t.1 {
-                result |= 1 << 14;
-            }
-            if t.2 {
-                result |= 1 << 13;
-            }
-            if t.3 {
-                result |= 1 << 12;
-            }
-            if t.4 {
-                result |= 1 << 11;
-            }
-            if t.5 {
-                result |= 1 << 10;
-            }
-            if t.6 {
-                result |= 1 << 9;
-            }
-            if t.7 {
-                result |= 1 << 8;
-            }
-            if t.8 {
-                result |= 1 << 7;
-            }
-            if t.9 {
-                result |= 1 << 6;
-            }
-            if t.10 {
-                result |= 1 << 5;
-            }
-            if t.11 {
-                result |= 1 << 4;
-            }
-            if t.12 {
-                result |= 1 << 3;
-            }
-            if t.13 {
-                result |= 1 << 2;
-            }
-            if t.14 {
-                result |= 1 << 1;
-            }
-            if t.15 {
-                result |= 1 << 0;
-            }
-            result
-        };
+    fn test_enumerator_sorted_ordered() {
+        // sorting the values of ordered types keeps the enumeration order
+        assert!(u8::enumerator_sorted().eq(0..=255));
+        assert!(<Option<Result<bool, i8>>>::enumerator_sorted()
+            .eq(<Option<Result<bool, i8>>>::enumerator()));
+        assert!(Enum3::enumerator_sorted().eq(Enum3::enumerator()));
+        assert!(OrderedPair::<bool>::enumerator_sorted().eq(OrderedPair::<bool>::enumerator()));
+    }
 
-        let collected_as_u16 = <Tuple16 as Enumerable>::enumerator()
-            .map(tuple16_to_u16)
-            .collect::<Vec<_>>();
-        assert_eq!(
-            collected_as_u16,
-            <u16 as Enumerable>::enumerator().collect::<Vec<_>>()
-        )
+    // the derived `Ord` of structs is lexicographic, following the enumeration order
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Enumerable)]
+    #[enumerable(ordered)]
+    struct OrderedPair<T> {
+        high: T,
+        low: bool,
+    }
+
+    #[test]
+    fn test_ordered_enumerables() {
+        assert_ordered::<Enum3>();
+        assert_ordered::<OrderedPair<Enum3>>();
+        assert_ordered::<Option<OrderedPair<u8>>>();
+        assert_ordered::<()>();
+        assert_ordered::<bool>();
+        assert_ordered::<u8>();
+        assert_ordered::<i16>();
+        assert_ordered::<Option<bool>>();
+        assert_ordered::<Result<Option<i8>, bool>>();
     }
 }
 
-mod generic_types {
+mod cached {
     use super::*;
+    use crate::{cached_all, try_cached_all, TooManyValues};
+    use std::sync::{Arc, Barrier};
 
     #[test]
-    fn test_generic_struct1() {
-        assert_enumerator_eq(<u8 as Enumerable>::enumerator().map(|x| GenericStruct1 { field: x }));
+    fn test_cached_all() {
+        let all = cached_all::<(Enum3, bool)>();
+        assert_eq!(all, collect_all::<(Enum3, bool)>());
+        assert!(std::ptr::eq(all, cached_all::<(Enum3, bool)>()));
+        assert_eq!(cached_all::<Enum0>(), &[]);
     }
 
     #[test]
-    fn test_generic_struct_with_default() {
-        assert_enumerator_eq(
-            <(u8, u8) as Enumerable>::enumerator()
-                .map(|(field1, field2)| GenericStruct2 { field1, field2 }),
+    fn test_cached_all_concurrent() {
+        const THREADS: usize = 8;
+
+        let barrier = Arc::new(Barrier::new(THREADS));
+        let handles = (0..THREADS)
+            .map(|_| {
+                let barrier = barrier.clone();
+                std::thread::spawn(move || {
+                    barrier.wait();
+                    cached_all::<(u8, Enum4)>().as_ptr() as usize
+                })
+            })
+            .collect::<Vec<_>>();
+        let pointers = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(pointers.iter().all(|p| *p == pointers[0]));
+        assert_eq!(cached_all::<(u8, Enum4)>().len(), 256 * 4);
+    }
+
+    #[test]
+    fn test_cached_all_limit() {
+        assert_eq!(
+            try_cached_all::<u16>(100),
+            Err(TooManyValues {
+                size: Some(65536),
+                limit: 100
+            })
         );
-        assert_enumerator_eq(
-            <(u8, bool) as Enumerable>::enumerator()
-                .map(|(field1, field2)| GenericStruct2 { field1, field2 }),
+        assert_eq!(
+            try_cached_all::<u64>(100),
+            Err(TooManyValues {
+                size: None,
+                limit: 100
+            })
         );
 
+        // types with unreported sizes are checked while enumerating
         assert_eq!(
-            u8::ENUMERABLE_SIZE * u8::ENUMERABLE_SIZE,
-            GenericStruct2::<u8>::ENUMERABLE_SIZE
+            try_cached_all::<NoSizeStruct>(5),
+            Err(TooManyValues {
+                size: None,
+                limit: 5
+            })
         );
+        assert_eq!(try_cached_all::<NoSizeStruct>(6).map(<[_]>::len), Ok(6));
+
+        // the limit applies to cached types as well
         assert_eq!(
-            u8::ENUMERABLE_SIZE * 2,
-            GenericStruct2::<u8, bool>::ENUMERABLE_SIZE
+            try_cached_all::<NoSizeStruct>(5),
+            Err(TooManyValues {
+                size: Some(6),
+                limit: 5
+            })
         );
     }
 
     #[test]
-    fn test_generic_enum() {
-        let expected1 =
-            <GenericStruct2<u8, bool> as Enumerable>::enumerator().map(GenericEnum3::Variant1);
-        let expected2 = std::iter::once(GenericEnum3::Variant2);
-        let expected3 = <Result<bool, bool>>::enumerator().map(GenericEnum3::Variant3);
-        let expected = expected1
-            .chain(expected2)
-            .chain(expected3)
-            .collect::<Vec<_>>();
-
-        assert_enumerator_eq::<GenericEnum3<u8, bool>>(expected);
+    #[should_panic(expected = "cannot cache 4294967296 values")]
+    fn test_cached_all_panics_over_default_limit() {
+        cached_all::<u32>();
     }
 }
@@ -3,7 +3,9 @@ use crate::Enumerable;
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
 pub enum Enum0 {}
 
+// the derived `Ord` follows the declaration order
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+#[enumerable(ordered)]
 pub enum Enum3 {
     A,
     B,
@@ -28,6 +30,7 @@ pub struct StructUnitFieldsNamed {}
 pub struct StructUnitFieldsUnnamed();
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+#[enumerable(exact_size, diagonal)]
 pub struct Struct2 {
     pub e3: Enum3,
     pub e4: Enum4,
@@ -35,10 +38,13 @@ pub struct Struct2 {
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
 #[enumerator(YesThisTypeEnumeratesStructTuple2)] // test custom enumerator names with a weird one
+#[enumerable(exact_size)]
+#[enumerable(diagonal)]
 pub struct StructTuple2(pub Enum3, pub Enum4);
 
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
 #[enumerator = "ComplexEnumerator"]
+#[enumerable(exact_size)]
 pub enum ComplexEnum {
     NoField,
     UnnamedField(Enum3),
@@ -49,6 +55,27 @@ pub enum ComplexEnum {
     UnnamedFieldAfterEmpty { e3: Enum3 },
 }
 
+// the last variant is uninhabited, so the last value comes from the one before
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+pub enum TrailingEmptyBranch {
+    Flag(bool),
+    Empty(Enum4, Enum0),
+}
+
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+pub struct StructWithEmptyField {
+    pub value: u8,
+    pub empty: Enum0,
+}
+
+// fields named like the locals in the generated `enumerable_from_index`
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+pub struct FieldsNamedLikeLocals {
+    pub index: Enum3,
+    pub rest: bool,
+    pub size: Enum4,
+}
+
 // following are test types for generic types.
 //
 // they are also used to test whether the `#[derive(Enumerable)]` macro can
@@ -82,3 +109,113 @@ pub enum GenericEnum3<
     Variant2, // test empty variant
     Variant3(Result<U, V>),
 }
+
+// following are test types for `#[enumerable(max_size = N)]`.
+
+// exactly at the limit: 3 * 4 = 12 values
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+#[enumerable(max_size = 12)]
+pub struct MaxSizeAtLimit {
+    pub e3: Enum3,
+    pub e4: Enum4,
+}
+
+// under the limit, on an enum
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+#[enumerable(max_size = 1_000)]
+pub enum MaxSizeUnderLimit {
+    A(u8),
+    B(Enum3),
+}
+
+// the check of generic types happens on instantiation
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+#[enumerable(max_size = 256, exact_size)]
+pub struct MaxSizeGeneric<T> {
+    pub value: T,
+}
+
+// more values than `usize::MAX` on 64-bit targets, but still countable in `u128`
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+pub struct ThreeU32 {
+    pub a: u32,
+    pub b: u32,
+    pub c: u32,
+}
+
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+pub enum WideEnum {
+    Small(Enum3),
+    Wide(u64, bool),
+    Empty(Enum0, u128),
+}
+
+// following are test types for `#[enumerable(no_size)]`.
+
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+#[enumerable(no_size)]
+pub struct NoSizeStruct {
+    pub flag: bool,
+    pub choice: Enum3,
+}
+
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+#[enumerable(no_size)]
+pub enum NoSizeGeneric<T> {
+    Empty,
+    Value(T),
+}
+
+// uninhabited, though the size is unreported
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+#[enumerable(no_size)]
+pub struct NoSizeEmpty {
+    pub value: u8,
+    pub empty: Enum0,
+}
+
+// following are test types for `#[enumerable(sort_by_discriminant)]`.
+
+// explicit discriminants out of declaration order, with gaps
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+#[enumerable(sort_by_discriminant)]
+#[repr(u16)]
+pub enum ProtocolOrder {
+    Close = 0x0800,
+    Open = 0x0001,
+    Ping = 0x0100,
+    Data = 0x0002,
+}
+
+// mixed implicit and explicit discriminants, including negative ones
+#[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Enumerable)]
+#[enumerable(sort_by_discriminant)]
+#[repr(i8)]
+pub enum MixedDiscriminants {
+    Ten = 10,
+    Eleven,
+    MinusTwo = -2,
+    MinusOne,
+    Zero = (0),
+    Five = 5,
+}
+
+// following are test types that are `Clone` but not `Copy`.
+
+#[derive(Clone, Debug, Eq, PartialEq, Enumerable)]
+pub enum Token {
+    Read,
+    Write,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Enumerable)]
+pub struct Grant {
+    pub token: Token,
+    pub scope: Option<Enum3>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Enumerable)]
+pub enum Permission<T> {
+    Denied,
+    Granted(T, bool),
+}
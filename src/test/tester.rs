@@ -1,4 +1,4 @@
-use crate::Enumerable;
+use crate::{Enumerable, ExactSizeEnumerable, OrderedEnumerable};
 use std::{cmp::PartialEq, fmt::Debug};
 
 /// Assert enumerator yields all elements in order.
@@ -36,6 +36,177 @@ pub fn assert_enumerator_eq_with_size_hint<T: Enumerable + Debug + PartialEq>(
     }
 }
 
+/// Assert the length of the enumerator is the number of values left at every step.
+pub fn assert_exact_size<T: ExactSizeEnumerable>() {
+    let mut iter = T::enumerator();
+    for remaining in (0..=T::ENUMERABLE_SIZE).rev() {
+        assert_eq!(iter.len(), remaining);
+        assert_eq!(iter.next().is_some(), remaining > 0);
+    }
+}
+
+/// Assert `first_value` and `last_value` agree with the enumerator.
+pub fn assert_first_last_value<T: Enumerable + Debug + PartialEq>() {
+    assert_eq!(T::first_value(), T::enumerator().next());
+    assert_eq!(T::last_value(), T::enumerator().last());
+}
+
+/// Assert `next_value` of each value is the value enumerated right after it, and `None` for the
+/// last one.
+pub fn assert_next_value<T: Enumerable + Debug + PartialEq>() {
+    let mut iter = T::enumerator().peekable();
+    while let Some(value) = iter.next() {
+        assert_eq!(
+            value.clone().next_value(),
+            iter.peek().cloned(),
+            "after {:?}",
+            value
+        );
+    }
+}
+
+/// Assert `next_value_direct` of each value is computed, and agrees with the enumerator.
+pub fn assert_next_value_direct<T: Enumerable + Debug + PartialEq>() {
+    let mut iter = T::enumerator().peekable();
+    while let Some(value) = iter.next() {
+        assert_eq!(
+            value.clone().next_value_direct(),
+            Some(iter.peek().cloned()),
+            "after {:?}",
+            value
+        );
+    }
+}
+
+/// Assert `previous_value` of each value is the value enumerated right before it, `None` for the
+/// first one, and `next_value` steps back to the value.
+pub fn assert_previous_value<T: Enumerable + Debug + PartialEq>() {
+    let mut previous = None;
+    for value in T::enumerator() {
+        assert_eq!(
+            value.clone().previous_value(),
+            previous,
+            "before {:?}",
+            value
+        );
+        if let Some(previous) = previous {
+            assert_eq!(previous.next_value(), Some(value.clone()));
+        }
+        previous = Some(value);
+    }
+}
+
+/// Assert `previous_value_direct` of each value is computed, and agrees with the enumerator.
+pub fn assert_previous_value_direct<T: Enumerable + Debug + PartialEq>() {
+    let mut previous = None;
+    for value in T::enumerator() {
+        assert_eq!(
+            value.clone().previous_value_direct(),
+            Some(previous),
+            "before {:?}",
+            value
+        );
+        previous = Some(value);
+    }
+}
+
+/// Assert the enumerator of an ordered enumerable yields values in strictly ascending order.
+pub fn assert_ordered<T: OrderedEnumerable + Debug>() {
+    let values = collect_all::<T>();
+    assert!(values.windows(2).all(|w| w[0] < w[1]), "{:?}", values);
+}
+
+/// Assert `enumerator_reversed` yields all elements in reverse order with exact size hints.
+pub fn assert_reversed_eq_rev<T: Enumerable + Debug + PartialEq>() {
+    let mut expected = collect_all::<T>();
+    expected.reverse();
+    let mut iter = T::enumerator_reversed();
+    for (yielded, value) in expected.iter().cloned().enumerate() {
+        let remaining = expected.len() - yielded;
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+        assert_eq!(iter.next(), Some(value));
+    }
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+/// Assert `enumerator_since(start)` yields the same elements as `enumerator().skip(start)`.
+pub fn assert_since_eq_skip<T: Enumerable + Debug + PartialEq>(start: usize) {
+    assert!(
+        T::enumerator_since(start).eq(T::enumerator().skip(start)),
+        "since({})",
+        start
+    );
+}
+
+/// Assert `enumerator_from` of each value yields the value and all values after it, and
+/// `enumerator_after` all values after it. The enumerators are positioned directly if `direct`.
+pub fn assert_from_eq_skip<T: Enumerable + Debug + PartialEq>(direct: bool) {
+    for (index, value) in T::enumerator().enumerate() {
+        assert!(
+            T::enumerator_from(value.clone()).eq(T::enumerator().skip(index)),
+            "from({:?})",
+            value
+        );
+        assert!(
+            T::enumerator_after(value.clone()).eq(T::enumerator().skip(index + 1)),
+            "after({:?})",
+            value
+        );
+        assert_eq!(T::enumerator_from_direct(value).is_some(), direct);
+    }
+}
+
+/// Assert `enumerable_to_index` of each element is its index in enumeration order.
+pub fn assert_to_index_inverts_from_index<T: Enumerable + PartialEq + Debug>() {
+    for (index, value) in T::enumerator().enumerate() {
+        assert_eq!(T::enumerable_to_index(&value), Some(index), "{:?}", value);
+    }
+}
+
+/// Assert `enumerator_step_by(start, step)` yields the same elements as
+/// `enumerator().skip(start).step_by(step)`, with an exact upper bound of its size hint.
+pub fn assert_step_by_eq_skip_step_by<T: Enumerable + Debug + PartialEq>(
+    start: usize,
+    step: usize,
+) {
+    let expected = T::enumerator()
+        .skip(start)
+        .step_by(step)
+        .collect::<Vec<_>>();
+    let iter = T::enumerator_step_by(start, step);
+    assert_eq!(iter.size_hint().1, Some(expected.len()));
+    assert_eq!(iter.collect::<Vec<_>>(), expected, "({}, {})", start, step);
+}
+
+/// Assert the shards of an enumerable reassemble into all its elements in order, and their
+/// lengths differ by at most one.
+pub fn assert_shards_reassemble<T: Enumerable + Debug + PartialEq>(num_shards: usize) {
+    let mut reassembled = vec![];
+    let mut lengths = vec![];
+    for shard in 0..num_shards {
+        let part = T::enumerator_shard(shard, num_shards).collect::<Vec<_>>();
+        lengths.push(part.len());
+        reassembled.extend(part);
+    }
+
+    assert_eq!(reassembled, collect_all::<T>(), "{} shards", num_shards);
+    let (min, max) = (lengths.iter().min(), lengths.iter().max());
+    assert!(max.unwrap() - min.unwrap() <= 1, "{:?}", lengths);
+}
+
+/// Assert `enumerator_since` of each start up to past the end yields the same elements with the
+/// same size hint as `enumerator().skip(start)`.
+pub fn assert_since_eq_skip_all<T: Enumerable + Debug + PartialEq>() {
+    let size = T::enumerator().count();
+    for start in 0..=size + 2 {
+        let since = T::enumerator_since(start);
+        assert_eq!(since.size_hint(), T::enumerator().skip(start).size_hint());
+        assert!(since.eq(T::enumerator().skip(start)), "start = {start}");
+    }
+    assert_eq!(T::enumerator_since(usize::MAX).next(), None);
+}
+
 /// Collect all elements of an enumerable into a vector.
 #[allow(dead_code)]
 pub fn collect_all<T: Enumerable>() -> Vec<T> {
@@ -0,0 +1,290 @@
+//! Floating-point numbers ordered by [`f32::total_cmp`] and [`f64::total_cmp`], so that every bit
+//! pattern can be enumerated.
+
+use crate::{
+    size_option_from_u128, Enumerable, OrderedEnumerable, ReversedEnumerator, StepByEnumerator,
+};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// Macro to define a total-order wrapper of a floating-point type, and implement the `Enumerable`
+/// trait for it.
+macro_rules! define_total_float_type {
+    ($name:ident, $enumerator:ident, $float:ident, $bits:ty) => {
+        #[doc = concat!("A [`", stringify!($float), "`] ordered by [`", stringify!($float), "::total_cmp`], enumerating every bit pattern of it.")]
+        ///
+        /// Floating-point numbers can't implement `Enumerable` directly, as `NaN`s aren't equal to
+        /// themselves. This wrapper compares the bits of the numbers in the IEEE 754 total order
+        /// instead, i.e. negative `NaN`s, negative infinity, negative numbers, `-0.0`, `+0.0`,
+        /// positive numbers, positive infinity and positive `NaN`s, with `NaN`s ordered by their
+        /// payloads. Two values are equal if and only if they have the same bits.
+        ///
+        /// All bit patterns are enumerated in this order.
+        #[derive(Copy, Clone, Debug, Default)]
+        pub struct $name($float);
+
+        impl $name {
+            #[doc = concat!("Wraps the `", stringify!($float), "`.")]
+            #[inline]
+            pub const fn new(value: $float) -> Self {
+                Self(value)
+            }
+
+            #[doc = concat!("Returns the wrapped `", stringify!($float), "`.")]
+            #[inline]
+            pub const fn get(self) -> $float {
+                self.0
+            }
+
+            #[doc = concat!("Wraps the `", stringify!($float), "` with the given bits, like [`", stringify!($float), "::from_bits`].")]
+            #[inline]
+            pub fn from_bits(bits: $bits) -> Self {
+                Self($float::from_bits(bits))
+            }
+
+            #[doc = concat!("Returns the bits of the wrapped `", stringify!($float), "`, like [`", stringify!($float), "::to_bits`].")]
+            #[inline]
+            pub fn to_bits(self) -> $bits {
+                self.0.to_bits()
+            }
+
+            /// The bits of the value at `index` in the total order.
+            #[inline]
+            fn bits_from_index(index: $bits) -> $bits {
+                const SIGN: $bits = 1 << (<$bits>::BITS - 1);
+                // negative values come first, with their magnitudes descending
+                if index & SIGN == 0 {
+                    !index
+                } else {
+                    index ^ SIGN
+                }
+            }
+
+            /// The index of the value with `bits` in the total order.
+            #[inline]
+            fn index_from_bits(bits: $bits) -> $bits {
+                const SIGN: $bits = 1 << (<$bits>::BITS - 1);
+                if bits & SIGN != 0 {
+                    !bits
+                } else {
+                    bits | SIGN
+                }
+            }
+
+            /// The index of this value in the total order.
+            #[inline]
+            fn index(self) -> $bits {
+                Self::index_from_bits(self.to_bits())
+            }
+
+            /// The value at `index` in the total order.
+            #[inline]
+            fn from_index(index: $bits) -> Self {
+                Self::from_bits(Self::bits_from_index(index))
+            }
+        }
+
+        impl From<$float> for $name {
+            #[inline]
+            fn from(value: $float) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for $float {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.to_bits() == other.to_bits()
+            }
+        }
+
+        impl Eq for $name {}
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.total_cmp(&other.0)
+            }
+        }
+
+        impl Hash for $name {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.to_bits().hash(state)
+            }
+        }
+
+        #[doc = concat!("`", stringify!($enumerator), "` is an iterator over possible values of [`", stringify!($name), "`].")]
+        ///
+        /// It yields all bit patterns in the total order, from the negative `NaN` with the greatest
+        /// payload to the positive `NaN` with the greatest payload.
+        pub struct $enumerator {
+            /// The index of the next value to yield from the front.
+            front: u128,
+            /// One past the index of the next value to yield from the back.
+            back: u128,
+        }
+
+        impl $enumerator {
+            /// The number of possible values.
+            const COUNT: u128 = 1 << <$bits>::BITS;
+
+            /// Creates a new enumerator, yielding the same values in the same order as
+            #[doc = concat!("`<", stringify!($name), " as Enumerable>::enumerator()`.")]
+            #[inline]
+            pub fn new() -> Self {
+                Self::since(0)
+            }
+
+            /// Creates a new enumerator yielding values from the index `start` on.
+            #[inline]
+            fn since(start: u128) -> Self {
+                Self {
+                    front: start.min(Self::COUNT),
+                    back: Self::COUNT,
+                }
+            }
+        }
+
+        impl Default for $enumerator {
+            #[inline]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl Iterator for $enumerator {
+            type Item = $name;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.nth(0)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                match usize::try_from(self.back - self.front) {
+                    Ok(remaining) => (remaining, Some(remaining)),
+                    Err(_) => (usize::MAX, None),
+                }
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                if n as u128 >= self.back - self.front {
+                    self.front = self.back;
+                    return None;
+                }
+
+                // the index is less than `back`, so it fits in the bits
+                let index = self.front + n as u128;
+                self.front = index + 1;
+                Some($name::from_index(index as $bits))
+            }
+        }
+
+        impl DoubleEndedIterator for $enumerator {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.front == self.back {
+                    return None;
+                }
+
+                self.back -= 1;
+                Some($name::from_index(self.back as $bits))
+            }
+        }
+
+        #[automatically_derived]
+        impl Enumerable for $name {
+            type Enumerator = $enumerator;
+
+            /// Returns an iterator over all bit patterns in the total order.
+            #[inline]
+            fn enumerator() -> Self::Enumerator {
+                $enumerator::new()
+            }
+
+            #[inline]
+            fn first_value() -> Option<Self> {
+                Some(Self::from_index(0))
+            }
+
+            #[inline]
+            fn last_value() -> Option<Self> {
+                Some(Self::from_index(<$bits>::MAX))
+            }
+
+            #[inline]
+            fn next_value_direct(self) -> Option<Option<Self>> {
+                Some(self.index().checked_add(1).map(Self::from_index))
+            }
+
+            #[inline]
+            fn previous_value_direct(self) -> Option<Option<Self>> {
+                Some(self.index().checked_sub(1).map(Self::from_index))
+            }
+
+            /// Returns an iterator over all possible values of this type starting from the
+            /// `start`-th one, positioned in O(1) time.
+            #[inline]
+            fn enumerator_since(start: usize) -> Self::Enumerator {
+                $enumerator::since(start as u128)
+            }
+
+            #[inline]
+            fn enumerable_from_index(index: usize) -> Option<Self> {
+                Self::enumerable_from_index_u128(index as u128)
+            }
+
+            #[inline]
+            fn enumerable_from_index_u128(index: u128) -> Option<Self> {
+                <$bits>::try_from(index).ok().map(Self::from_index)
+            }
+
+            #[inline]
+            fn enumerable_to_index_direct(value: &Self) -> Option<u128> {
+                Some(value.index() as u128)
+            }
+
+            #[inline]
+            fn enumerator_from_direct(value: Self) -> Option<Self::Enumerator> {
+                Some($enumerator::since(value.index() as u128))
+            }
+
+            #[inline]
+            fn enumerator_reversed() -> ReversedEnumerator<Self> {
+                ReversedEnumerator::from_double_ended()
+            }
+
+            #[inline]
+            fn enumerator_step_by(start: usize, step: usize) -> StepByEnumerator<Self> {
+                StepByEnumerator::by_index(start, step)
+            }
+
+            const ENUMERABLE_SIZE_OPTION: Option<usize> =
+                size_option_from_u128(Self::ENUMERABLE_SIZE_OPTION_U128);
+
+            const ENUMERABLE_SIZE_OPTION_U128: Option<u128> = Some($enumerator::COUNT);
+        }
+
+        impl OrderedEnumerable for $name {}
+    };
+}
+
+define_total_float_type!(TotalF32, TotalF32Enumerator, f32, u32);
+define_total_float_type!(TotalF64, TotalF64Enumerator, f64, u64);
@@ -0,0 +1,108 @@
+//! Exhaustive checks of properties of functions whose domains are [`Enumerable`].
+//!
+//! As all values of an `Enumerable` type can be enumerated, properties of functions taking them can
+//! be verified outright instead of sampled. On failure, these checks return a witness, i.e. the
+//! values violating the property.
+
+use crate::Enumerable;
+
+use alloc::vec::Vec;
+
+/// Checks whether `f` is injective, i.e. maps different values of `A` to different values of `B`.
+///
+/// Returns the first colliding pair `(a1, a2)` found, where `a1` is enumerated before `a2` and
+/// `f(a1) == f(a2)`.
+///
+/// `f` is called once for each value of `A`. As `B` is only required to implement [`PartialEq`],
+/// the outputs are compared pairwise, which takes O(|A|²) comparisons in the worst case.
+///
+/// ## Example
+///
+/// ```
+/// use core::num::Wrapping;
+/// use enumerable::verify::is_injective;
+///
+/// assert_eq!(is_injective(|x: Wrapping<u8>| x + Wrapping(17)), Ok(()));
+/// assert_eq!(is_injective(|x: u8| x / 2), Err((0, 1)));
+/// ```
+pub fn is_injective<A: Enumerable, B: PartialEq>(mut f: impl FnMut(A) -> B) -> Result<(), (A, A)> {
+    let mut seen: Vec<(A, B)> = Vec::new();
+
+    for a in A::enumerator() {
+        let b = f(a.clone());
+        if let Some((previous, _)) = seen.iter().find(|(_, seen_b)| *seen_b == b) {
+            return Err((previous.clone(), a));
+        }
+        seen.push((a, b));
+    }
+
+    Ok(())
+}
+
+/// Checks whether `f` is surjective, i.e. every value of `B` is the output of some value of `A`.
+///
+/// Returns the first value of `B`, in enumeration order, which `f` never outputs.
+///
+/// No output is stored, so `f` is called for values of `A` again for each value of `B`, which takes
+/// O(|A|·|B|) calls in the worst case.
+///
+/// ## Example
+///
+/// ```
+/// use core::num::Wrapping;
+/// use enumerable::verify::is_surjective;
+///
+/// assert_eq!(is_surjective(|x: Wrapping<u8>| -x), Ok(()));
+/// assert_eq!(is_surjective(|x: bool| x as u8), Err(2));
+/// ```
+pub fn is_surjective<A: Enumerable, B: Enumerable + PartialEq>(
+    mut f: impl FnMut(A) -> B,
+) -> Result<(), B> {
+    for b in B::enumerator() {
+        if !A::enumerator().any(|a| f(a) == b) {
+            return Err(b);
+        }
+    }
+
+    Ok(())
+}
+
+/// The reason why a function is not bijective, returned by [`is_bijective`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NonBijective<A, B> {
+    /// The function is not injective, `f(a1) == f(a2)` for the contained `(a1, a2)`.
+    Collision(A, A),
+    /// The function is not surjective, the contained value is never an output.
+    Missed(B),
+}
+
+/// Checks whether `f` is bijective, i.e. both injective and surjective.
+///
+/// Injectivity is checked first, see [`is_injective`] and [`is_surjective`] for the witnesses and
+/// costs. Surjectivity is only checked if the sizes of `A` and `B` differ or are unknown, as an
+/// injective function between two types of the same size is always surjective. A failure of
+/// either check is returned as a [`NonBijective`].
+///
+/// ## Example
+///
+/// ```
+/// use core::num::Wrapping;
+/// use enumerable::verify::{is_bijective, NonBijective};
+///
+/// assert_eq!(is_bijective(|x: Wrapping<u8>| x * Wrapping(3)), Ok(()));
+/// assert_eq!(
+///     is_bijective(|x: bool| Some(x)),
+///     Err(NonBijective::Missed(None))
+/// );
+/// ```
+pub fn is_bijective<A: Enumerable, B: Enumerable + PartialEq>(
+    mut f: impl FnMut(A) -> B,
+) -> Result<(), NonBijective<A, B>> {
+    is_injective(&mut f).map_err(|(a1, a2)| NonBijective::Collision(a1, a2))?;
+
+    // an injective function between finite sets of the same size is surjective
+    match (A::ENUMERABLE_SIZE_OPTION, B::ENUMERABLE_SIZE_OPTION) {
+        (Some(size_a), Some(size_b)) if size_a == size_b => Ok(()),
+        _ => is_surjective(&mut f).map_err(NonBijective::Missed),
+    }
+}
@@ -0,0 +1,7 @@
+//! Compile-fail tests for the errors reported by `#[derive(Enumerable)]`.
+
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
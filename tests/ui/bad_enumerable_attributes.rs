@@ -0,0 +1,44 @@
+use enumerable::Enumerable;
+
+#[derive(Copy, Clone, Enumerable)]
+#[enumerable(max_size = "ten")]
+#[enumerable(no_such_option)]
+pub enum Bad {
+    A,
+    B,
+}
+
+#[derive(Copy, Clone, Enumerable)]
+#[enumerable(no_size, max_size = 10)]
+#[enumerable(no_size)]
+pub struct BadNoSize {
+    value: bool,
+}
+
+#[derive(Copy, Clone, Enumerable)]
+#[enumerable(exact_size, no_size)]
+#[enumerable(exact_size)]
+pub struct BadExactSize {
+    value: bool,
+}
+
+#[derive(Copy, Clone, Enumerable)]
+pub enum BadSkip {
+    A,
+    #[enumerable(skip, skip)]
+    B,
+    #[enumerable(hidden)]
+    C,
+}
+
+#[derive(Clone, Enumerable)]
+pub struct BadSkipField {
+    #[enumerable(skip)]
+    id: u64,
+    #[enumerable(default = 0)]
+    count: u8,
+    #[enumerable(skip, default = 0, default = 1)]
+    seq: u8,
+}
+
+fn main() {}
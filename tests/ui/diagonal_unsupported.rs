@@ -0,0 +1,15 @@
+use enumerable::Enumerable;
+
+// Only structs with fields can be enumerated in diagonal order.
+#[derive(Copy, Clone, Enumerable)]
+#[enumerable(diagonal)]
+pub enum NotAStruct {
+    A(bool),
+    B,
+}
+
+#[derive(Copy, Clone, Enumerable)]
+#[enumerable(diagonal)]
+pub struct NoFields;
+
+fn main() {}
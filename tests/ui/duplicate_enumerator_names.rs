@@ -0,0 +1,12 @@
+use enumerable::Enumerable;
+
+#[derive(Copy, Clone, Enumerable)]
+#[enumerator(FirstEnumerator)]
+#[enumerator = "SecondEnumerator"]
+#[enumerator(ThirdEnumerator)]
+pub enum Duplicated {
+    A,
+    B,
+}
+
+fn main() {}
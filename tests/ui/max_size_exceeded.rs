@@ -0,0 +1,27 @@
+use enumerable::Enumerable;
+
+// 257 values, one more than allowed.
+#[derive(Copy, Clone, Enumerable)]
+#[enumerable(max_size = 256)]
+pub enum JustOverLimit {
+    Byte(u8),
+    Nothing,
+}
+
+// More values than `u128::MAX`.
+#[derive(Copy, Clone, Enumerable)]
+#[enumerable(max_size = 100_000)]
+pub struct Unbounded {
+    a: usize,
+    b: usize,
+}
+
+// More values than `usize::MAX`, but still counted.
+#[derive(Copy, Clone, Enumerable)]
+#[enumerable(max_size = 100_000)]
+pub struct BeyondUsize {
+    a: u64,
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use enumerable::Enumerable;
+
+// Two fields of reference types and a malformed `#[enumerator]` attribute are all reported from
+// a single expansion.
+#[derive(Copy, Clone, Enumerable)]
+#[enumerator(Not A Name)]
+pub struct Bad<'a, 'b> {
+    value: &'a bool,
+    other: (bool, &'b bool),
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+use enumerable::Enumerable;
+
+const BASE: isize = 4;
+
+// Discriminants that are not integer literals can't be evaluated by the derive, all of them are
+// reported.
+#[derive(Copy, Clone, Enumerable)]
+#[enumerable(sort_by_discriminant)]
+pub enum NonLiteral {
+    A = BASE,
+    B = 1 << 3,
+    C = 2,
+}
+
+// Only fieldless enums can be sorted by discriminant.
+#[derive(Copy, Clone, Enumerable)]
+#[enumerable(sort_by_discriminant)]
+pub enum WithFields {
+    A(bool),
+    B,
+}
+
+#[derive(Copy, Clone, Enumerable)]
+#[enumerable(sort_by_discriminant)]
+pub struct NotAnEnum {
+    a: bool,
+}
+
+fn main() {}